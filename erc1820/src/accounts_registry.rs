@@ -0,0 +1,26 @@
+use alloc::vec::Vec;
+use casper_types::bytesrepr::Bytes;
+use casper_types::{Key, URef};
+use constants::ACCOUNTS_REGISTRY_KEY_NAME;
+use ::{detail, indexed_list};
+
+#[inline]
+pub(crate) fn accounts_registry() -> URef {
+    detail::get_uref(ACCOUNTS_REGISTRY_KEY_NAME)
+}
+
+/// Records that `implementer` serves `interface_hash` for `account`.
+pub fn add_account(accounts_uref: URef, implementer: Key, account: Key, interface_hash: Bytes) {
+    indexed_list::add(accounts_uref, implementer, (account, interface_hash));
+}
+
+/// Forgets that `implementer` serves `interface_hash` for `account`.
+pub fn remove_account(accounts_uref: URef, implementer: Key, account: Key, interface_hash: Bytes) {
+    indexed_list::remove(accounts_uref, implementer, &(account, interface_hash));
+}
+
+/// Returns at most `limit` of the `(account, interface_hash)` pairs served by `implementer`,
+/// skipping the first `offset` ones.
+pub fn get_accounts(accounts_uref: URef, implementer: Key, offset: u64, limit: u64) -> Vec<(Key, Bytes)> {
+    indexed_list::page(accounts_uref, implementer, offset, limit)
+}
@@ -5,23 +5,53 @@ pub const IMPLEMENTERS_REGISTRY_KEY_NAME: &str = "implementers";
 pub const MANAGERS_REGISTRY_KEY_NAME: &str = "managers";
 ///
 pub const ERC1820_REGISTRY_CONTRACT_NAME: &str = "erc1820_registry";
+///
+pub const ERC1820_REGISTRY_PACKAGE_NAME: &str = "erc1820_registry_package_hash";
+///
+pub const ERC165_CACHE_REGISTRY_KEY_NAME: &str = "erc165_cache";
+/// Name of dictionary-key for the contracts which declared ERC165 support
+pub const ERC165_CONTRACTS_KEY_NAME: &str = "erc165_contracts";
+/// Name of dictionary-key for the interfaces registered by each account
+pub const INTERFACES_REGISTRY_KEY_NAME: &str = "interfaces";
+/// Name of dictionary-key for the accounts served by each implementer
+pub const ACCOUNTS_REGISTRY_KEY_NAME: &str = "implementer_accounts";
+/// Name of dictionary-key for `events`
+pub const EVENTS_KEY_NAME: &str = "events";
+/// Name of named-key for `events_count`
+pub const EVENTS_COUNT_KEY_NAME: &str = "events_count";
 
 /// Entry points
 pub const SET_INTERFACE_ENTRY_POINT: &str = "set_interface_implementer";
 ///
 pub const GET_INTERFACE_ENTRY_POINT: &str = "get_interface_implementer";
 ///
+pub const SET_INTERFACES_ENTRY_POINT: &str = "set_interface_implementers";
+///
+pub const CLEAR_INTERFACE_ENTRY_POINT: &str = "clear_interface_implementer";
+///
 pub const SET_MANAGER_ENTRY_POINT: &str = "set_manager";
 ///
 pub const GET_MANAGER_ENTRY_POINT: &str = "get_manager";
 ///
+pub const GET_INTERFACES_ENTRY_POINT: &str = "get_interfaces";
+///
+pub const GET_INTERFACE_COUNT_ENTRY_POINT: &str = "get_interface_count";
+///
+pub const GET_ACCOUNTS_FOR_IMPLEMENTER_ENTRY_POINT: &str = "get_accounts_for_implementer";
+///
 pub const INTERFACE_HASH: &str = "interface_hash";
 ///
-pub const UPDATE_ERC165_CACHE: &str = "update_erc165_Cache";
+pub const UPDATE_ERC165_CACHE: &str = "update_erc165_cache";
 ///
 pub const IMPLEMENTERS_ERC165_INTERFACE: &str = "implements_erc165_interface";
 ///
 pub const IMPLEMENTERS_ERC165_INTERFACE_NO_CACHE: &str = "implements_erc165_interface_no_cache";
+/// Entry point a contract calls to declare that it exposes `supports_interface`
+pub const DECLARE_ERC165_ENTRY_POINT: &str = "declare_erc165";
+/// Entry point a contract exposes to declare the ERC165 interfaces it implements
+pub const SUPPORTS_INTERFACE: &str = "supports_interface";
+/// Entry point an implementer contract exposes to accept acting for an account
+pub const CAN_IMPLEMENT_INTERFACE_FOR_ADDRESS: &str = "can_implement_interface_for_address";
 
 /// Entry point's parameter
 pub const ACCOUNT_RUNTIME_ARG_NAME: &str = "account";
@@ -34,7 +64,22 @@ pub const I_NAME_RUNTIME_ARG_NAME: &str = "interface_name";
 ///
 pub const IMPLEMENTER_RUNTIME_ARG_NAME: &str = "implementer";
 ///
+pub const IMPLEMENTERS_RUNTIME_ARG_NAME: &str = "implementers";
+/// Session argument with the interface names to register
+pub const I_NAMES_RUNTIME_ARG_NAME: &str = "interface_names";
+/// Session argument with the hash of the registry contract
+pub const REGISTRY_RUNTIME_ARG_NAME: &str = "erc1820_registry";
+///
 pub const I_ID_RUNTIME_ARG_NAME: &str = "interface_id";
+///
+pub const OFFSET_RUNTIME_ARG_NAME: &str = "offset";
+///
+pub const LIMIT_RUNTIME_ARG_NAME: &str = "limit";
 
+/// ERC165 id of `supports_interface` itself
+pub const ERC165_INTERFACE_ID: [u8; 4] = [0x01, 0xff, 0xc9, 0xa7];
+/// ERC165 id no contract may claim to support
+pub const INVALID_INTERFACE_ID: [u8; 4] = [0xff, 0xff, 0xff, 0xff];
 
-
+/// Name whose interface hash an implementer returns to accept acting for an account
+pub const ERC1820_ACCEPT_MAGIC: &str = "ERC1820_ACCEPT_MAGIC";
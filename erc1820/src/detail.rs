@@ -5,7 +5,9 @@ use casper_contract::{
     contract_api::runtime,
     unwrap_or_revert::UnwrapOrRevert,
 };
-use casper_types::{ApiError, URef};
+use casper_types::{system::CallStackElement, ApiError, ContractHash, Key, URef};
+
+use error::Error;
 
 /// Gets [`URef`] under a name.
 pub(crate) fn get_uref(name: &str) -> URef {
@@ -13,4 +15,41 @@ pub(crate) fn get_uref(name: &str) -> URef {
         .ok_or(ApiError::MissingKey)
         .unwrap_or_revert();
     key.try_into().unwrap_or_revert()
-}
\ No newline at end of file
+}
+
+/// Returns the key of a [`CallStackElement`], contracts are identified by their package hash.
+fn call_stack_element_to_key(call_stack_element: CallStackElement) -> Key {
+    match call_stack_element {
+        CallStackElement::Session { account_hash } => Key::from(account_hash),
+        CallStackElement::StoredSession { account_hash, .. } => Key::from(account_hash),
+        CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        } => Key::from(contract_package_hash),
+    }
+}
+
+/// Gets the immediate caller of the registry, the only key allowed to act as itself.
+///
+/// An account calling the registry, directly or through session code, is its account hash, and a
+/// contract calling it is its package hash. A contract called by an account never acts as that
+/// account.
+pub(crate) fn get_immediate_caller_key() -> Result<Key, Error> {
+    runtime::get_call_stack()
+        .into_iter()
+        .rev()
+        .nth(1)
+        .map(call_stack_element_to_key)
+        .ok_or(Error::InvalidContext)
+}
+
+/// Gets the hash of the contract calling the registry.
+///
+/// Returns [`Error::NotAContract`] when the immediate caller is an account.
+pub(crate) fn get_immediate_caller_contract() -> Result<ContractHash, Error> {
+    match runtime::get_call_stack().into_iter().rev().nth(1) {
+        Some(CallStackElement::StoredContract { contract_hash, .. }) => Ok(contract_hash),
+        Some(_) => Err(Error::NotAContract),
+        None => Err(Error::InvalidContext),
+    }
+}
@@ -1,5 +1,5 @@
 //! Contains definition of the entry points.
-use alloc::{string::String, vec};
+use alloc::{string::String, vec, vec::Vec};
 
 use casper_types::{
     {CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter, Key}
@@ -9,7 +9,13 @@ use casper_types::bytesrepr::Bytes;
 use crate::constants::{
     ACCOUNT_RUNTIME_ARG_NAME, GET_INTERFACE_ENTRY_POINT, I_HASH_RUNTIME_ARG_NAME,
     IMPLEMENTER_RUNTIME_ARG_NAME, SET_INTERFACE_ENTRY_POINT, SET_MANAGER_ENTRY_POINT,
-    GET_MANAGER_ENTRY_POINT, NEW_MANAGER_RUNTIME_ARG_NAME
+    GET_MANAGER_ENTRY_POINT, NEW_MANAGER_RUNTIME_ARG_NAME, UPDATE_ERC165_CACHE,
+    IMPLEMENTERS_ERC165_INTERFACE, IMPLEMENTERS_ERC165_INTERFACE_NO_CACHE, SUPPORTS_INTERFACE,
+    I_ID_RUNTIME_ARG_NAME, INTERFACE_HASH, I_NAME_RUNTIME_ARG_NAME,
+    CAN_IMPLEMENT_INTERFACE_FOR_ADDRESS, GET_INTERFACES_ENTRY_POINT, GET_INTERFACE_COUNT_ENTRY_POINT,
+    GET_ACCOUNTS_FOR_IMPLEMENTER_ENTRY_POINT, OFFSET_RUNTIME_ARG_NAME, LIMIT_RUNTIME_ARG_NAME,
+    CLEAR_INTERFACE_ENTRY_POINT, SET_INTERFACES_ENTRY_POINT, IMPLEMENTERS_RUNTIME_ARG_NAME,
+    DECLARE_ERC165_ENTRY_POINT
 };
 
 /// `get_manager`
@@ -68,12 +74,195 @@ pub fn set_interface_implementer() -> EntryPoint {
     )
 }
 
+/// `set_interface_implementers`
+pub fn set_interface_implementers() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_INTERFACES_ENTRY_POINT),
+        vec![
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(IMPLEMENTERS_RUNTIME_ARG_NAME, Vec::<(Bytes, Key)>::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// `clear_interface_implementer`
+pub fn clear_interface_implementer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CLEAR_INTERFACE_ENTRY_POINT),
+        vec![
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(I_HASH_RUNTIME_ARG_NAME, Bytes::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// `get_interfaces`
+pub fn get_interfaces() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_INTERFACES_ENTRY_POINT),
+        vec![
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
+        Vec::<Bytes>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// `get_interface_count`
+pub fn get_interface_count() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_INTERFACE_COUNT_ENTRY_POINT),
+        vec![
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// `get_accounts_for_implementer`
+pub fn get_accounts_for_implementer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_ACCOUNTS_FOR_IMPLEMENTER_ENTRY_POINT),
+        vec![
+            Parameter::new(IMPLEMENTER_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(OFFSET_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(LIMIT_RUNTIME_ARG_NAME, u64::cl_type())
+        ],
+        Vec::<(Key, Bytes)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// `interface_hash`
+pub fn interface_hash() -> EntryPoint {
+    EntryPoint::new(
+        String::from(INTERFACE_HASH),
+        vec![
+            Parameter::new(I_NAME_RUNTIME_ARG_NAME, String::cl_type())
+        ],
+        Bytes::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// `update_erc165_cache`
+pub fn update_erc165_cache() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UPDATE_ERC165_CACHE),
+        vec![
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(I_ID_RUNTIME_ARG_NAME, Bytes::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// `implements_erc165_interface`
+pub fn implements_erc165_interface() -> EntryPoint {
+    EntryPoint::new(
+        String::from(IMPLEMENTERS_ERC165_INTERFACE),
+        vec![
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(I_ID_RUNTIME_ARG_NAME, Bytes::cl_type())
+        ],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// `implements_erc165_interface_no_cache`
+pub fn implements_erc165_interface_no_cache() -> EntryPoint {
+    EntryPoint::new(
+        String::from(IMPLEMENTERS_ERC165_INTERFACE_NO_CACHE),
+        vec![
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(I_ID_RUNTIME_ARG_NAME, Bytes::cl_type())
+        ],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// `declare_erc165`
+///
+/// Contracts exposing [`supports_interface`] call it once, so the registry starts asking them about
+/// ERC165 interfaces.
+pub fn declare_erc165() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DECLARE_ERC165_ENTRY_POINT),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// `supports_interface`
+///
+/// Contracts which want to be detected through ERC165 add this entry point and return `true` for
+/// the 4-byte ids of the interfaces they implement, including `ERC165_INTERFACE_ID`, and `false`
+/// for `INVALID_INTERFACE_ID`.
+pub fn supports_interface() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SUPPORTS_INTERFACE),
+        vec![
+            Parameter::new(I_ID_RUNTIME_ARG_NAME, Bytes::cl_type())
+        ],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// `can_implement_interface_for_address`
+///
+/// Contracts which are registered as implementers add this entry point and return
+/// [`crate::accept_magic`] for the interface hashes and accounts they agree to act for.
+pub fn can_implement_interface_for_address() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CAN_IMPLEMENT_INTERFACE_FOR_ADDRESS),
+        vec![
+            Parameter::new(I_HASH_RUNTIME_ARG_NAME, Bytes::cl_type()),
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
+        Bytes::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the default set of ERC20 token entry points.
 pub fn default() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
     entry_points.add_entry_point(set_interface_implementer());
     entry_points.add_entry_point(get_interface_implementer());
+    entry_points.add_entry_point(set_interface_implementers());
+    entry_points.add_entry_point(clear_interface_implementer());
     entry_points.add_entry_point(set_manager());
     entry_points.add_entry_point(get_manager());
+    entry_points.add_entry_point(get_interfaces());
+    entry_points.add_entry_point(get_interface_count());
+    entry_points.add_entry_point(get_accounts_for_implementer());
+    entry_points.add_entry_point(interface_hash());
+    entry_points.add_entry_point(update_erc165_cache());
+    entry_points.add_entry_point(implements_erc165_interface());
+    entry_points.add_entry_point(implements_erc165_interface_no_cache());
+    entry_points.add_entry_point(declare_erc165());
+    entry_points.add_entry_point(supports_interface());
     entry_points
 }
@@ -0,0 +1,97 @@
+use alloc::string::{String};
+use alloc::vec::Vec;
+use casper_types::bytesrepr::{Bytes, ToBytes};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs, URef};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert
+};
+use constants::{
+    ERC165_CACHE_REGISTRY_KEY_NAME, ERC165_CONTRACTS_KEY_NAME, ERC165_INTERFACE_ID,
+    INVALID_INTERFACE_ID, I_ID_RUNTIME_ARG_NAME, SUPPORTS_INTERFACE
+};
+use detail;
+
+#[inline]
+pub(crate) fn erc165_cache_registry() -> URef {
+    detail::get_uref(ERC165_CACHE_REGISTRY_KEY_NAME)
+}
+
+#[inline]
+pub(crate) fn erc165_contracts() -> URef {
+    detail::get_uref(ERC165_CONTRACTS_KEY_NAME)
+}
+
+/// Returns the 4-byte ERC165 id carried by `interface_hash`, either as is or right-padded with
+/// zeros to 32 bytes, and `None` for any other interface hash.
+pub(crate) fn to_erc165_id(interface_hash: &Bytes) -> Option<Bytes> {
+    match interface_hash.len() {
+        4 => Some(interface_hash.clone()),
+        32 if interface_hash[4..].iter().all(|byte| *byte == 0) => {
+            Some(Bytes::from(interface_hash[..4].to_vec()))
+        }
+        _ => None
+    }
+}
+
+fn supports_interface(contract: ContractHash, interface_id: &[u8]) -> bool {
+    runtime::call_contract(
+        contract,
+        SUPPORTS_INTERFACE,
+        runtime_args! {
+            I_ID_RUNTIME_ARG_NAME => Bytes::from(interface_id.to_vec())
+        }
+    )
+}
+
+/// Records that `contract` exposes the `supports_interface` entry point.
+pub fn declare(contracts_uref: URef, contract: ContractHash) {
+    storage::dictionary_put(contracts_uref, hex::encode(contract.value()).as_str(), true);
+}
+
+/// Returns whether `contract` declared that it exposes the `supports_interface` entry point.
+pub fn is_declared(contracts_uref: URef, contract: ContractHash) -> bool {
+    storage::dictionary_get(contracts_uref, hex::encode(contract.value()).as_str())
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Asks `account` whether it implements `interface_id`.
+///
+/// Only contracts can implement ERC165 interfaces, and a `Key::Hash` is the hash of a contract,
+/// like the implementers of the registry, not of a contract package. Accounts, and contracts
+/// which did not declare ERC165 support, implement no ERC165 interface and are never called.
+pub fn implements_no_cache(contracts_uref: URef, account: Key, interface_id: &Bytes) -> bool {
+    let contract = match account {
+        Key::Hash(hash) => ContractHash::new(hash),
+        _ => return false
+    };
+    if !is_declared(contracts_uref, contract) {
+        return false;
+    }
+
+    if !supports_interface(contract, &ERC165_INTERFACE_ID) {
+        return false;
+    }
+    if supports_interface(contract, &INVALID_INTERFACE_ID) {
+        return false;
+    }
+    supports_interface(contract, interface_id)
+}
+
+pub fn read_cache(cache_uref: URef, account: Key, interface_id: &Bytes) -> Option<bool> {
+    storage::dictionary_get(cache_uref, to_str(account, interface_id).as_str()).unwrap_or_revert()
+}
+
+pub fn write_cache(cache_uref: URef, account: Key, interface_id: &Bytes, implements: bool) {
+    storage::dictionary_put(cache_uref, to_str(account, interface_id).as_str(), implements);
+}
+
+pub(crate) fn to_str(owner: Key, interface_id: &Bytes) -> String {
+    let mut preimage = Vec::new();
+    preimage.append(&mut owner.to_bytes().unwrap_or_revert());
+    preimage.extend_from_slice(interface_id);
+
+    let hash = runtime::blake2b(&preimage);
+    hex::encode(&hash)
+}
@@ -0,0 +1,66 @@
+//! Error handling on the casper platform.
+use casper_types::ApiError;
+
+/// Errors which can be returned by the library.
+///
+/// When an `Error` is returned from a smart contract, it is converted to an [`ApiError::User`].
+///
+/// The registry errors use the reserved range `[1000..1099]`, which does not conflict with the
+/// ranges of the ERC777 token and its sender and recipient hooks (`[(u16::MAX - 13)..u16::MAX]`
+/// and `[1300..1399]` for the send policies), nor with the ranges of the wrapped tokens
+/// (`[1100..1299]`). The codes are stable, new variants
+/// only take the next free code.
+///
+/// Where a smart contract consuming this library needs to define further error variants, it can
+/// return those via the [`Error::User`] variant or equivalently via the [`ApiError::User`]
+/// variant, with a code outside of the reserved ranges.
+pub enum Error {
+    /// The caller is not the manager of the account (code 1000).
+    NotManager,
+    /// The implementer is the address 0x00 (code 1001).
+    InvalidImplementer,
+    /// The interface id is not an ERC165 interface id (code 1002).
+    InvalidInterfaceId,
+    /// ERC165 interfaces are detected, they cannot be registered (code 1003).
+    ERC165InterfaceNotAllowed,
+    /// The implementer contract did not accept to act for the account (code 1004).
+    AcceptanceRejected,
+    /// The implementer is the manager of the account (code 1005).
+    ImplementerIsManager,
+    /// The registry was called from an invalid context (code 1006).
+    InvalidContext,
+    /// Only a contract can declare ERC165 support (code 1007).
+    NotAContract,
+    /// The events log is full (code 1008).
+    Overflow,
+    /// User error.
+    User(u16),
+}
+
+const ERROR_NOT_MANAGER: u16 = 1000;
+const ERROR_INVALID_IMPLEMENTER: u16 = 1001;
+const ERROR_INVALID_INTERFACE_ID: u16 = 1002;
+const ERROR_ERC165_INTERFACE_NOT_ALLOWED: u16 = 1003;
+const ERROR_ACCEPTANCE_REJECTED: u16 = 1004;
+const ERROR_IMPLEMENTER_IS_MANAGER: u16 = 1005;
+const ERROR_INVALID_CONTEXT: u16 = 1006;
+const ERROR_NOT_A_CONTRACT: u16 = 1007;
+const ERROR_OVERFLOW: u16 = 1008;
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        let user_error = match error {
+            Error::NotManager => ERROR_NOT_MANAGER,
+            Error::InvalidImplementer => ERROR_INVALID_IMPLEMENTER,
+            Error::InvalidInterfaceId => ERROR_INVALID_INTERFACE_ID,
+            Error::ERC165InterfaceNotAllowed => ERROR_ERC165_INTERFACE_NOT_ALLOWED,
+            Error::AcceptanceRejected => ERROR_ACCEPTANCE_REJECTED,
+            Error::ImplementerIsManager => ERROR_IMPLEMENTER_IS_MANAGER,
+            Error::InvalidContext => ERROR_INVALID_CONTEXT,
+            Error::NotAContract => ERROR_NOT_A_CONTRACT,
+            Error::Overflow => ERROR_OVERFLOW,
+            Error::User(user_error) => user_error,
+        };
+        ApiError::User(user_error)
+    }
+}
@@ -0,0 +1,76 @@
+//! Implementation of the events log of the registry.
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{bytesrepr::Bytes, Key, URef};
+
+use constants::{EVENTS_COUNT_KEY_NAME, EVENTS_KEY_NAME};
+use detail;
+use error::Error;
+
+/// Events recorded by the registry.
+pub(crate) enum ERC1820Event {
+    /// `implementer` was set for `interface_hash` on behalf of `account`.
+    InterfaceImplementerSet {
+        account: Key,
+        interface_hash: Bytes,
+        implementer: Key,
+    },
+    /// `new_manager` became the manager of `account`.
+    ManagerChanged {
+        account: Key,
+        new_manager: Key,
+    },
+}
+
+impl ERC1820Event {
+    /// Converts the event into the map stored in the events dictionary.
+    fn to_map(&self) -> BTreeMap<String, String> {
+        let mut map = BTreeMap::new();
+        match self {
+            ERC1820Event::InterfaceImplementerSet {
+                account,
+                interface_hash,
+                implementer,
+            } => {
+                map.insert("event_type".to_string(), "InterfaceImplementerSet".to_string());
+                map.insert("account".to_string(), account.to_formatted_string());
+                map.insert("interface_hash".to_string(), hex::encode(interface_hash.to_vec()));
+                map.insert("implementer".to_string(), implementer.to_formatted_string());
+            }
+            ERC1820Event::ManagerChanged {
+                account,
+                new_manager,
+            } => {
+                map.insert("event_type".to_string(), "ManagerChanged".to_string());
+                map.insert("account".to_string(), account.to_formatted_string());
+                map.insert("new_manager".to_string(), new_manager.to_formatted_string());
+            }
+        }
+        map
+    }
+}
+
+#[inline]
+pub(crate) fn events_uref() -> URef {
+    detail::get_uref(EVENTS_KEY_NAME)
+}
+
+#[inline]
+pub(crate) fn events_count_uref() -> URef {
+    detail::get_uref(EVENTS_COUNT_KEY_NAME)
+}
+
+/// Appends `event` to the events dictionary under the next index.
+pub(crate) fn emit(events_uref: URef, events_count_uref: URef, event: ERC1820Event) {
+    let index: u64 = storage::read(events_count_uref)
+        .unwrap_or_revert()
+        .unwrap_or_default();
+
+    storage::dictionary_put(events_uref, &index.to_string(), event.to_map());
+    let new_count = index.checked_add(1).unwrap_or_revert_with(Error::Overflow);
+    storage::write(events_count_uref, new_count);
+}
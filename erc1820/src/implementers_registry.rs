@@ -1,13 +1,18 @@
 use alloc::string::{String};
 use alloc::vec::Vec;
 use casper_types::bytesrepr::{Bytes, ToBytes};
-use casper_types::{ApiError, Key, URef};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs, URef};
 use casper_types::account::AccountHash;
-use ::{detail};
+use ::{detail, accept_magic};
 use casper_contract::{
-    contract_api::{runtime, storage}
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert
 };
-use constants::{IMPLEMENTERS_REGISTRY_KEY_NAME};
+use constants::{
+    IMPLEMENTERS_REGISTRY_KEY_NAME, CAN_IMPLEMENT_INTERFACE_FOR_ADDRESS, I_HASH_RUNTIME_ARG_NAME,
+    ACCOUNT_RUNTIME_ARG_NAME
+};
+use error::Error;
 
 #[inline]
 pub(crate) fn implementers_registry() -> URef {
@@ -16,20 +21,37 @@ pub(crate) fn implementers_registry() -> URef {
 
 pub fn create_or_update_implementer(
     implementer_uref: URef,
+    caller: Key,
     account: Key,
     interface_hash: Bytes,
     implementer: Key,
     manager: Key
-) -> Result<(), ApiError> {
+) -> Result<(), Error> {
     let hash_string: String;
 
-    if manager.ne(&account) {
-        return Err(ApiError::User(1000));
+    // Only the manager of the account, which is the account itself by default, can set its implementers.
+    if caller.ne(&manager) {
+        return Err(Error::NotManager);
+    }
+
+    if implementer.eq(&Key::Account(AccountHash::default())) {
+        return Err(Error::InvalidImplementer)
+    }
+
+    if implementer.eq(&manager) {
+        return Err(Error::ImplementerIsManager)
     }
 
-    if implementer.eq(&manager) || implementer.eq(&Key::Account(AccountHash::default())) {
-        
-        return Err(ApiError::User(1001))
+    // A contract implementer has to agree to act for the account.
+    if let Key::Hash(hash) = implementer {
+        let answer = can_implement_interface_for_address(
+            ContractHash::new(hash),
+            account,
+            interface_hash.clone()
+        );
+        if answer.ne(&accept_magic()) {
+            return Err(Error::AcceptanceRejected);
+        }
     }
 
     hash_string = to_str(account, interface_hash);
@@ -41,19 +63,56 @@ pub fn create_or_update_implementer(
     Ok(())
 }
 
+/// Removes the implementer of `interface_hash` for `account`, only its manager can do it.
+///
+/// The address 0x00 is stored back, which is what `get_implementer` returns for an interface
+/// which was never registered.
+pub fn clear_implementer(
+    implementer_uref: URef,
+    caller: Key,
+    account: Key,
+    interface_hash: Bytes,
+    manager: Key
+) -> Result<(), Error> {
+    if caller.ne(&manager) {
+        return Err(Error::NotManager);
+    }
+
+    storage::dictionary_put(
+        implementer_uref,
+        to_str(account, interface_hash).as_str(),
+        Key::Account(AccountHash::default()));
+    Ok(())
+}
+
+fn can_implement_interface_for_address(
+    implementer: ContractHash,
+    account: Key,
+    interface_hash: Bytes
+) -> Bytes {
+    runtime::call_contract(
+        implementer,
+        CAN_IMPLEMENT_INTERFACE_FOR_ADDRESS,
+        runtime_args! {
+            I_HASH_RUNTIME_ARG_NAME => interface_hash,
+            ACCOUNT_RUNTIME_ARG_NAME => account
+        }
+    )
+}
+
 pub fn get_implementer(implementer_uref: URef, account: Key, interface_hash: Bytes) -> Key {
     let hash_string = to_str(account, interface_hash);
     let implementer = storage::dictionary_get(
         implementer_uref,
         hash_string.as_str()
-    ).unwrap().unwrap_or(Key::Account(AccountHash::default()));
+    ).unwrap_or_revert().unwrap_or(Key::Account(AccountHash::default()));
 
     implementer
 }
 
 pub(crate) fn to_str(owner: Key, tag: Bytes) -> String {
     let mut preimage = Vec::new();
-    preimage.append(&mut owner.to_bytes().unwrap());
+    preimage.append(&mut owner.to_bytes().unwrap_or_revert());
     preimage.append(&mut tag.to_vec());
 
     let hash = runtime::blake2b(&preimage);
@@ -0,0 +1,112 @@
+//! Lists of items stored in a dictionary, one entry per index.
+//!
+//! Every list belongs to an owner key and is kept in three kinds of dictionary items: the number
+//! of items, the item at each index, and the position of each item. Adding, removing and counting
+//! items, or reading a page of them, never loads the whole list.
+use alloc::string::String;
+use alloc::vec::Vec;
+use casper_types::bytesrepr::{FromBytes, ToBytes};
+use casper_types::{CLTyped, Key, URef};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert
+};
+
+const COUNT_TAG: u8 = 0;
+const ITEM_TAG: u8 = 1;
+const POSITION_TAG: u8 = 2;
+
+/// Returns the number of items of the list of `owner`.
+pub fn count(list_uref: URef, owner: Key) -> u64 {
+    storage::dictionary_get(list_uref, count_key(owner).as_str())
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Returns whether `item` is in the list of `owner`, without reading the list.
+pub fn contains<T: ToBytes>(list_uref: URef, owner: Key, item: &T) -> bool {
+    position(list_uref, owner, item) != 0
+}
+
+/// Appends `item` to the list of `owner`, unless it is already there.
+pub fn add<T: CLTyped + ToBytes>(list_uref: URef, owner: Key, item: T) {
+    if contains(list_uref, owner, &item) {
+        return;
+    }
+
+    let index = count(list_uref, owner);
+    // Positions are stored one-based, so a missing item reads as 0.
+    storage::dictionary_put(list_uref, position_key(owner, &item).as_str(), index + 1);
+    storage::dictionary_put(list_uref, item_key(owner, index).as_str(), item);
+    storage::dictionary_put(list_uref, count_key(owner).as_str(), index + 1);
+}
+
+/// Removes `item` from the list of `owner`.
+///
+/// The last item of the list takes the index of the removed one, so the order of the remaining
+/// items is not preserved.
+pub fn remove<T: CLTyped + ToBytes + FromBytes>(list_uref: URef, owner: Key, item: &T) {
+    let position = position(list_uref, owner, item);
+    if position == 0 {
+        return;
+    }
+
+    let index = position - 1;
+    let last_index = count(list_uref, owner) - 1;
+    if index != last_index {
+        let last_item: T = get(list_uref, owner, last_index).unwrap_or_revert();
+        storage::dictionary_put(list_uref, position_key(owner, &last_item).as_str(), position);
+        storage::dictionary_put(list_uref, item_key(owner, index).as_str(), last_item);
+    }
+
+    storage::dictionary_put(list_uref, position_key(owner, item).as_str(), 0u64);
+    storage::dictionary_put(list_uref, count_key(owner).as_str(), last_index);
+}
+
+/// Returns the item at `index` of the list of `owner`.
+pub fn get<T: CLTyped + FromBytes>(list_uref: URef, owner: Key, index: u64) -> Option<T> {
+    if index >= count(list_uref, owner) {
+        return None;
+    }
+    storage::dictionary_get(list_uref, item_key(owner, index).as_str()).unwrap_or_revert()
+}
+
+/// Returns at most `limit` items of the list of `owner`, skipping the first `offset` ones.
+pub fn page<T: CLTyped + FromBytes>(list_uref: URef, owner: Key, offset: u64, limit: u64) -> Vec<T> {
+    let end = count(list_uref, owner).min(offset.saturating_add(limit));
+    (offset..end)
+        .map(|index| {
+            storage::dictionary_get(list_uref, item_key(owner, index).as_str())
+                .unwrap_or_revert()
+                .unwrap_or_revert()
+        })
+        .collect()
+}
+
+fn position<T: ToBytes>(list_uref: URef, owner: Key, item: &T) -> u64 {
+    storage::dictionary_get(list_uref, position_key(owner, item).as_str())
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+fn count_key(owner: Key) -> String {
+    to_str(COUNT_TAG, owner, &[])
+}
+
+fn item_key(owner: Key, index: u64) -> String {
+    to_str(ITEM_TAG, owner, &index.to_le_bytes())
+}
+
+fn position_key<T: ToBytes>(owner: Key, item: &T) -> String {
+    to_str(POSITION_TAG, owner, &item.to_bytes().unwrap_or_revert())
+}
+
+fn to_str(tag: u8, owner: Key, suffix: &[u8]) -> String {
+    let mut preimage = Vec::new();
+    preimage.push(tag);
+    preimage.append(&mut owner.to_bytes().unwrap_or_revert());
+    preimage.extend_from_slice(suffix);
+
+    let hash = runtime::blake2b(&preimage);
+    hex::encode(&hash)
+}
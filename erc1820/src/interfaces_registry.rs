@@ -0,0 +1,30 @@
+use alloc::vec::Vec;
+use casper_types::bytesrepr::Bytes;
+use casper_types::{Key, URef};
+use constants::INTERFACES_REGISTRY_KEY_NAME;
+use ::{detail, indexed_list};
+
+#[inline]
+pub(crate) fn interfaces_registry() -> URef {
+    detail::get_uref(INTERFACES_REGISTRY_KEY_NAME)
+}
+
+/// Adds `interface_hash` to the interfaces registered by `account`, unless it is already there.
+pub fn add_interface(interfaces_uref: URef, account: Key, interface_hash: Bytes) {
+    indexed_list::add(interfaces_uref, account, interface_hash);
+}
+
+/// Removes `interface_hash` from the interfaces registered by `account`.
+pub fn remove_interface(interfaces_uref: URef, account: Key, interface_hash: Bytes) {
+    indexed_list::remove(interfaces_uref, account, &interface_hash);
+}
+
+/// Returns the number of interface hashes registered by `account`.
+pub fn get_interface_count(interfaces_uref: URef, account: Key) -> u64 {
+    indexed_list::count(interfaces_uref, account)
+}
+
+/// Returns the interface hashes registered by `account`.
+pub fn get_interfaces(interfaces_uref: URef, account: Key) -> Vec<Bytes> {
+    indexed_list::page(interfaces_uref, account, 0, u64::MAX)
+}
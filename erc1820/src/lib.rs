@@ -15,9 +15,16 @@ pub mod constants;
 pub mod entry_points;
 mod implementers_registry;
 mod managers_registry;
+mod interfaces_registry;
+mod accounts_registry;
+pub mod indexed_list;
+mod erc165;
+mod events;
+mod error;
 mod detail;
 
-use alloc::string::{ToString};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use core::convert::TryInto;
 
 use once_cell::unsync::OnceCell;
@@ -26,25 +33,68 @@ use casper_contract::{
     contract_api::{runtime, storage},
     unwrap_or_revert::UnwrapOrRevert,
 };
-use casper_types::{{contracts::NamedKeys, EntryPoints, Key, URef}, ApiError};
+use casper_types::{contracts::NamedKeys, EntryPoints, Key, URef};
 use casper_types::bytesrepr::Bytes;
+use casper_types::account::AccountHash;
 
 use constants::{
-    ERC1820_REGISTRY_CONTRACT_NAME, IMPLEMENTERS_REGISTRY_KEY_NAME, MANAGERS_REGISTRY_KEY_NAME
+    ERC1820_REGISTRY_CONTRACT_NAME, IMPLEMENTERS_REGISTRY_KEY_NAME, MANAGERS_REGISTRY_KEY_NAME,
+    ERC1820_REGISTRY_PACKAGE_NAME, ERC165_CACHE_REGISTRY_KEY_NAME, ERC165_CONTRACTS_KEY_NAME,
+    ERC165_INTERFACE_ID, ERC1820_ACCEPT_MAGIC, EVENTS_KEY_NAME, EVENTS_COUNT_KEY_NAME, INTERFACES_REGISTRY_KEY_NAME,
+    ACCOUNTS_REGISTRY_KEY_NAME
 };
+use events::ERC1820Event;
+
+pub use error::Error;
+
+/// Returns the canonical hash of `interface_name`, which is the blake2b-256 hash of its UTF-8
+/// bytes.
+///
+/// Implementers of an interface are registered under this 32-byte hash, so every contract asking
+/// the registry about an interface should compute it through this function.
+pub fn interface_hash(interface_name: &str) -> Bytes {
+    Bytes::from(runtime::blake2b(interface_name.as_bytes()).to_vec())
+}
+
+/// Returns the value an implementer contract answers from `can_implement_interface_for_address`
+/// to accept acting for an account.
+pub fn accept_magic() -> Bytes {
+    interface_hash(ERC1820_ACCEPT_MAGIC)
+}
 
 /// Struct
 #[derive(Default)]
 pub struct ERC1820 {
     implementer_uref: OnceCell<URef>,
-    manager_uref: OnceCell<URef>
+    manager_uref: OnceCell<URef>,
+    erc165_cache_uref: OnceCell<URef>,
+    erc165_contracts_uref: OnceCell<URef>,
+    interfaces_uref: OnceCell<URef>,
+    accounts_uref: OnceCell<URef>,
+    events_uref: OnceCell<URef>,
+    events_count_uref: OnceCell<URef>
 }
 
 impl ERC1820 {
-    fn new(implementer_uref: URef, manager_uref: URef) -> Self {
+    fn new(
+        implementer_uref: URef,
+        manager_uref: URef,
+        erc165_cache_uref: URef,
+        erc165_contracts_uref: URef,
+        interfaces_uref: URef,
+        accounts_uref: URef,
+        events_uref: URef,
+        events_count_uref: URef
+    ) -> Self {
         Self {
             implementer_uref: implementer_uref.into(),
-            manager_uref: manager_uref.into()
+            manager_uref: manager_uref.into(),
+            erc165_cache_uref: erc165_cache_uref.into(),
+            erc165_contracts_uref: erc165_contracts_uref.into(),
+            interfaces_uref: interfaces_uref.into(),
+            accounts_uref: accounts_uref.into(),
+            events_uref: events_uref.into(),
+            events_count_uref: events_count_uref.into()
         }
     }
 
@@ -56,24 +106,165 @@ impl ERC1820 {
         *self.manager_uref.get_or_init(managers_registry::managers_registry)
     }
 
+    fn erc165_cache_uref(&self) -> URef {
+        *self.erc165_cache_uref.get_or_init(erc165::erc165_cache_registry)
+    }
+
+    fn erc165_contracts_uref(&self) -> URef {
+        *self.erc165_contracts_uref.get_or_init(erc165::erc165_contracts)
+    }
+
+    fn interfaces_registry_uref(&self) -> URef {
+        *self.interfaces_uref.get_or_init(interfaces_registry::interfaces_registry)
+    }
+
+    fn accounts_registry_uref(&self) -> URef {
+        *self.accounts_uref.get_or_init(accounts_registry::accounts_registry)
+    }
+
+    fn events_uref(&self) -> URef {
+        *self.events_uref.get_or_init(events::events_uref)
+    }
+
+    fn events_count_uref(&self) -> URef {
+        *self.events_count_uref.get_or_init(events::events_count_uref)
+    }
+
+    fn emit(&self, event: ERC1820Event) {
+        events::emit(self.events_uref(), self.events_count_uref(), event)
+    }
+
     /// Returns the name of the token.
     pub fn set_interface_implementer(
         &self,
         account: Key,
         i_hash: Bytes,
         implementer: Key
-    ) -> Result<(), ApiError> {
+    ) -> Result<(), Error> {
+        self.register_implementer(
+            detail::get_immediate_caller_key()?,
+            managers_registry::get_manager(self.managers_registry_uref(), account),
+            account,
+            i_hash,
+            implementer
+        )
+    }
+
+    /// Sets every `(i_hash, implementer)` pair of `implementers` for `account`.
+    ///
+    /// The manager is checked once, and any invalid registration reverts the whole batch.
+    pub fn set_interface_implementers(
+        &self,
+        account: Key,
+        implementers: Vec<(Bytes, Key)>
+    ) -> Result<(), Error> {
+        let caller = detail::get_immediate_caller_key()?;
+        let manager = managers_registry::get_manager(self.managers_registry_uref(), account);
+        if caller.ne(&manager) {
+            return Err(Error::NotManager);
+        }
+
+        for (i_hash, implementer) in implementers {
+            self.register_implementer(caller, manager, account, i_hash, implementer)?;
+        }
+        Ok(())
+    }
+
+    fn register_implementer(
+        &self,
+        caller: Key,
+        manager: Key,
+        account: Key,
+        i_hash: Bytes,
+        implementer: Key
+    ) -> Result<(), Error> {
+        // ERC165 interfaces are detected through `supports_interface`, never registered.
+        if erc165::to_erc165_id(&i_hash).is_some() {
+            return Err(Error::ERC165InterfaceNotAllowed);
+        }
+
+        let previous_implementer = implementers_registry::get_implementer(
+            self.implementer_registry_uref(),
+            account,
+            i_hash.clone()
+        );
+
         implementers_registry::create_or_update_implementer(
             self.implementer_registry_uref(),
+            caller,
             account,
-            i_hash,
+            i_hash.clone(),
             implementer,
+            manager
+        )?;
+        interfaces_registry::add_interface(self.interfaces_registry_uref(), account, i_hash.clone());
+        accounts_registry::remove_account(
+            self.accounts_registry_uref(),
+            previous_implementer,
+            account,
+            i_hash.clone()
+        );
+        accounts_registry::add_account(self.accounts_registry_uref(), implementer, account, i_hash.clone());
+
+        self.emit(ERC1820Event::InterfaceImplementerSet {
+            account,
+            interface_hash: i_hash,
+            implementer
+        });
+        Ok(())
+    }
+
+    /// Removes the implementer of `i_hash` for `account`, so
+    /// [`ERC1820::get_interface_implementer`] returns the address 0x00 again.
+    ///
+    /// Only the manager of `account` can call it, and ERC777 tokens then skip the hook.
+    pub fn clear_interface_implementer(&self, account: Key, i_hash: Bytes) -> Result<(), Error> {
+        if erc165::to_erc165_id(&i_hash).is_some() {
+            return Err(Error::ERC165InterfaceNotAllowed);
+        }
+
+        let previous_implementer = implementers_registry::get_implementer(
+            self.implementer_registry_uref(),
+            account,
+            i_hash.clone()
+        );
+
+        implementers_registry::clear_implementer(
+            self.implementer_registry_uref(),
+            detail::get_immediate_caller_key()?,
+            account,
+            i_hash.clone(),
             managers_registry::get_manager(self.managers_registry_uref(), account)
-        )
+        )?;
+        interfaces_registry::remove_interface(self.interfaces_registry_uref(), account, i_hash.clone());
+        accounts_registry::remove_account(
+            self.accounts_registry_uref(),
+            previous_implementer,
+            account,
+            i_hash.clone()
+        );
+
+        self.emit(ERC1820Event::InterfaceImplementerSet {
+            account,
+            interface_hash: i_hash,
+            implementer: Key::Account(AccountHash::default())
+        });
+        Ok(())
     }
 
     /// Returns the symbol of the token.
-    pub fn get_interface_implementer(&self, account: Key, i_hash: Bytes) -> Result<Key, ApiError> {
+    ///
+    /// For an ERC165 interface id, `account` is returned when it implements the interface.
+    pub fn get_interface_implementer(&self, account: Key, i_hash: Bytes) -> Result<Key, Error> {
+        if let Some(interface_id) = erc165::to_erc165_id(&i_hash) {
+            let result = if self.implements_erc165_interface(account, interface_id)? {
+                account
+            } else {
+                Key::Account(AccountHash::default())
+            };
+            return Ok(result);
+        }
+
         let result = implementers_registry::get_implementer(
             self.implementer_registry_uref(),
             account,
@@ -83,29 +274,106 @@ impl ERC1820 {
         Ok(result)
     }
 
-    /// it adds a new manager for performing operations
-    pub fn set_manager(&self, account: Key, new_manager: Key) -> Result<(), ApiError> {
-        managers_registry::set_manager(
+    /// it adds a new manager for performing operations, setting the account itself or the zero key
+    /// resets it
+    pub fn set_manager(&self, account: Key, new_manager: Key) -> Result<(), Error> {
+        let new_manager = managers_registry::set_manager(
             self.managers_registry_uref(),
+            detail::get_immediate_caller_key()?,
             account,
             new_manager
-        )
+        )?;
+
+        self.emit(ERC1820Event::ManagerChanged {
+            account,
+            new_manager
+        });
+        Ok(())
+    }
+
+    /// Returns the interface hashes `account` has registered an implementer for.
+    ///
+    /// Removing an interface moves the last registered one into its place.
+    pub fn get_interfaces(&self, account: Key) -> Vec<Bytes> {
+        interfaces_registry::get_interfaces(self.interfaces_registry_uref(), account)
+    }
+
+    /// Returns the number of interface hashes `account` has registered an implementer for.
+    pub fn get_interface_count(&self, account: Key) -> u64 {
+        interfaces_registry::get_interface_count(self.interfaces_registry_uref(), account)
+    }
+
+    /// Returns at most `limit` of the `(account, interface_hash)` pairs `implementer` is registered
+    /// for, skipping the first `offset` ones.
+    ///
+    /// Removing a pair moves the last registered one into its place, so pages read while the
+    /// implementer gains or loses accounts may skip or repeat pairs.
+    pub fn get_accounts_for_implementer(&self, implementer: Key, offset: u64, limit: u64) -> Vec<(Key, Bytes)> {
+        accounts_registry::get_accounts(self.accounts_registry_uref(), implementer, offset, limit)
     }
 
     /// it returns a manager for the parameter account
-    pub fn get_manager(&self, account: Key) -> Result<Key, ApiError> {
+    pub fn get_manager(&self, account: Key) -> Result<Key, Error> {
         let manager = managers_registry::get_manager(
-            self.implementer_registry_uref(),
+            self.managers_registry_uref(),
             account
         );
 
         Ok(manager)
     }
 
+    /// Returns the canonical hash of `interface_name`.
+    pub fn interface_hash(&self, interface_name: String) -> Bytes {
+        interface_hash(&interface_name)
+    }
+
+    /// Checks whether `account` implements the ERC165 interface `interface_id`, and stores the
+    /// result so [`ERC1820::implements_erc165_interface`] does not need to ask `account` again.
+    pub fn update_erc165_cache(&self, account: Key, interface_id: Bytes) -> Result<(), Error> {
+        let interface_id = erc165::to_erc165_id(&interface_id).ok_or(Error::InvalidInterfaceId)?;
+        let implements = erc165::implements_no_cache(self.erc165_contracts_uref(), account, &interface_id);
+        erc165::write_cache(self.erc165_cache_uref(), account, &interface_id, implements);
+        Ok(())
+    }
+
+    /// Checks whether `account` implements the ERC165 interface `interface_id`, using the cached
+    /// result when [`ERC1820::update_erc165_cache`] has been called for it.
+    pub fn implements_erc165_interface(&self, account: Key, interface_id: Bytes) -> Result<bool, Error> {
+        let interface_id = erc165::to_erc165_id(&interface_id).ok_or(Error::InvalidInterfaceId)?;
+        let implements = erc165::read_cache(self.erc165_cache_uref(), account, &interface_id)
+            .unwrap_or_else(|| {
+                erc165::implements_no_cache(self.erc165_contracts_uref(), account, &interface_id)
+            });
+        Ok(implements)
+    }
+
+    /// Checks whether `account` implements the ERC165 interface `interface_id` by calling its
+    /// `supports_interface` entry point, without looking at the cache.
+    pub fn implements_erc165_interface_no_cache(&self, account: Key, interface_id: Bytes) -> Result<bool, Error> {
+        let interface_id = erc165::to_erc165_id(&interface_id).ok_or(Error::InvalidInterfaceId)?;
+        Ok(erc165::implements_no_cache(self.erc165_contracts_uref(), account, &interface_id))
+    }
+
+    /// Declares that the calling contract exposes the `supports_interface` entry point.
+    ///
+    /// The registry only asks declared contracts about ERC165 interfaces, any other contract is
+    /// treated as implementing none of them. The declaration is bound to the contract hash, so
+    /// every new version of a contract package declares it again.
+    pub fn declare_erc165(&self) -> Result<(), Error> {
+        let contract = detail::get_immediate_caller_contract()?;
+        erc165::declare(self.erc165_contracts_uref(), contract);
+        Ok(())
+    }
+
+    /// The registry implements ERC165 itself, and only declares `supports_interface`.
+    pub fn supports_interface(&self, interface_id: Bytes) -> bool {
+        interface_id.to_vec() == ERC165_INTERFACE_ID.to_vec()
+    }
+
     /// Installs the ERC1820 contract with the default set of entry points.
     ///
     /// This should be called from within `fn call()` of your contract.
-    pub fn install() -> Result<ERC1820, ApiError> {
+    pub fn install() -> Result<ERC1820, Error> {
         let default_entry_points = entry_points::default();
         ERC1820::install_custom(
             ERC1820_REGISTRY_CONTRACT_NAME,
@@ -124,7 +392,7 @@ impl ERC1820 {
     pub fn install_custom(
         contract_key_name: &str,
         entry_points: EntryPoints,
-    ) -> Result<ERC1820, ApiError> {
+    ) -> Result<ERC1820, Error> {
 
         let implementer_uref: URef;
         if runtime::get_key(IMPLEMENTERS_REGISTRY_KEY_NAME).is_some() {
@@ -148,6 +416,71 @@ impl ERC1820 {
                 .unwrap_or_revert();
         }
 
+        let erc165_cache_uref: URef;
+        if runtime::get_key(ERC165_CACHE_REGISTRY_KEY_NAME).is_some() {
+            erc165_cache_uref = runtime::get_key(ERC165_CACHE_REGISTRY_KEY_NAME)
+                .unwrap_or_revert()
+                .try_into()
+                .unwrap_or_revert();
+        } else {
+            erc165_cache_uref = storage::new_dictionary(ERC165_CACHE_REGISTRY_KEY_NAME)
+                .unwrap_or_revert();
+        }
+
+        let erc165_contracts_uref: URef;
+        if runtime::get_key(ERC165_CONTRACTS_KEY_NAME).is_some() {
+            erc165_contracts_uref = runtime::get_key(ERC165_CONTRACTS_KEY_NAME)
+                .unwrap_or_revert()
+                .try_into()
+                .unwrap_or_revert();
+        } else {
+            erc165_contracts_uref = storage::new_dictionary(ERC165_CONTRACTS_KEY_NAME)
+                .unwrap_or_revert();
+        }
+
+        let interfaces_uref: URef;
+        if runtime::get_key(INTERFACES_REGISTRY_KEY_NAME).is_some() {
+            interfaces_uref = runtime::get_key(INTERFACES_REGISTRY_KEY_NAME)
+                .unwrap_or_revert()
+                .try_into()
+                .unwrap_or_revert();
+        } else {
+            interfaces_uref = storage::new_dictionary(INTERFACES_REGISTRY_KEY_NAME)
+                .unwrap_or_revert();
+        }
+
+        let accounts_uref: URef;
+        if runtime::get_key(ACCOUNTS_REGISTRY_KEY_NAME).is_some() {
+            accounts_uref = runtime::get_key(ACCOUNTS_REGISTRY_KEY_NAME)
+                .unwrap_or_revert()
+                .try_into()
+                .unwrap_or_revert();
+        } else {
+            accounts_uref = storage::new_dictionary(ACCOUNTS_REGISTRY_KEY_NAME)
+                .unwrap_or_revert();
+        }
+
+        let events_uref: URef;
+        if runtime::get_key(EVENTS_KEY_NAME).is_some() {
+            events_uref = runtime::get_key(EVENTS_KEY_NAME)
+                .unwrap_or_revert()
+                .try_into()
+                .unwrap_or_revert();
+        } else {
+            events_uref = storage::new_dictionary(EVENTS_KEY_NAME)
+                .unwrap_or_revert();
+        }
+
+        let events_count_uref: URef;
+        if runtime::get_key(EVENTS_COUNT_KEY_NAME).is_some() {
+            events_count_uref = runtime::get_key(EVENTS_COUNT_KEY_NAME)
+                .unwrap_or_revert()
+                .try_into()
+                .unwrap_or_revert();
+        } else {
+            events_count_uref = storage::new_uref(0u64).into_read_write();
+        }
+
         let mut named_keys = NamedKeys::new();
 
         let implementer_key = {
@@ -160,18 +493,68 @@ impl ERC1820 {
             Key::from(manager_uref)
         };
 
+        let erc165_cache_key = {
+            runtime::remove_key(ERC165_CACHE_REGISTRY_KEY_NAME);
+            Key::from(erc165_cache_uref)
+        };
+
+        let erc165_contracts_key = {
+            runtime::remove_key(ERC165_CONTRACTS_KEY_NAME);
+            Key::from(erc165_contracts_uref)
+        };
+
         named_keys.insert(IMPLEMENTERS_REGISTRY_KEY_NAME.to_string(), implementer_key);
         named_keys.insert(MANAGERS_REGISTRY_KEY_NAME.to_string(), manager_key);
+        let interfaces_key = {
+            runtime::remove_key(INTERFACES_REGISTRY_KEY_NAME);
+            Key::from(interfaces_uref)
+        };
 
-        let (contract_hash, _version) =
-            storage::new_contract(entry_points, Some(named_keys), None, None);
+        let accounts_key = {
+            runtime::remove_key(ACCOUNTS_REGISTRY_KEY_NAME);
+            Key::from(accounts_uref)
+        };
+
+        let events_key = {
+            runtime::remove_key(EVENTS_KEY_NAME);
+            Key::from(events_uref)
+        };
+
+        let events_count_key = {
+            runtime::remove_key(EVENTS_COUNT_KEY_NAME);
+            Key::from(events_count_uref)
+        };
+
+        named_keys.insert(ERC165_CACHE_REGISTRY_KEY_NAME.to_string(), erc165_cache_key);
+        named_keys.insert(ERC165_CONTRACTS_KEY_NAME.to_string(), erc165_contracts_key);
+        named_keys.insert(INTERFACES_REGISTRY_KEY_NAME.to_string(), interfaces_key);
+        named_keys.insert(ACCOUNTS_REGISTRY_KEY_NAME.to_string(), accounts_key);
+        named_keys.insert(EVENTS_KEY_NAME.to_string(), events_key);
+        named_keys.insert(EVENTS_COUNT_KEY_NAME.to_string(), events_count_key);
+
+        // Hash of the contract package will be reachable through named keys.
+        let (contract_hash, _version) = storage::new_contract(
+            entry_points,
+            Some(named_keys),
+            Some(ERC1820_REGISTRY_PACKAGE_NAME.to_string()),
+            None
+        );
 
         // Hash of the installed contract will be reachable through named keys.
         runtime::put_key(contract_key_name, Key::from(contract_hash));
 
+        // The registry answers ERC165 queries about itself through `supports_interface`.
+        erc165::declare(erc165_contracts_uref, contract_hash);
+
         Ok(ERC1820::new(
             implementer_uref,
-            manager_uref
+            manager_uref,
+            erc165_cache_uref,
+            erc165_contracts_uref,
+            interfaces_uref,
+            accounts_uref,
+            events_uref,
+            events_count_uref
         ))
     }
 }
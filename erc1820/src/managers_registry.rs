@@ -1,46 +1,60 @@
 use alloc::string::{String};
 use casper_types::bytesrepr::{ToBytes};
-use casper_types::{ApiError, Key, URef};
+use casper_types::account::AccountHash;
+use casper_types::{Key, URef};
 use casper_contract::{
-    contract_api::{runtime, storage}
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert
 };
 use constants::MANAGERS_REGISTRY_KEY_NAME;
 use detail;
+use error::Error;
 
 #[inline]
 pub(crate) fn managers_registry() -> URef {
     detail::get_uref(MANAGERS_REGISTRY_KEY_NAME)
 }
 
-pub fn set_manager(manager_uref: URef, account: Key, manager: Key) -> Result<(), ApiError>{
+/// Sets the manager of `account`, only its current manager (the caller) can do it, and returns the
+/// manager it was set to.
+///
+/// Setting the account itself or the zero key as the manager resets it to the default, as a zero
+/// manager could never call the registry and the account would lose control of its implementers.
+pub fn set_manager(manager_uref: URef, caller: Key, account: Key, manager: Key) -> Result<Key, Error>{
 
     let previous_manager = get_manager(manager_uref, account);
-    if previous_manager.ne(&account) {
-        return Err(ApiError::User(999))
+    if previous_manager.ne(&caller) {
+        return Err(Error::NotManager)
     }
 
+    let manager = if manager.eq(&Key::Account(AccountHash::default())) {
+        account
+    } else {
+        manager
+    };
+
     let hash_account = to_str(account);
 
     storage::dictionary_put(
         manager_uref,
         hash_account.as_str(),
         manager);
-    Ok(())
+    Ok(manager)
 }
 
+/// Returns the manager of `account`, which is the account itself unless another one was set.
 pub fn get_manager(manager_uref: URef, account: Key) -> Key {
-    let caller = runtime::get_caller();
     let hash_string = to_str(account);
     let manager: Key = storage::dictionary_get(
         manager_uref,
         hash_string.as_str()
-    ).unwrap().unwrap_or(Key::Account(caller));
+    ).unwrap_or_revert().unwrap_or(account);
 
     manager
 }
 
 pub(crate) fn to_str(owner: Key) -> String {
-    let key_bytes = owner.to_bytes().unwrap();
+    let key_bytes = owner.to_bytes().unwrap_or_revert();
     let hash = runtime::blake2b(&key_bytes);
     hex::encode(&hash)
 }
@@ -0,0 +1,58 @@
+//! Constants shared by the contracts receiving ERC777 tokens on their own contract package.
+
+/// Suffix of the named-key under which the installer keeps the hash of the contract package
+pub const PACKAGE_HASH_KEY_NAME_SUFFIX: &str = "_package_hash";
+
+/// Name of named-key for the owner
+pub const OWNER_KEY_NAME: &str = "owner";
+/// Name of named-key for the hash of the erc1820 registry
+pub const ERC1820_REGISTRY_KEY_NAME: &str = "erc1820_registry";
+/// Name of named-key for the registered tokens
+pub const ERC777_REGISTRY_KEY_NAME: &str = "erc777_registry";
+
+/// Name of `tokens_received` entry point.
+pub const TOKENS_RECEIVED_ENTRY_POINT_NAME: &str = "tokens_received";
+/// Name of `register_self` entry point.
+pub const REGISTER_SELF_ENTRY_POINT_NAME: &str = "register_self";
+/// Name of `add_token` entry point.
+pub const ADD_TOKEN_ENTRY_POINT_NAME: &str = "add_token";
+/// Name of `remove_token` entry point.
+pub const REMOVE_TOKEN_ENTRY_POINT_NAME: &str = "remove_token";
+
+/// Name of `erc1820_contract` runtime argument.
+pub const ERC1820_CONTRACT_RUNTIME_ARG_NAME: &str = "erc1820_contract";
+/// Name of `token` runtime argument.
+pub const TOKEN_RUNTIME_ARG_NAME: &str = "token";
+/// Name of `operator` runtime argument.
+pub const OPERATOR_RUNTIME_ARG_NAME: &str = "operator";
+/// Name of `from` runtime argument.
+pub const FROM_RUNTIME_ARG_NAME: &str = "from";
+/// Name of `to` runtime argument.
+pub const TO_RUNTIME_ARG_NAME: &str = "to";
+/// Name of `amount` runtime argument.
+pub const AMOUNT_RUNTIME_ARG_NAME: &str = "amount";
+/// Name of `user_data` runtime argument.
+pub const USER_DATA_RUNTIME_ARG_NAME: &str = "user_data";
+/// Name of `operator_data` runtime argument.
+pub const OPERATOR_DATA_RUNTIME_ARG_NAME: &str = "operator_data";
+/// Name of `account` runtime argument.
+pub const ACCOUNT_RUNTIME_ARG_NAME: &str = "account";
+/// Name of `i_hash` runtime argument.
+pub const I_HASH_RUNTIME_ARG_NAME: &str = "i_hash";
+
+/// Name of `set_interface_implementer` entry point of the erc1820 registry.
+pub const SET_INTERFACE_OF_EXTERNAL_ENTRY_POINT: &str = "set_interface_implementer";
+/// Name of `implementer` runtime argument of the erc1820 registry.
+pub const IMPLEMENTER_RUNTIME_ARG_NAME: &str = "implementer";
+
+/// Name of `send` entry point of the tokens.
+pub const SEND_OF_EXTERNAL_ENTRY_POINT: &str = "send";
+/// Name of `granularity` entry point of the tokens.
+pub const GRANULARITY_OF_EXTERNAL_ENTRY_POINT: &str = "granularity";
+/// Name of `recipient` runtime argument of the tokens.
+pub const RECIPIENT_RUNTIME_ARG_NAME: &str = "recipient";
+/// Name of `data` runtime argument of the tokens.
+pub const DATA_RUNTIME_ARG_NAME: &str = "data";
+
+/// Name of the interface the contract implements in the erc1820 registry.
+pub const HASH_ERC1820_RECIPIENT: &str = "ERC777TokensRecipient";
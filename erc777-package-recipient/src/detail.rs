@@ -0,0 +1,51 @@
+//! Implementation details, also used by the contracts built on the library.
+use core::convert::TryInto;
+
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{system::CallStackElement, ApiError, Key, URef};
+
+use crate::error::Error;
+
+/// Gets [`URef`] under a name.
+pub fn get_uref(name: &str) -> URef {
+    let key = runtime::get_key(name)
+        .ok_or(ApiError::MissingKey)
+        .unwrap_or_revert();
+    key.try_into().unwrap_or_revert()
+}
+
+/// Gets the immediate caller of the current execution: the token calling the hook, or the account
+/// or contract calling an entry point.
+///
+/// Contracts are identified by their package hash.
+pub fn get_immediate_caller_key() -> Result<Key, Error> {
+    match runtime::get_call_stack().into_iter().rev().nth(1) {
+        Some(CallStackElement::Session { account_hash }) => Ok(Key::from(account_hash)),
+        Some(CallStackElement::StoredSession { account_hash, .. }) => Ok(Key::from(account_hash)),
+        Some(CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        }) => Ok(Key::from(contract_package_hash)),
+        None => Err(Error::InvalidContext),
+    }
+}
+
+/// Gets the key of the contract package currently being executed, which is the holder of the
+/// tokens received by the contract.
+pub fn get_self_key() -> Result<Key, Error> {
+    match runtime::get_call_stack().into_iter().last() {
+        Some(CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        }) => Ok(Key::from(contract_package_hash)),
+        _ => Err(Error::InvalidContext),
+    }
+}
+
+/// Gets the key the erc1820 registry knows the current contract by, its contract hash.
+pub(crate) fn get_current_contract_key() -> Result<Key, Error> {
+    match runtime::get_call_stack().into_iter().last() {
+        Some(CallStackElement::StoredContract { contract_hash, .. }) => Ok(Key::from(contract_hash)),
+        _ => Err(Error::InvalidContext),
+    }
+}
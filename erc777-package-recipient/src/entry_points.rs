@@ -0,0 +1,77 @@
+//! Contains definition of the entry points shared by the contracts built on the library.
+use alloc::{string::String, vec, vec::Vec};
+
+use casper_erc1820::entry_points::can_implement_interface_for_address;
+use casper_types::{
+    bytesrepr::Bytes, CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints,
+    Key, Parameter, U256,
+};
+
+use crate::constants::{
+    ADD_TOKEN_ENTRY_POINT_NAME, AMOUNT_RUNTIME_ARG_NAME, FROM_RUNTIME_ARG_NAME,
+    OPERATOR_DATA_RUNTIME_ARG_NAME, OPERATOR_RUNTIME_ARG_NAME, REGISTER_SELF_ENTRY_POINT_NAME,
+    REMOVE_TOKEN_ENTRY_POINT_NAME, TOKENS_RECEIVED_ENTRY_POINT_NAME, TOKEN_RUNTIME_ARG_NAME,
+    TO_RUNTIME_ARG_NAME, USER_DATA_RUNTIME_ARG_NAME,
+};
+
+/// Returns the `tokens_received` entry point.
+pub fn tokens_received() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOKENS_RECEIVED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OPERATOR_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(FROM_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(TO_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(USER_DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+            Parameter::new(OPERATOR_DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `register_self` entry point.
+pub fn register_self() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REGISTER_SELF_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `add_token` entry point.
+pub fn add_token() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ADD_TOKEN_ENTRY_POINT_NAME),
+        vec![Parameter::new(TOKEN_RUNTIME_ARG_NAME, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `remove_token` entry point.
+pub fn remove_token() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REMOVE_TOKEN_ENTRY_POINT_NAME),
+        vec![Parameter::new(TOKEN_RUNTIME_ARG_NAME, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the entry points every contract built on the library keeps, to which it adds its own.
+pub fn default() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(tokens_received());
+    entry_points.add_entry_point(can_implement_interface_for_address());
+    entry_points.add_entry_point(register_self());
+    entry_points.add_entry_point(add_token());
+    entry_points.add_entry_point(remove_token());
+    entry_points
+}
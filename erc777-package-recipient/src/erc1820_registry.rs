@@ -0,0 +1,40 @@
+//! Implementation of the calls to the erc1820 registry.
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::Bytes, runtime_args, ContractHash, Key, RuntimeArgs, URef};
+
+use crate::{
+    constants::{
+        ACCOUNT_RUNTIME_ARG_NAME, ERC1820_REGISTRY_KEY_NAME, IMPLEMENTER_RUNTIME_ARG_NAME,
+        I_HASH_RUNTIME_ARG_NAME, SET_INTERFACE_OF_EXTERNAL_ENTRY_POINT,
+    },
+    detail,
+};
+
+/// Getter for the uref holding the hash of the erc1820 registry.
+#[inline]
+pub(crate) fn get_erc1820_uref() -> URef {
+    detail::get_uref(ERC1820_REGISTRY_KEY_NAME)
+}
+
+/// Reads the erc1820 registry the contract was installed with.
+pub(crate) fn read_erc1820(erc1820_uref: URef) -> ContractHash {
+    storage::read(erc1820_uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Sets `implementer` as the implementer of `i_hash` for `account`.
+pub(crate) fn set_implementer(
+    erc1820_hash: ContractHash,
+    account: Key,
+    i_hash: Bytes,
+    implementer: Key,
+) {
+    let args = runtime_args! {
+        ACCOUNT_RUNTIME_ARG_NAME => account,
+        I_HASH_RUNTIME_ARG_NAME => i_hash,
+        IMPLEMENTER_RUNTIME_ARG_NAME => implementer
+    };
+    runtime::call_contract::<()>(erc1820_hash, SET_INTERFACE_OF_EXTERNAL_ENTRY_POINT, args);
+}
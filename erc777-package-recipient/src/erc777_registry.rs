@@ -0,0 +1,87 @@
+//! Implementation of the registered erc777 tokens and of the calls to them.
+use alloc::string::String;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args, ContractPackageHash, Key, RuntimeArgs, URef, U256,
+};
+
+use crate::{
+    constants::{
+        AMOUNT_RUNTIME_ARG_NAME, DATA_RUNTIME_ARG_NAME, ERC777_REGISTRY_KEY_NAME,
+        GRANULARITY_OF_EXTERNAL_ENTRY_POINT, RECIPIENT_RUNTIME_ARG_NAME,
+        SEND_OF_EXTERNAL_ENTRY_POINT,
+    },
+    detail,
+    error::Error,
+};
+
+/// Getter for the uref of the registered tokens dictionary.
+#[inline]
+pub(crate) fn get_erc777_uref() -> URef {
+    detail::get_uref(ERC777_REGISTRY_KEY_NAME)
+}
+
+/// Creates a dictionary item key for a token.
+#[inline]
+fn make_dictionary_item_key(token: Key) -> String {
+    let preimage = token.to_bytes().unwrap_or_revert();
+    base64::encode(&preimage)
+}
+
+/// Returns the package hash of `token`.
+fn token_package_hash(token: Key) -> Result<ContractPackageHash, Error> {
+    token
+        .into_hash()
+        .map(ContractPackageHash::new)
+        .ok_or(Error::InvalidToken)
+}
+
+/// Registers `token`, the key of an erc777 contract package.
+pub(crate) fn add_token(erc777_uref: URef, token: Key) -> Result<(), Error> {
+    token_package_hash(token)?;
+    storage::dictionary_put(erc777_uref, &make_dictionary_item_key(token), true);
+    Ok(())
+}
+
+/// Unregisters `token`.
+pub(crate) fn remove_token(erc777_uref: URef, token: Key) {
+    storage::dictionary_put(erc777_uref, &make_dictionary_item_key(token), false);
+}
+
+/// Returns whether `token` is registered.
+pub(crate) fn is_registered(erc777_uref: URef, token: Key) -> bool {
+    storage::dictionary_get(erc777_uref, &make_dictionary_item_key(token))
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Asks `token` for its granularity.
+pub(crate) fn granularity(token: Key) -> Result<U256, Error> {
+    Ok(runtime::call_versioned_contract(
+        token_package_hash(token)?,
+        None,
+        GRANULARITY_OF_EXTERNAL_ENTRY_POINT,
+        runtime_args! {},
+    ))
+}
+
+/// Sends `amount` tokens held by the contract to `recipient`.
+pub(crate) fn send(token: Key, recipient: Key, amount: U256, data: Bytes) -> Result<(), Error> {
+    let args = runtime_args! {
+        RECIPIENT_RUNTIME_ARG_NAME => recipient,
+        AMOUNT_RUNTIME_ARG_NAME => amount,
+        DATA_RUNTIME_ARG_NAME => data
+    };
+    runtime::call_versioned_contract::<()>(
+        token_package_hash(token)?,
+        None,
+        SEND_OF_EXTERNAL_ENTRY_POINT,
+        args,
+    );
+    Ok(())
+}
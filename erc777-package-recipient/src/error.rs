@@ -0,0 +1,17 @@
+//! Error handling on the casper platform.
+
+/// Errors which can be returned by the library.
+///
+/// They carry no error code of their own: every contract built on the library converts them into
+/// its own range, as it does for its other errors.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The contract was called from within an invalid context.
+    InvalidContext,
+    /// The caller is not allowed to configure the contract.
+    Unauthorized,
+    /// The token is not registered in the contract.
+    UnknownToken,
+    /// The token is not the key of a contract package.
+    InvalidToken,
+}
@@ -0,0 +1,193 @@
+//! A library for contracts receiving ERC777 tokens on their own contract package on the Casper
+//! network.
+//!
+//! The main functionality is provided via the [`PackageRecipient`] struct. The contract is the
+//! `ERC777TokensRecipient` implementer of its own contract package, and only accepts the tokens
+//! registered by its owner. What it does with the received tokens is left to the contract built
+//! on the library, such as the payment splitter and the vault.
+
+#![warn(missing_docs)]
+#![no_std]
+
+extern crate alloc;
+
+pub mod constants;
+pub mod detail;
+pub mod entry_points;
+mod erc1820_registry;
+mod erc777_registry;
+mod error;
+mod owner;
+
+use alloc::{format, string::ToString};
+
+use once_cell::unsync::OnceCell;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc1820::{accept_magic, interface_hash};
+use casper_types::{
+    bytesrepr::Bytes, contracts::NamedKeys, runtime_args, ContractHash, EntryPoints, Key,
+    RuntimeArgs, URef, U256,
+};
+
+use constants::{
+    ERC1820_REGISTRY_KEY_NAME, ERC777_REGISTRY_KEY_NAME, HASH_ERC1820_RECIPIENT, OWNER_KEY_NAME,
+    PACKAGE_HASH_KEY_NAME_SUFFIX, REGISTER_SELF_ENTRY_POINT_NAME,
+};
+pub use error::Error;
+
+/// Implementation of a recipient of the registered tokens sent to its own contract package.
+#[derive(Default)]
+pub struct PackageRecipient {
+    owner_uref: OnceCell<URef>,
+    erc1820_uref: OnceCell<URef>,
+    erc777_uref: OnceCell<URef>,
+}
+
+impl PackageRecipient {
+    fn new(owner_uref: URef, erc1820_uref: URef, erc777_uref: URef) -> Self {
+        Self {
+            owner_uref: owner_uref.into(),
+            erc1820_uref: erc1820_uref.into(),
+            erc777_uref: erc777_uref.into(),
+        }
+    }
+
+    fn owner_uref(&self) -> URef {
+        *self.owner_uref.get_or_init(owner::get_owner_uref)
+    }
+
+    fn erc1820_uref(&self) -> URef {
+        *self.erc1820_uref.get_or_init(erc1820_registry::get_erc1820_uref)
+    }
+
+    fn erc777_uref(&self) -> URef {
+        *self.erc777_uref.get_or_init(erc777_registry::get_erc777_uref)
+    }
+
+    /// Installs the contract with `entry_points`, adding the owner, the erc1820 registry and the
+    /// registered tokens to `named_keys`, then registers it as the implementer of its package.
+    ///
+    /// `entry_points` must keep those of [`entry_points::default`], as `register_self` is called
+    /// during the install. The hash of the contract package is stored in the installer's named
+    /// keys under `contract_key_name` followed by [`PACKAGE_HASH_KEY_NAME_SUFFIX`].
+    pub fn install(
+        erc1820_hash: ContractHash,
+        contract_key_name: &str,
+        entry_points: EntryPoints,
+        mut named_keys: NamedKeys,
+    ) -> PackageRecipient {
+        let erc777_uref = storage::new_dictionary(ERC777_REGISTRY_KEY_NAME).unwrap_or_revert();
+
+        let owner_uref = storage::new_uref(Key::from(runtime::get_caller())).into_read();
+        let erc1820_uref = storage::new_uref(erc1820_hash).into_read();
+
+        let erc777_key = {
+            runtime::remove_key(ERC777_REGISTRY_KEY_NAME);
+            Key::from(erc777_uref)
+        };
+
+        named_keys.insert(OWNER_KEY_NAME.to_string(), Key::from(owner_uref));
+        named_keys.insert(ERC1820_REGISTRY_KEY_NAME.to_string(), Key::from(erc1820_uref));
+        named_keys.insert(ERC777_REGISTRY_KEY_NAME.to_string(), erc777_key);
+
+        // Hash of the contract package will be reachable through named keys, it is the account
+        // the tokens are sent to.
+        let package_hash_key_name = format!("{}{}", contract_key_name, PACKAGE_HASH_KEY_NAME_SUFFIX);
+        let (contract_hash, _version) = storage::new_contract(
+            entry_points,
+            Some(named_keys),
+            Some(package_hash_key_name),
+            None,
+        );
+
+        // Hash of the installed contract will be reachable through named keys.
+        runtime::put_key(contract_key_name, Key::from(contract_hash));
+
+        // Only the contract itself can register the implementer of its package.
+        runtime::call_contract::<()>(
+            contract_hash,
+            REGISTER_SELF_ENTRY_POINT_NAME,
+            runtime_args! {},
+        );
+
+        PackageRecipient::new(owner_uref, erc1820_uref, erc777_uref)
+    }
+
+    /// Returns an error unless the caller is the owner, the account which installed the contract.
+    pub fn only_owner(&self) -> Result<(), Error> {
+        let owner = owner::read_owner(self.owner_uref());
+        if detail::get_immediate_caller_key()?.ne(&owner) {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Registers the contract as the `ERC777TokensRecipient` implementer of its own contract
+    /// package in the erc1820 registry.
+    ///
+    /// It is called during the install, calling it again has no effect.
+    pub fn register_self(&self) -> Result<(), Error> {
+        self.only_owner()?;
+        erc1820_registry::set_implementer(
+            erc1820_registry::read_erc1820(self.erc1820_uref()),
+            detail::get_self_key()?,
+            interface_hash(HASH_ERC1820_RECIPIENT),
+            detail::get_current_contract_key()?,
+        );
+        Ok(())
+    }
+
+    /// Answers the ERC1820 registry whether this contract agrees to be the `ERC777TokensRecipient`
+    /// implementer of `account`, which is only the case for its own contract package.
+    pub fn can_implement_interface_for_address(&self, i_hash: Bytes, account: Key) -> Bytes {
+        let is_self = detail::get_self_key()
+            .map(|package| package.eq(&account))
+            .unwrap_or(false);
+        if i_hash.eq(&interface_hash(HASH_ERC1820_RECIPIENT)) && is_self {
+            accept_magic()
+        } else {
+            Bytes::default()
+        }
+    }
+
+    /// Returns the token calling `tokens_received`, after checking that it is registered and that
+    /// `to` is the contract package.
+    pub fn received_token(&self, to: Key) -> Result<Key, Error> {
+        let token = detail::get_immediate_caller_key()?;
+        if !erc777_registry::is_registered(self.erc777_uref(), token) {
+            return Err(Error::UnknownToken);
+        }
+        if to.ne(&detail::get_self_key()?) {
+            return Err(Error::InvalidContext);
+        }
+        Ok(token)
+    }
+
+    /// Registers `token`, the key of an erc777 contract package, so that it is accepted by the
+    /// hook.
+    pub fn add_token(&self, token: Key) -> Result<(), Error> {
+        self.only_owner()?;
+        erc777_registry::add_token(self.erc777_uref(), token)
+    }
+
+    /// Unregisters `token`, the hook rejects it from then on.
+    pub fn remove_token(&self, token: Key) -> Result<(), Error> {
+        self.only_owner()?;
+        erc777_registry::remove_token(self.erc777_uref(), token);
+        Ok(())
+    }
+
+    /// Asks `token` for its granularity.
+    pub fn granularity(&self, token: Key) -> Result<U256, Error> {
+        erc777_registry::granularity(token)
+    }
+
+    /// Sends `amount` of the `token` held by the contract package to `recipient`.
+    pub fn send(&self, token: Key, recipient: Key, amount: U256, data: Bytes) -> Result<(), Error> {
+        erc777_registry::send(token, recipient, amount, data)
+    }
+}
@@ -0,0 +1,16 @@
+//! Implementation of the owner, the account which manages the tokens.
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{Key, URef};
+
+use crate::{constants::OWNER_KEY_NAME, detail};
+
+/// Getter for the uref holding the owner.
+#[inline]
+pub(crate) fn get_owner_uref() -> URef {
+    detail::get_uref(OWNER_KEY_NAME)
+}
+
+/// Reads the account which installed the contract.
+pub(crate) fn read_owner(owner_uref: URef) -> Key {
+    storage::read(owner_uref).unwrap_or_revert().unwrap_or_revert()
+}
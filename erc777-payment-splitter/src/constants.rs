@@ -0,0 +1,31 @@
+//! Constants used by the Payment Splitter contract.
+//!
+//! The names shared with the other contracts built on `casper-erc777-package-recipient` are
+//! re-exported from it.
+
+pub use casper_erc777_package_recipient::constants::*;
+
+/// Name of named-key for `contract`
+pub const PAYMENT_SPLITTER_CONTRACT_NAME: &str = "erc777_payment_splitter_contract";
+
+/// Name of named-key for the payees and their shares
+pub const PAYEES_KEY_NAME: &str = "payees";
+/// Name of named-key for the amounts released to every payee
+pub const RELEASED_REGISTRY_KEY_NAME: &str = "released";
+
+/// Shares of the payees are expressed in basis points, and always add up to this total.
+pub const TOTAL_SHARES: u32 = 10_000;
+
+/// Name of `set_payees` entry point.
+pub const SET_PAYEES_ENTRY_POINT_NAME: &str = "set_payees";
+/// Name of `get_payees` entry point.
+pub const GET_PAYEES_ENTRY_POINT_NAME: &str = "get_payees";
+/// Name of `released` entry point.
+pub const RELEASED_ENTRY_POINT_NAME: &str = "released";
+
+/// Name of `payees` runtime argument.
+pub const PAYEES_RUNTIME_ARG_NAME: &str = "payees";
+/// Name of `shares` runtime argument.
+pub const SHARES_RUNTIME_ARG_NAME: &str = "shares";
+/// Name of `payee` runtime argument.
+pub const PAYEE_RUNTIME_ARG_NAME: &str = "payee";
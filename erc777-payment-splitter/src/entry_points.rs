@@ -0,0 +1,61 @@
+//! Contains definition of the entry points.
+use alloc::{string::String, vec, vec::Vec};
+
+use casper_types::{
+    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter,
+    U256,
+};
+
+use crate::constants::{
+    GET_PAYEES_ENTRY_POINT_NAME, PAYEES_RUNTIME_ARG_NAME, PAYEE_RUNTIME_ARG_NAME,
+    RELEASED_ENTRY_POINT_NAME, SET_PAYEES_ENTRY_POINT_NAME, SHARES_RUNTIME_ARG_NAME,
+    TOKEN_RUNTIME_ARG_NAME,
+};
+
+/// Returns the `set_payees` entry point.
+pub fn set_payees() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_PAYEES_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(PAYEES_RUNTIME_ARG_NAME, Vec::<Key>::cl_type()),
+            Parameter::new(SHARES_RUNTIME_ARG_NAME, Vec::<u32>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_payees` entry point.
+pub fn get_payees() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_PAYEES_ENTRY_POINT_NAME),
+        Vec::new(),
+        Vec::<(Key, u32)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `released` entry point.
+pub fn released() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RELEASED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(PAYEE_RUNTIME_ARG_NAME, Key::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the default set of Payment Splitter entry points.
+pub fn default() -> EntryPoints {
+    let mut entry_points = casper_erc777_package_recipient::entry_points::default();
+    entry_points.add_entry_point(set_payees());
+    entry_points.add_entry_point(get_payees());
+    entry_points.add_entry_point(released());
+    entry_points
+}
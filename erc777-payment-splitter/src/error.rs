@@ -0,0 +1,58 @@
+//! Error handling on the casper platform.
+use casper_erc777_package_recipient::Error as PackageRecipientError;
+use casper_types::ApiError;
+
+/// Errors which can be returned by the library.
+///
+/// When an `Error` is returned from a smart contract, it is converted to an [`ApiError::User`].
+/// The errors of the payment splitter use the range `[1500..1599]`.
+pub enum Error {
+    /// The splitter was called from within an invalid context.
+    InvalidContext,
+    /// The caller is not allowed to configure the contract.
+    Unauthorized,
+    /// The token is not registered in the contract.
+    UnknownToken,
+    /// The token is not the key of a contract package.
+    InvalidToken,
+    /// The payees are empty, repeated, include the splitter or do not match the shares.
+    InvalidPayees,
+    /// A share is zero, or the shares do not add up to 10000 basis points.
+    InvalidShares,
+    /// Operation would cause an integer overflow.
+    Overflow,
+}
+
+const ERROR_INVALID_CONTEXT: u16 = 1500;
+const ERROR_UNAUTHORIZED: u16 = 1501;
+const ERROR_UNKNOWN_TOKEN: u16 = 1502;
+const ERROR_INVALID_TOKEN: u16 = 1503;
+const ERROR_INVALID_PAYEES: u16 = 1504;
+const ERROR_INVALID_SHARES: u16 = 1505;
+const ERROR_OVERFLOW: u16 = 1506;
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        let user_error = match error {
+            Error::InvalidContext => ERROR_INVALID_CONTEXT,
+            Error::Unauthorized => ERROR_UNAUTHORIZED,
+            Error::UnknownToken => ERROR_UNKNOWN_TOKEN,
+            Error::InvalidToken => ERROR_INVALID_TOKEN,
+            Error::InvalidPayees => ERROR_INVALID_PAYEES,
+            Error::InvalidShares => ERROR_INVALID_SHARES,
+            Error::Overflow => ERROR_OVERFLOW,
+        };
+        ApiError::User(user_error)
+    }
+}
+
+impl From<PackageRecipientError> for Error {
+    fn from(error: PackageRecipientError) -> Self {
+        match error {
+            PackageRecipientError::InvalidContext => Error::InvalidContext,
+            PackageRecipientError::Unauthorized => Error::Unauthorized,
+            PackageRecipientError::UnknownToken => Error::UnknownToken,
+            PackageRecipientError::InvalidToken => Error::InvalidToken,
+        }
+    }
+}
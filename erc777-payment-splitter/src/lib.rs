@@ -0,0 +1,199 @@
+//! A library for splitting the ERC777 tokens received by a contract among payees on the Casper
+//! network.
+//!
+//! The main functionality is provided via the [`PaymentSplitter`] struct. The contract is the
+//! `ERC777TokensRecipient` implementer of its own contract package, so every registered token
+//! sent to the package goes through [`PaymentSplitter::tokens_received`], which sends it on to the
+//! payees right away according to their shares.
+//!
+//! The hook plumbing, the owner and the registered tokens come from
+//! [`casper_erc777_package_recipient`], which the vault is built on as well.
+
+#![warn(missing_docs)]
+#![no_std]
+
+extern crate alloc;
+
+pub mod constants;
+pub mod entry_points;
+mod error;
+mod payees;
+mod released;
+
+use alloc::{string::ToString, vec::Vec};
+
+use once_cell::unsync::OnceCell;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc777_package_recipient::{detail, PackageRecipient};
+use casper_types::{
+    bytesrepr::Bytes, contracts::NamedKeys, ContractHash, EntryPoints, Key, URef, U256,
+};
+
+use constants::{PAYEES_KEY_NAME, PAYMENT_SPLITTER_CONTRACT_NAME, RELEASED_REGISTRY_KEY_NAME};
+pub use error::Error;
+
+/// Implementation of a recipient splitting the received tokens among payees.
+#[derive(Default)]
+pub struct PaymentSplitter {
+    recipient: PackageRecipient,
+    payees_uref: OnceCell<URef>,
+    released_uref: OnceCell<URef>,
+}
+
+impl PaymentSplitter {
+    fn new(recipient: PackageRecipient, payees_uref: URef, released_uref: URef) -> Self {
+        Self {
+            recipient,
+            payees_uref: payees_uref.into(),
+            released_uref: released_uref.into(),
+        }
+    }
+
+    fn payees_uref(&self) -> URef {
+        *self.payees_uref.get_or_init(payees::get_payees_uref)
+    }
+
+    fn released_uref(&self) -> URef {
+        *self.released_uref.get_or_init(released::get_released_uref)
+    }
+
+    /// Installs the Payment Splitter contract with the default set of entry points.
+    ///
+    /// `payees[i]` receives `shares[i]` basis points of every received amount, and the shares
+    /// must add up to [`constants::TOTAL_SHARES`]. Tokens are registered afterwards with
+    /// [`PaymentSplitter::add_token`].
+    ///
+    /// This should be called from within `fn call()` of your contract.
+    pub fn install(
+        erc1820_hash: ContractHash,
+        payees: Vec<Key>,
+        shares: Vec<u32>,
+    ) -> Result<PaymentSplitter, Error> {
+        PaymentSplitter::install_custom(
+            erc1820_hash,
+            payees,
+            shares,
+            PAYMENT_SPLITTER_CONTRACT_NAME,
+            entry_points::default(),
+        )
+    }
+
+    /// Installs the Payment Splitter contract with a custom set of entry points.
+    ///
+    /// # Warning
+    ///
+    /// Contract developers should use [`PaymentSplitter::install`] instead, as it will create the
+    /// default set of entry points. The custom set must keep `register_self`, which is called
+    /// during the install.
+    #[doc(hidden)]
+    pub fn install_custom(
+        erc1820_hash: ContractHash,
+        payees: Vec<Key>,
+        shares: Vec<u32>,
+        contract_key_name: &str,
+        entry_points: EntryPoints,
+    ) -> Result<PaymentSplitter, Error> {
+        let payees = payees::validate(payees, shares)?;
+
+        let released_uref = storage::new_dictionary(RELEASED_REGISTRY_KEY_NAME).unwrap_or_revert();
+        let payees_uref = storage::new_uref(payees).into_read_write();
+
+        let mut named_keys = NamedKeys::new();
+
+        let released_key = {
+            runtime::remove_key(RELEASED_REGISTRY_KEY_NAME);
+            Key::from(released_uref)
+        };
+
+        named_keys.insert(PAYEES_KEY_NAME.to_string(), Key::from(payees_uref));
+        named_keys.insert(RELEASED_REGISTRY_KEY_NAME.to_string(), released_key);
+
+        let recipient =
+            PackageRecipient::install(erc1820_hash, contract_key_name, entry_points, named_keys);
+
+        Ok(PaymentSplitter::new(recipient, payees_uref, released_uref))
+    }
+
+    /// Registers the contract as the `ERC777TokensRecipient` implementer of its own contract
+    /// package in the erc1820 registry.
+    ///
+    /// It is called during the install, calling it again has no effect.
+    pub fn register_self(&self) -> Result<(), Error> {
+        Ok(self.recipient.register_self()?)
+    }
+
+    /// Answers the ERC1820 registry whether this contract agrees to be the `ERC777TokensRecipient`
+    /// implementer of `account`, which is only the case for its own contract package.
+    pub fn can_implement_interface_for_address(&self, i_hash: Bytes, account: Key) -> Bytes {
+        self.recipient.can_implement_interface_for_address(i_hash, account)
+    }
+
+    /// Sends the `amount` tokens received by the splitter on to the payees, passing `data` along.
+    ///
+    /// Every part is rounded down to a multiple of the token granularity, and what is left goes to
+    /// the first payee. Only registered tokens are accepted.
+    pub fn tokens_received(
+        &self,
+        _operator: Key,
+        _from: Key,
+        to: Key,
+        amount: U256,
+        data: Bytes,
+        _operator_data: Bytes,
+    ) -> Result<(), Error> {
+        let token = self.recipient.received_token(to)?;
+
+        let granularity = self.recipient.granularity(token)?.max(U256::one());
+        let payees = payees::read_payees(self.payees_uref());
+        for (payee, part) in payees::split(&payees, amount, granularity) {
+            if part.is_zero() {
+                continue;
+            }
+            self.recipient.send(token, payee, part, data.clone())?;
+            released::add_released(self.released_uref(), token, payee, part)?;
+        }
+        Ok(())
+    }
+
+    /// Replaces the payees, `payees[i]` receives `shares[i]` basis points of every received
+    /// amount from then on.
+    ///
+    /// The parts are sent within the movement to the splitter, so a payee whose
+    /// `ERC777TokensRecipient` hook rejects the token makes every payment revert until it is
+    /// replaced. A contract payee should register a hook which accepts the registered tokens.
+    pub fn set_payees(&self, payees: Vec<Key>, shares: Vec<u32>) -> Result<(), Error> {
+        self.recipient.only_owner()?;
+        let payees = payees::validate(payees, shares)?;
+        let splitter = detail::get_self_key()?;
+        if payees.iter().any(|(payee, _)| payee.eq(&splitter)) {
+            return Err(Error::InvalidPayees);
+        }
+        payees::write_payees(self.payees_uref(), payees);
+        Ok(())
+    }
+
+    /// Returns the payees together with their shares in basis points.
+    pub fn get_payees(&self) -> Vec<(Key, u32)> {
+        payees::read_payees(self.payees_uref())
+    }
+
+    /// Returns the total amount of `token` sent to `payee`.
+    pub fn released(&self, token: Key, payee: Key) -> U256 {
+        released::read_released(self.released_uref(), token, payee)
+    }
+
+    /// Registers `token`, the key of an erc777 contract package, so that it is split when
+    /// received.
+    pub fn add_token(&self, token: Key) -> Result<(), Error> {
+        Ok(self.recipient.add_token(token)?)
+    }
+
+    /// Unregisters `token`, the hook rejects it from then on.
+    pub fn remove_token(&self, token: Key) -> Result<(), Error> {
+        Ok(self.recipient.remove_token(token)?)
+    }
+}
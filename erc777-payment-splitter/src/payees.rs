@@ -0,0 +1,81 @@
+//! Implementation of the payees and of the split of the received tokens among them.
+use alloc::vec::Vec;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_erc777_package_recipient::detail;
+use casper_types::{Key, URef, U256};
+
+use crate::{
+    constants::{PAYEES_KEY_NAME, TOTAL_SHARES},
+    error::Error,
+};
+
+/// Getter for the uref holding the payees.
+#[inline]
+pub(crate) fn get_payees_uref() -> URef {
+    detail::get_uref(PAYEES_KEY_NAME)
+}
+
+/// Reads the payees together with their shares in basis points.
+pub(crate) fn read_payees(payees_uref: URef) -> Vec<(Key, u32)> {
+    storage::read(payees_uref)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Writes the payees, which have been validated beforehand.
+pub(crate) fn write_payees(payees_uref: URef, payees: Vec<(Key, u32)>) {
+    storage::write(payees_uref, payees);
+}
+
+/// Pairs `payees[i]` with `shares[i]`.
+///
+/// There must be at least one payee, no payee can appear twice, and the shares, none of them zero,
+/// must add up to [`TOTAL_SHARES`].
+pub(crate) fn validate(payees: Vec<Key>, shares: Vec<u32>) -> Result<Vec<(Key, u32)>, Error> {
+    if payees.is_empty() || payees.len() != shares.len() {
+        return Err(Error::InvalidPayees);
+    }
+
+    let mut total: u32 = 0;
+    for (index, (payee, share)) in payees.iter().zip(shares.iter()).enumerate() {
+        if payees[..index].contains(payee) {
+            return Err(Error::InvalidPayees);
+        }
+        if *share == 0 {
+            return Err(Error::InvalidShares);
+        }
+        total = total.checked_add(*share).ok_or(Error::InvalidShares)?;
+    }
+    if total != TOTAL_SHARES {
+        return Err(Error::InvalidShares);
+    }
+
+    Ok(payees.into_iter().zip(shares.into_iter()).collect())
+}
+
+/// Splits `amount` among `payees` according to their shares.
+///
+/// Every part is rounded down to a multiple of `granularity`, and what is left, rounded down the
+/// same way, goes to the first payee. When `amount` itself is not a multiple of `granularity`,
+/// the rest stays in the splitter.
+///
+/// The parts are computed without multiplying `amount` itself, so any amount can be split.
+pub(crate) fn split(payees: &[(Key, u32)], amount: U256, granularity: U256) -> Vec<(Key, U256)> {
+    let total_shares = U256::from(TOTAL_SHARES);
+    let mut parts = Vec::with_capacity(payees.len());
+    let mut distributed = U256::zero();
+    for (payee, share) in payees {
+        let share = U256::from(*share);
+        let part = amount / total_shares * share + (amount % total_shares) * share / total_shares;
+        let part = part - part % granularity;
+        distributed += part;
+        parts.push((*payee, part));
+    }
+
+    let remainder = amount - distributed;
+    if let Some((_, first_part)) = parts.first_mut() {
+        *first_part += remainder - remainder % granularity;
+    }
+    parts
+}
@@ -0,0 +1,48 @@
+//! Implementation of the amounts of every token released to every payee.
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc777_package_recipient::detail;
+use casper_types::{bytesrepr::ToBytes, Key, URef, U256};
+
+use crate::{constants::RELEASED_REGISTRY_KEY_NAME, error::Error};
+
+/// Getter for the uref of the released amounts dictionary.
+#[inline]
+pub(crate) fn get_released_uref() -> URef {
+    detail::get_uref(RELEASED_REGISTRY_KEY_NAME)
+}
+
+/// Creates a dictionary item key for the amount of `token` released to `payee`.
+#[inline]
+fn make_dictionary_item_key(token: Key, payee: Key) -> String {
+    let mut preimage: Vec<u8> = token.to_bytes().unwrap_or_revert();
+    preimage.append(&mut payee.to_bytes().unwrap_or_revert());
+    hex::encode(runtime::blake2b(&preimage))
+}
+
+/// Reads the amount of `token` released to `payee`.
+///
+/// A payee which has never received `token` has a zero amount.
+pub(crate) fn read_released(released_uref: URef, token: Key, payee: Key) -> U256 {
+    storage::dictionary_get(released_uref, &make_dictionary_item_key(token, payee))
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Adds `amount` to the amount of `token` released to `payee`.
+pub(crate) fn add_released(
+    released_uref: URef,
+    token: Key,
+    payee: Key,
+    amount: U256,
+) -> Result<(), Error> {
+    let released = read_released(released_uref, token, payee)
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+    storage::dictionary_put(released_uref, &make_dictionary_item_key(token, payee), released);
+    Ok(())
+}
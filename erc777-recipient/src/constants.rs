@@ -1,12 +1,52 @@
 //! Constants used by the ERC777 Recipient contract.
+use casper_types::Key;
+
 /// Contract Name
 pub const ERC777_RECIPIENT_CONTRACT_NAME: &str = "erc777_recipient_contract";
 
 
 /// Key name `movement_registry`
 pub const BALANCES_REGISTRY_KEY_NAME: &str = "balances";
-/// Key name `erc777_recipient_registry`
+/// Key name `erc777_recipient_registry`, the registered tokens
 pub const ERC777_REGISTRY_KEY_NAME: &str = "erc777_recipient_registry";
+/// Key name `movement_count`
+pub const MOVEMENT_COUNT_KEY_NAME: &str = "movement_count";
+/// Key name `delegates`
+pub const DELEGATES_REGISTRY_KEY_NAME: &str = "delegates";
+/// Key name `erc1820_registry`
+pub const ERC1820_REGISTRY_KEY_NAME: &str = "erc1820_registry";
+/// Key name `served_accounts`
+pub const SERVED_ACCOUNTS_KEY_NAME: &str = "served_accounts";
+/// Owner of the list of served accounts in the `served_accounts` dictionary, which holds a single list
+pub const SERVED_ACCOUNTS_LIST_OWNER: Key = Key::Hash([0u8; 32]);
+/// Key name `owner`
+pub const OWNER_KEY_NAME: &str = "owner";
+/// Key name `receive_policies`
+pub const POLICIES_REGISTRY_KEY_NAME: &str = "receive_policies";
+/// Key name `invoices`
+pub const INVOICES_REGISTRY_KEY_NAME: &str = "invoices";
+/// Key name `invoice_rules`
+pub const INVOICE_RULES_REGISTRY_KEY_NAME: &str = "invoice_rules";
+
+/// ------------ Receive policies
+/// Policy with the only senders the holder accepts tokens from
+pub const SENDER_ALLOWLIST_POLICY: &str = "sender_allowlist";
+/// Policy with the minimum amount of a single movement
+pub const MIN_AMOUNT_POLICY: &str = "min_amount";
+/// Policy with the maximum amount of a single movement
+pub const MAX_AMOUNT_POLICY: &str = "max_amount";
+/// Policy with the prefix the `data` must start with
+pub const DATA_PREFIX_POLICY: &str = "data_prefix";
+
+/// ------------ Invoices
+/// Prefix of the `data` of a movement paying an invoice, the invoice id follows it
+pub const INVOICE_DATA_PREFIX: &[u8] = b"invoice:";
+/// Rule rejecting the payments greater than the amount due
+pub const REJECT_OVERPAYMENT_RULE: &str = "reject_overpayment";
+/// Rule rejecting the payments smaller than the amount due
+pub const REJECT_UNDERPAYMENT_RULE: &str = "reject_underpayment";
+/// Rule rejecting the payments of expired invoices
+pub const REJECT_EXPIRED_RULE: &str = "reject_expired";
 
 
 /// Entry point named `transfer`
@@ -17,13 +57,45 @@ pub const BURN_ENTRY_POINT: &str = "burn";
 pub const TOKENS_RECEIVED_ENTRY_POINT: &str = "tokens_received";
 /// Entry points
 pub const BALANCE_OF_ENTRY_POINT_NAME: &str = "balance_of";
+/// Entry point named `register_for`
+pub const REGISTER_FOR_ENTRY_POINT: &str = "register_for";
+/// Entry point named `unregister_for`
+pub const UNREGISTER_FOR_ENTRY_POINT: &str = "unregister_for";
+/// Entry point named `get_served_accounts`
+pub const GET_SERVED_ACCOUNTS_ENTRY_POINT: &str = "get_served_accounts";
+/// Entry point named `set_sender_allowlist`
+pub const SET_SENDER_ALLOWLIST_ENTRY_POINT: &str = "set_sender_allowlist";
+/// Entry point named `set_min_amount`
+pub const SET_MIN_AMOUNT_ENTRY_POINT: &str = "set_min_amount";
+/// Entry point named `set_max_amount`
+pub const SET_MAX_AMOUNT_ENTRY_POINT: &str = "set_max_amount";
+/// Entry point named `set_data_prefix`
+pub const SET_DATA_PREFIX_ENTRY_POINT: &str = "set_data_prefix";
+/// Entry point named `add_token`
+pub const ADD_TOKEN_ENTRY_POINT: &str = "add_token";
+/// Entry point named `remove_token`
+pub const REMOVE_TOKEN_ENTRY_POINT: &str = "remove_token";
+/// Entry point named `add_delegate`
+pub const ADD_DELEGATE_ENTRY_POINT: &str = "add_delegate";
+/// Entry point named `remove_delegate`
+pub const REMOVE_DELEGATE_ENTRY_POINT: &str = "remove_delegate";
+/// Entry point named `movement_count`
+pub const MOVEMENT_COUNT_ENTRY_POINT: &str = "movement_count";
+/// Entry point named `get_movement`
+pub const GET_MOVEMENT_ENTRY_POINT: &str = "get_movement";
+/// Entry point named `get_movements`
+pub const GET_MOVEMENTS_ENTRY_POINT: &str = "get_movements";
+/// Entry point named `create_invoice`
+pub const CREATE_INVOICE_ENTRY_POINT: &str = "create_invoice";
+/// Entry point named `invoice_status`
+pub const INVOICE_STATUS_ENTRY_POINT: &str = "invoice_status";
+/// Entry point named `set_invoice_rules`
+pub const SET_INVOICE_RULES_ENTRY_POINT: &str = "set_invoice_rules";
 
 
 /// Entry point's parameter
 pub const ERC1820_CONTRACT_ARG_NAME: &str = "erc1820_contract";
 /// Entry point's parameter
-pub const ERC777_CONTRACT_ARG_NAME: &str = "erc777_contract";
-/// Entry point's parameter
 pub const SELF_CONTRACT_ARG_NAME: &str = "self_contract";
 /// Entry point's parameter
 pub const OPERATOR_RUNTIME_ARG_NAME: &str = "operator";
@@ -39,11 +111,41 @@ pub const AMOUNT_RUNTIME_ARG_NAME: &str = "amount";
 pub const USER_DATA_RUNTIME_ARG_NAME: &str = "user_data";
 /// Entry point's parameter
 pub const OPERATOR_DATA_RUNTIME_ARG_NAME: &str = "operator_data";
+/// Entry point's parameter
+pub const SENDERS_RUNTIME_ARG_NAME: &str = "senders";
+/// Entry point's parameter
+pub const PREFIX_RUNTIME_ARG_NAME: &str = "prefix";
+/// Entry point's parameter
+pub const DELEGATE_RUNTIME_ARG_NAME: &str = "delegate";
+/// Entry point's parameter
+pub const TOKEN_RUNTIME_ARG_NAME: &str = "token";
+/// Entry point's parameter
+pub const INDEX_RUNTIME_ARG_NAME: &str = "index";
+/// Entry point's parameter
+pub const OFFSET_RUNTIME_ARG_NAME: &str = "offset";
+/// Entry point's parameter
+pub const LIMIT_RUNTIME_ARG_NAME: &str = "limit";
+/// Entry point's parameter
+pub const INVOICE_ID_RUNTIME_ARG_NAME: &str = "invoice_id";
+/// Entry point's parameter
+pub const EXPIRY_RUNTIME_ARG_NAME: &str = "expiry";
+/// Entry point's parameter
+pub const REJECT_OVERPAYMENT_RUNTIME_ARG_NAME: &str = "reject_overpayment";
+/// Entry point's parameter
+pub const REJECT_UNDERPAYMENT_RUNTIME_ARG_NAME: &str = "reject_underpayment";
+/// Entry point's parameter
+pub const REJECT_EXPIRED_RUNTIME_ARG_NAME: &str = "reject_expired";
 
 
 ///-------- External Contract
 /// Registry contract's entry points
 pub const SET_INTERFACE_OF_EXTERNAL_ENTRY_POINT: &str = "set_interface_implementer";
+/// Registry contract's entry points
+pub const GET_INTERFACE_OF_EXTERNAL_ENTRY_POINT: &str = "get_interface_implementer";
+/// Registry contract's entry points
+pub const CLEAR_INTERFACE_OF_EXTERNAL_ENTRY_POINT: &str = "clear_interface_implementer";
+/// Registry contract's entry points
+pub const GET_MANAGER_OF_EXTERNAL_ENTRY_POINT: &str = "get_manager";
 
 /// Name of `account` runtime argument.
 pub const ACCOUNT_RUNTIME_ARG_NAME: &str = "account";
@@ -0,0 +1,36 @@
+//! Implementation of the delegates, the accounts allowed to move funds along with the owner.
+use alloc::string::String;
+use core::convert::TryInto;
+
+use casper_contract::{contract_api::{runtime, storage}, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{ApiError, Key, URef};
+use casper_types::bytesrepr::ToBytes;
+use constants::DELEGATES_REGISTRY_KEY_NAME;
+
+/// get the delegates uref.
+#[inline]
+pub(crate) fn get_delegates_uref() -> URef {
+    let key = runtime::get_key(DELEGATES_REGISTRY_KEY_NAME)
+        .ok_or(ApiError::MissingKey)
+        .unwrap_or_revert();
+    key.try_into().unwrap_or_revert()
+}
+
+/// Creates a dictionary item key for a delegate.
+#[inline]
+fn make_dictionary_item_key(delegate: Key) -> String {
+    let preimage = delegate.to_bytes().unwrap_or_revert();
+    base64::encode(&preimage)
+}
+
+/// Allows or disallows `delegate` to move funds.
+pub(crate) fn write_delegate(delegates_uref: URef, delegate: Key, allowed: bool) {
+    storage::dictionary_put(delegates_uref, &make_dictionary_item_key(delegate), allowed);
+}
+
+/// Returns whether `delegate` is allowed to move funds.
+pub(crate) fn is_delegate(delegates_uref: URef, delegate: Key) -> bool {
+    storage::dictionary_get(delegates_uref, &make_dictionary_item_key(delegate))
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
@@ -1,15 +1,34 @@
 //! Contains definition of the entry points.
-use alloc::{string::String, vec};
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
 
 use casper_types::{{CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter}, Key, U256};
 use casper_types::bytesrepr::Bytes;
-use constants::{ACCOUNT_RUNTIME_ARG_NAME, BALANCE_OF_ENTRY_POINT_NAME, TOKENS_RECEIVED_ENTRY_POINT, OPERATOR_RUNTIME_ARG_NAME, FROM_RUNTIME_ARG_NAME, TO_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, USER_DATA_RUNTIME_ARG_NAME, OPERATOR_DATA_RUNTIME_ARG_NAME, TRANSFER_ENTRY_POINT, BURN_ENTRY_POINT};
+use casper_erc1820::entry_points::can_implement_interface_for_address;
+use constants::{
+    ACCOUNT_RUNTIME_ARG_NAME, BALANCE_OF_ENTRY_POINT_NAME, TOKENS_RECEIVED_ENTRY_POINT,
+    OPERATOR_RUNTIME_ARG_NAME, FROM_RUNTIME_ARG_NAME, TO_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME,
+    USER_DATA_RUNTIME_ARG_NAME, OPERATOR_DATA_RUNTIME_ARG_NAME, TRANSFER_ENTRY_POINT, BURN_ENTRY_POINT,
+    MOVEMENT_COUNT_ENTRY_POINT, GET_MOVEMENT_ENTRY_POINT, GET_MOVEMENTS_ENTRY_POINT,
+    INDEX_RUNTIME_ARG_NAME, OFFSET_RUNTIME_ARG_NAME, LIMIT_RUNTIME_ARG_NAME, TOKEN_RUNTIME_ARG_NAME,
+    ADD_TOKEN_ENTRY_POINT, REMOVE_TOKEN_ENTRY_POINT, ADD_DELEGATE_ENTRY_POINT,
+    REMOVE_DELEGATE_ENTRY_POINT, DELEGATE_RUNTIME_ARG_NAME, REGISTER_FOR_ENTRY_POINT,
+    UNREGISTER_FOR_ENTRY_POINT, GET_SERVED_ACCOUNTS_ENTRY_POINT, SET_SENDER_ALLOWLIST_ENTRY_POINT,
+    SET_MIN_AMOUNT_ENTRY_POINT, SET_MAX_AMOUNT_ENTRY_POINT, SET_DATA_PREFIX_ENTRY_POINT,
+    SENDERS_RUNTIME_ARG_NAME, PREFIX_RUNTIME_ARG_NAME, CREATE_INVOICE_ENTRY_POINT,
+    INVOICE_STATUS_ENTRY_POINT, SET_INVOICE_RULES_ENTRY_POINT, INVOICE_ID_RUNTIME_ARG_NAME,
+    EXPIRY_RUNTIME_ARG_NAME, REJECT_OVERPAYMENT_RUNTIME_ARG_NAME,
+    REJECT_UNDERPAYMENT_RUNTIME_ARG_NAME, REJECT_EXPIRED_RUNTIME_ARG_NAME
+};
+use invoices::InvoiceStatus;
 
 /// Returns the `balance_of` entry point.
 pub fn balance_of() -> EntryPoint {
     EntryPoint::new(
         String::from(BALANCE_OF_ENTRY_POINT_NAME),
-        vec![Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type())],
+        vec![
+            Parameter::new(TOKEN_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
         U256::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
@@ -39,6 +58,7 @@ pub fn transfer() -> EntryPoint {
     EntryPoint::new(
         String::from(TRANSFER_ENTRY_POINT),
         vec![
+            Parameter::new(TOKEN_RUNTIME_ARG_NAME, Key::cl_type()),
             Parameter::new(FROM_RUNTIME_ARG_NAME, Key::cl_type()),
             Parameter::new(TO_RUNTIME_ARG_NAME, Key::cl_type()),
             Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
@@ -56,6 +76,7 @@ pub fn burn() -> EntryPoint {
     EntryPoint::new(
         String::from(BURN_ENTRY_POINT),
         vec![
+            Parameter::new(TOKEN_RUNTIME_ARG_NAME, Key::cl_type()),
             Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type()),
             Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
             Parameter::new(USER_DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
@@ -67,6 +88,229 @@ pub fn burn() -> EntryPoint {
     )
 }
 
+/// Returns the `register_for` entry point.
+pub fn register_for() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REGISTER_FOR_ENTRY_POINT),
+        vec![
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `unregister_for` entry point.
+pub fn unregister_for() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNREGISTER_FOR_ENTRY_POINT),
+        vec![
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_served_accounts` entry point.
+pub fn get_served_accounts() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_SERVED_ACCOUNTS_ENTRY_POINT),
+        vec![],
+        Vec::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_sender_allowlist` entry point.
+pub fn set_sender_allowlist() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_SENDER_ALLOWLIST_ENTRY_POINT),
+        vec![
+            Parameter::new(SENDERS_RUNTIME_ARG_NAME, Vec::<Key>::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_min_amount` entry point.
+pub fn set_min_amount() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_MIN_AMOUNT_ENTRY_POINT),
+        vec![
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_max_amount` entry point.
+pub fn set_max_amount() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_MAX_AMOUNT_ENTRY_POINT),
+        vec![
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_data_prefix` entry point.
+pub fn set_data_prefix() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_DATA_PREFIX_ENTRY_POINT),
+        vec![
+            Parameter::new(PREFIX_RUNTIME_ARG_NAME, Bytes::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `add_token` entry point.
+pub fn add_token() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ADD_TOKEN_ENTRY_POINT),
+        vec![
+            Parameter::new(TOKEN_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `remove_token` entry point.
+pub fn remove_token() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REMOVE_TOKEN_ENTRY_POINT),
+        vec![
+            Parameter::new(TOKEN_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `add_delegate` entry point.
+pub fn add_delegate() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ADD_DELEGATE_ENTRY_POINT),
+        vec![
+            Parameter::new(DELEGATE_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `remove_delegate` entry point.
+pub fn remove_delegate() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REMOVE_DELEGATE_ENTRY_POINT),
+        vec![
+            Parameter::new(DELEGATE_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `movement_count` entry point.
+pub fn movement_count() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MOVEMENT_COUNT_ENTRY_POINT),
+        vec![],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_movement` entry point.
+pub fn get_movement() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_MOVEMENT_ENTRY_POINT),
+        vec![
+            Parameter::new(INDEX_RUNTIME_ARG_NAME, u64::cl_type())
+        ],
+        BTreeMap::<String, String>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_movements` entry point.
+pub fn get_movements() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_MOVEMENTS_ENTRY_POINT),
+        vec![
+            Parameter::new(OFFSET_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(LIMIT_RUNTIME_ARG_NAME, u64::cl_type())
+        ],
+        Vec::<BTreeMap<String, String>>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `create_invoice` entry point.
+pub fn create_invoice() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CREATE_INVOICE_ENTRY_POINT),
+        vec![
+            Parameter::new(INVOICE_ID_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(TOKEN_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(EXPIRY_RUNTIME_ARG_NAME, u64::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `invoice_status` entry point.
+pub fn invoice_status() -> EntryPoint {
+    EntryPoint::new(
+        String::from(INVOICE_STATUS_ENTRY_POINT),
+        vec![
+            Parameter::new(INVOICE_ID_RUNTIME_ARG_NAME, String::cl_type())
+        ],
+        InvoiceStatus::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_invoice_rules` entry point.
+pub fn set_invoice_rules() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_INVOICE_RULES_ENTRY_POINT),
+        vec![
+            Parameter::new(REJECT_OVERPAYMENT_RUNTIME_ARG_NAME, bool::cl_type()),
+            Parameter::new(REJECT_UNDERPAYMENT_RUNTIME_ARG_NAME, bool::cl_type()),
+            Parameter::new(REJECT_EXPIRED_RUNTIME_ARG_NAME, bool::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the default set of ERC777 Recipient token entry points.
 pub fn default() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
@@ -74,5 +318,23 @@ pub fn default() -> EntryPoints {
     entry_points.add_entry_point(balance_of());
     entry_points.add_entry_point(transfer());
     entry_points.add_entry_point(burn());
+    entry_points.add_entry_point(can_implement_interface_for_address());
+    entry_points.add_entry_point(register_for());
+    entry_points.add_entry_point(unregister_for());
+    entry_points.add_entry_point(get_served_accounts());
+    entry_points.add_entry_point(set_sender_allowlist());
+    entry_points.add_entry_point(set_min_amount());
+    entry_points.add_entry_point(set_max_amount());
+    entry_points.add_entry_point(set_data_prefix());
+    entry_points.add_entry_point(add_token());
+    entry_points.add_entry_point(remove_token());
+    entry_points.add_entry_point(add_delegate());
+    entry_points.add_entry_point(remove_delegate());
+    entry_points.add_entry_point(movement_count());
+    entry_points.add_entry_point(get_movement());
+    entry_points.add_entry_point(get_movements());
+    entry_points.add_entry_point(create_invoice());
+    entry_points.add_entry_point(invoice_status());
+    entry_points.add_entry_point(set_invoice_rules());
     entry_points
 }
@@ -1,9 +1,11 @@
-//! Implementation of erc777_registry.
+//! Implementation of the registered erc777 tokens.
+use alloc::string::String;
 use core::convert::TryInto;
 
 use casper_contract::{contract_api::{storage, runtime}, unwrap_or_revert::UnwrapOrRevert};
-use casper_types::{URef, ApiError, ContractHash, Key, runtime_args, RuntimeArgs, U256};
-use casper_types::bytesrepr::Bytes;
+use casper_types::{URef, U256, ApiError, ContractPackageHash, Key, runtime_args, RuntimeArgs};
+use casper_types::bytesrepr::{Bytes, ToBytes};
+
 use constants::{ADDRESS_RUNTIME_ARG_NAME, BALANCE_OF_EXTERNAL_ENTRY_POINT};
 use Error;
 
@@ -24,17 +26,53 @@ pub(crate) fn get_erc777_uref() -> URef {
     key.try_into().unwrap_or_revert()
 }
 
-/// this works to save the erc777 when it deploys the contract.
-pub(crate) fn save_erc777_contract(erc777_uref: URef, contract_hash: ContractHash) {
-    storage::dictionary_put(erc777_uref, ERC777_REGISTRY_KEY_NAME, contract_hash);
+/// Creates a dictionary item key for a token.
+#[inline]
+fn make_dictionary_item_key(token: Key) -> String {
+    let preimage = token.to_bytes().unwrap_or_revert();
+    base64::encode(&preimage)
+}
+
+/// Registers `token`, the key of an erc777 contract package.
+pub(crate) fn add_token(erc777_uref: URef, token: Key) -> Result<(), Error> {
+    token.into_hash().ok_or(Error::InvalidAddress)?;
+    storage::dictionary_put(erc777_uref, &make_dictionary_item_key(token), true);
+    Ok(())
+}
+
+/// Unregisters `token`.
+pub(crate) fn remove_token(erc777_uref: URef, token: Key) {
+    storage::dictionary_put(erc777_uref, &make_dictionary_item_key(token), false);
+}
+
+/// Returns whether `token` is registered.
+pub(crate) fn is_registered(erc777_uref: URef, token: Key) -> bool {
+    storage::dictionary_get(erc777_uref, &make_dictionary_item_key(token))
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Returns the package hash of a registered `token`.
+fn token_package_hash(erc777_uref: URef, token: Key) -> Result<ContractPackageHash, Error> {
+    if !is_registered(erc777_uref, token) {
+        return Err(Error::UnknownToken);
+    }
+    token.into_hash()
+        .map(ContractPackageHash::new)
+        .ok_or(Error::UnknownToken)
 }
 
 /// transfer tokens calling an erc777 contract
-pub(crate) fn transfer(erc777_uref: URef, from: Key, to: Key, amount: U256, user_data: Bytes, operator_data: Bytes) {
-    let hash_contract = storage::dictionary_get(
-        erc777_uref,
-        ERC777_REGISTRY_KEY_NAME
-    ).unwrap_or_default().unwrap_or_default();
+pub(crate) fn transfer(
+    erc777_uref: URef,
+    token: Key,
+    from: Key,
+    to: Key,
+    amount: U256,
+    user_data: Bytes,
+    operator_data: Bytes
+) -> Result<(), Error> {
+    let package_hash = token_package_hash(erc777_uref, token)?;
 
     let registry_args = runtime_args! {
         SENDER_RUNTIME_ARG_NAME => from,
@@ -43,19 +81,25 @@ pub(crate) fn transfer(erc777_uref: URef, from: Key, to: Key, amount: U256, user
         DATA_RUNTIME_ARG_NAME => user_data,
         OPERATOR_DATA_RUNTIME_ARG_NAME => operator_data
     };
-    runtime::call_contract::<()>(
-        hash_contract,
+    runtime::call_versioned_contract::<()>(
+        package_hash,
+        None,
         OPERATOR_SEND_EXTERNAL_ENTRY_POINT,
         registry_args,
     );
+    Ok(())
 }
 
 /// burn tokens calling an erc777 contract
-pub(crate) fn burn(erc777_uref: URef, account: Key, amount: U256, user_data: Bytes, operator_data: Bytes) {
-    let hash_contract = storage::dictionary_get(
-        erc777_uref,
-        ERC777_REGISTRY_KEY_NAME
-    ).unwrap_or_default().unwrap_or_default();
+pub(crate) fn burn(
+    erc777_uref: URef,
+    token: Key,
+    account: Key,
+    amount: U256,
+    user_data: Bytes,
+    operator_data: Bytes
+) -> Result<(), Error> {
+    let package_hash = token_package_hash(erc777_uref, token)?;
 
     let registry_args = runtime_args! {
         ACCOUNT_RUNTIME_ARG_NAME => account,
@@ -63,28 +107,28 @@ pub(crate) fn burn(erc777_uref: URef, account: Key, amount: U256, user_data: Byt
         DATA_RUNTIME_ARG_NAME => user_data,
         OPERATOR_DATA_RUNTIME_ARG_NAME => operator_data
     };
-    runtime::call_contract::<()>(
-        hash_contract,
+    runtime::call_versioned_contract::<()>(
+        package_hash,
+        None,
         OPERATOR_BURN_EXTERNAL_ENTRY_POINT,
         registry_args,
     );
+    Ok(())
 }
 
 /// getting balance of account calling an erc777 contract
-pub(crate) fn balance_of(erc777_uref: URef, account: Key) -> Result<U256, Error>{
-    let hash_contract = storage::dictionary_get(
-        erc777_uref,
-        ERC777_REGISTRY_KEY_NAME
-    ).unwrap_or_default().unwrap_or_default();
+pub(crate) fn balance_of(erc777_uref: URef, token: Key, account: Key) -> Result<U256, Error>{
+    let package_hash = token_package_hash(erc777_uref, token)?;
 
     let registry_args = runtime_args! {
         ADDRESS_RUNTIME_ARG_NAME => account
     };
-    let result = runtime::call_contract::<U256>(
-        hash_contract,
+    let result = runtime::call_versioned_contract::<U256>(
+        package_hash,
+        None,
         BALANCE_OF_EXTERNAL_ENTRY_POINT,
         registry_args,
     );
 
     Ok(result)
-}
\ No newline at end of file
+}
@@ -11,6 +11,9 @@ use casper_types::ApiError;
 ///
 /// Such a user error should be in the range `[0..(u16::MAX - 4)]` (i.e. [0, 65532]) to avoid
 /// conflicting with the other `Error` variants.
+///
+/// The errors of the registered tokens, of the delegates, of the served accounts, of the receive
+/// policies and of the invoices use the range `[1400..1499]`.
 pub enum Error {
     /// ERC20 contract called from within an invalid context.
     InvalidContext,
@@ -25,7 +28,37 @@ pub enum Error {
     ///
     InvalidAddress,
     ///
-    InvalidOperator
+    InvalidOperator,
+    /// The caller is not allowed to configure the contract.
+    Unauthorized,
+    /// The token is not registered in the contract.
+    UnknownToken,
+    /// The contract is not registered for the account.
+    AccountNotServed,
+    /// The sender is not in the sender allowlist of the holder.
+    SenderNotAllowed,
+    /// The amount is smaller than the minimum amount of a single movement of the holder.
+    AmountTooSmall,
+    /// The amount is greater than the maximum amount of a single movement of the holder.
+    AmountTooLarge,
+    /// The `data` does not start with the prefix required by the holder.
+    MissingDataPrefix,
+    /// An invoice already exists with the same id.
+    InvoiceExists,
+    /// No invoice exists with the id carried by the `data`.
+    InvoiceNotFound,
+    /// The invoice was already paid.
+    InvoiceAlreadyPaid,
+    /// The invoice expired before the payment.
+    InvoiceExpired,
+    /// The invoice is payable in another token.
+    InvoiceWrongToken,
+    /// The amount is greater than the amount due on the invoice.
+    InvoiceOverpaid,
+    /// The amount is smaller than the amount due on the invoice.
+    InvoiceUnderpaid,
+    /// The invoice id carried by the `data` is not valid UTF-8.
+    InvalidInvoiceId
 }
 
 const ERROR_INVALID_CONTEXT: u16 = u16::MAX;
@@ -34,6 +67,21 @@ const ERROR_INSUFFICIENT_ALLOWANCE: u16 = u16::MAX - 2;
 const ERROR_OVERFLOW: u16 = u16::MAX - 3;
 const ERROR_ADDRESS_UNKNOWN: u16 = u16::MAX - 4;
 const ERROR_OPERATOR_NOT_FOUND: u16 = u16::MAX - 5;
+const ERROR_UNAUTHORIZED: u16 = 1400;
+const ERROR_UNKNOWN_TOKEN: u16 = 1401;
+const ERROR_ACCOUNT_NOT_SERVED: u16 = 1402;
+const ERROR_SENDER_NOT_ALLOWED: u16 = 1403;
+const ERROR_AMOUNT_TOO_SMALL: u16 = 1404;
+const ERROR_AMOUNT_TOO_LARGE: u16 = 1405;
+const ERROR_MISSING_DATA_PREFIX: u16 = 1406;
+const ERROR_INVOICE_EXISTS: u16 = 1407;
+const ERROR_INVOICE_NOT_FOUND: u16 = 1408;
+const ERROR_INVOICE_ALREADY_PAID: u16 = 1409;
+const ERROR_INVOICE_EXPIRED: u16 = 1410;
+const ERROR_INVOICE_WRONG_TOKEN: u16 = 1411;
+const ERROR_INVOICE_OVERPAID: u16 = 1412;
+const ERROR_INVOICE_UNDERPAID: u16 = 1413;
+const ERROR_INVALID_INVOICE_ID: u16 = 1414;
 
 impl From<Error> for ApiError {
     fn from(error: Error) -> Self {
@@ -44,7 +92,22 @@ impl From<Error> for ApiError {
             Error::Overflow => ERROR_OVERFLOW,
             Error::User(user_error) => user_error,
             Error::InvalidAddress => ERROR_ADDRESS_UNKNOWN,
-            Error::InvalidOperator => ERROR_OPERATOR_NOT_FOUND
+            Error::InvalidOperator => ERROR_OPERATOR_NOT_FOUND,
+            Error::Unauthorized => ERROR_UNAUTHORIZED,
+            Error::UnknownToken => ERROR_UNKNOWN_TOKEN,
+            Error::AccountNotServed => ERROR_ACCOUNT_NOT_SERVED,
+            Error::SenderNotAllowed => ERROR_SENDER_NOT_ALLOWED,
+            Error::AmountTooSmall => ERROR_AMOUNT_TOO_SMALL,
+            Error::AmountTooLarge => ERROR_AMOUNT_TOO_LARGE,
+            Error::MissingDataPrefix => ERROR_MISSING_DATA_PREFIX,
+            Error::InvoiceExists => ERROR_INVOICE_EXISTS,
+            Error::InvoiceNotFound => ERROR_INVOICE_NOT_FOUND,
+            Error::InvoiceAlreadyPaid => ERROR_INVOICE_ALREADY_PAID,
+            Error::InvoiceExpired => ERROR_INVOICE_EXPIRED,
+            Error::InvoiceWrongToken => ERROR_INVOICE_WRONG_TOKEN,
+            Error::InvoiceOverpaid => ERROR_INVOICE_OVERPAID,
+            Error::InvoiceUnderpaid => ERROR_INVOICE_UNDERPAID,
+            Error::InvalidInvoiceId => ERROR_INVALID_INVOICE_ID
         };
         ApiError::User(user_error)
     }
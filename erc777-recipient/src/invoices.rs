@@ -0,0 +1,256 @@
+//! Implementation of the invoices, the payments the owner expects to receive.
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryInto;
+
+use casper_contract::{contract_api::{runtime, storage}, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{ApiError, CLType, CLTyped, Key, URef, U256};
+use casper_types::bytesrepr::{self, Bytes, FromBytes, ToBytes};
+use constants::{
+    INVOICES_REGISTRY_KEY_NAME, INVOICE_RULES_REGISTRY_KEY_NAME, INVOICE_DATA_PREFIX,
+    REJECT_OVERPAYMENT_RULE, REJECT_UNDERPAYMENT_RULE, REJECT_EXPIRED_RULE
+};
+use error::Error;
+
+/// The state of an [`Invoice`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum InvoiceStatus {
+    /// The invoice waits for its payment.
+    Open,
+    /// The whole amount of the invoice was received.
+    Paid,
+    /// The invoice was not paid before its expiry.
+    Expired,
+}
+
+impl InvoiceStatus {
+    fn tag(&self) -> u8 {
+        match self {
+            InvoiceStatus::Open => 0,
+            InvoiceStatus::Paid => 1,
+            InvoiceStatus::Expired => 2,
+        }
+    }
+}
+
+impl CLTyped for InvoiceStatus {
+    fn cl_type() -> CLType {
+        CLType::U8
+    }
+}
+
+impl ToBytes for InvoiceStatus {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        self.tag().to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.tag().serialized_length()
+    }
+}
+
+impl FromBytes for InvoiceStatus {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        let status = match tag {
+            0 => InvoiceStatus::Open,
+            1 => InvoiceStatus::Paid,
+            2 => InvoiceStatus::Expired,
+            _ => return Err(bytesrepr::Error::Formatting),
+        };
+        Ok((status, remainder))
+    }
+}
+
+/// A payment of `amount` tokens of `token` the owner expects before `expiry`.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Invoice {
+    /// Key of the contract package of the token to pay with.
+    pub token: Key,
+    /// Amount to pay.
+    pub amount: U256,
+    /// Block time in milliseconds after which the invoice expires, or 0 if it never expires.
+    pub expiry: u64,
+    /// Amount received so far.
+    pub paid: U256,
+    /// Stored state of the invoice, which is never [`InvoiceStatus::Expired`].
+    pub status: InvoiceStatus,
+}
+
+impl Invoice {
+    /// Returns `true` if the invoice has an expiry and `now` is past it.
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expiry != 0 && now > self.expiry
+    }
+
+    /// Returns the state of the invoice at `now`.
+    pub fn status_at(&self, now: u64) -> InvoiceStatus {
+        if self.status == InvoiceStatus::Open && self.is_expired(now) {
+            InvoiceStatus::Expired
+        } else {
+            self.status
+        }
+    }
+}
+
+impl CLTyped for Invoice {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Invoice {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.token.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.expiry.to_bytes()?);
+        result.append(&mut self.paid.to_bytes()?);
+        result.append(&mut self.status.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.token.serialized_length()
+            + self.amount.serialized_length()
+            + self.expiry.serialized_length()
+            + self.paid.serialized_length()
+            + self.status.serialized_length()
+    }
+}
+
+impl FromBytes for Invoice {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (token, remainder) = Key::from_bytes(bytes)?;
+        let (amount, remainder) = U256::from_bytes(remainder)?;
+        let (expiry, remainder) = u64::from_bytes(remainder)?;
+        let (paid, remainder) = U256::from_bytes(remainder)?;
+        let (status, remainder) = InvoiceStatus::from_bytes(remainder)?;
+        let invoice = Invoice {
+            token,
+            amount,
+            expiry,
+            paid,
+            status,
+        };
+        Ok((invoice, remainder))
+    }
+}
+
+/// get the invoices uref.
+#[inline]
+pub(crate) fn get_invoices_uref() -> URef {
+    let key = runtime::get_key(INVOICES_REGISTRY_KEY_NAME)
+        .ok_or(ApiError::MissingKey)
+        .unwrap_or_revert();
+    key.try_into().unwrap_or_revert()
+}
+
+/// get the invoice rules uref.
+#[inline]
+pub(crate) fn get_invoice_rules_uref() -> URef {
+    let key = runtime::get_key(INVOICE_RULES_REGISTRY_KEY_NAME)
+        .ok_or(ApiError::MissingKey)
+        .unwrap_or_revert();
+    key.try_into().unwrap_or_revert()
+}
+
+/// Creates a dictionary item key for an invoice id.
+#[inline]
+fn make_dictionary_item_key(invoice_id: &str) -> String {
+    hex::encode(runtime::blake2b(invoice_id.as_bytes()))
+}
+
+/// Reads the invoice stored under `invoice_id`, if any.
+pub(crate) fn read_invoice(invoices_uref: URef, invoice_id: &str) -> Option<Invoice> {
+    storage::dictionary_get(invoices_uref, make_dictionary_item_key(invoice_id).as_str())
+        .unwrap_or_revert()
+}
+
+fn write_invoice(invoices_uref: URef, invoice_id: &str, invoice: Invoice) {
+    storage::dictionary_put(invoices_uref, make_dictionary_item_key(invoice_id).as_str(), invoice);
+}
+
+/// Creates an open invoice under `invoice_id`, which cannot be used twice.
+pub(crate) fn create(
+    invoices_uref: URef,
+    invoice_id: &str,
+    token: Key,
+    amount: U256,
+    expiry: u64
+) -> Result<(), Error> {
+    if read_invoice(invoices_uref, invoice_id).is_some() {
+        return Err(Error::InvoiceExists);
+    }
+    write_invoice(invoices_uref, invoice_id, Invoice {
+        token,
+        amount,
+        expiry,
+        paid: U256::zero(),
+        status: InvoiceStatus::Open,
+    });
+    Ok(())
+}
+
+/// Stores whether the movements breaking `rule` are rejected.
+pub(crate) fn write_rule(rules_uref: URef, rule: &str, reject: bool) {
+    storage::dictionary_put(rules_uref, rule, reject);
+}
+
+/// Returns whether the movements breaking `rule` are rejected, which is the case by default.
+fn read_rule(rules_uref: URef, rule: &str) -> bool {
+    storage::dictionary_get(rules_uref, rule)
+        .unwrap_or_revert()
+        .unwrap_or(true)
+}
+
+/// Returns the invoice id of a movement, which is the rest of `data` after [`INVOICE_DATA_PREFIX`],
+/// or an error when it is not valid UTF-8.
+pub(crate) fn parse_invoice_id(data: &Bytes) -> Result<Option<String>, Error> {
+    if !data.starts_with(INVOICE_DATA_PREFIX) {
+        return Ok(None);
+    }
+    core::str::from_utf8(&data[INVOICE_DATA_PREFIX.len()..])
+        .map(|invoice_id| Some(String::from(invoice_id)))
+        .map_err(|_| Error::InvalidInvoiceId)
+}
+
+/// Adds a payment of `amount` tokens of `token` to the invoice `invoice_id`.
+///
+/// Expired invoices, overpayments and underpayments are rejected unless the owner has relaxed the
+/// rule, an underpayment then leaves the invoice open until the rest is received.
+pub(crate) fn pay(
+    invoices_uref: URef,
+    rules_uref: URef,
+    invoice_id: &str,
+    token: Key,
+    amount: U256,
+    now: u64
+) -> Result<(), Error> {
+    let mut invoice = read_invoice(invoices_uref, invoice_id).ok_or(Error::InvoiceNotFound)?;
+
+    if invoice.status == InvoiceStatus::Paid {
+        return Err(Error::InvoiceAlreadyPaid);
+    }
+    if invoice.token.ne(&token) {
+        return Err(Error::InvoiceWrongToken);
+    }
+    if read_rule(rules_uref, REJECT_EXPIRED_RULE) && invoice.is_expired(now) {
+        return Err(Error::InvoiceExpired);
+    }
+
+    let due = invoice.amount.saturating_sub(invoice.paid);
+    if read_rule(rules_uref, REJECT_OVERPAYMENT_RULE) && amount > due {
+        return Err(Error::InvoiceOverpaid);
+    }
+    if read_rule(rules_uref, REJECT_UNDERPAYMENT_RULE) && amount < due {
+        return Err(Error::InvoiceUnderpaid);
+    }
+
+    invoice.paid = invoice.paid.checked_add(amount).ok_or(Error::Overflow)?;
+    if invoice.paid >= invoice.amount {
+        invoice.status = InvoiceStatus::Paid;
+    }
+    write_invoice(invoices_uref, invoice_id, invoice);
+    Ok(())
+}
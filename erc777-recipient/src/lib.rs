@@ -10,6 +10,7 @@ extern crate alloc;
 extern crate casper_types;
 extern crate casper_contract;
 extern crate once_cell;
+extern crate casper_erc1820;
 
 pub mod constants;
 pub mod entry_points;
@@ -17,8 +18,16 @@ mod error;
 mod erc1820_registry;
 mod recipient_notifier;
 mod erc777_registry;
+mod owner;
+mod detail;
+mod delegates;
+mod served_accounts;
+mod policies;
+mod invoices;
 
-use alloc::string::{ToString};
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use once_cell::unsync::OnceCell;
 
 use casper_contract::{
@@ -26,28 +35,134 @@ use casper_contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{{contracts::NamedKeys, EntryPoints, Key, URef}, U256, ContractHash};
-use casper_types::bytesrepr::{Bytes, ToBytes};
+use casper_types::bytesrepr::Bytes;
+use casper_erc1820::{accept_magic, interface_hash};
 use constants::{
     ERC777_RECIPIENT_CONTRACT_NAME, BALANCES_REGISTRY_KEY_NAME,
-    HASH_ERC1820_RECIPIENT, ERC777_REGISTRY_KEY_NAME
+    HASH_ERC1820_RECIPIENT, ERC777_REGISTRY_KEY_NAME, OWNER_KEY_NAME, MOVEMENT_COUNT_KEY_NAME,
+    DELEGATES_REGISTRY_KEY_NAME, ERC1820_REGISTRY_KEY_NAME, SERVED_ACCOUNTS_KEY_NAME,
+    POLICIES_REGISTRY_KEY_NAME, MIN_AMOUNT_POLICY, MAX_AMOUNT_POLICY, INVOICES_REGISTRY_KEY_NAME,
+    INVOICE_RULES_REGISTRY_KEY_NAME, REJECT_OVERPAYMENT_RULE, REJECT_UNDERPAYMENT_RULE,
+    REJECT_EXPIRED_RULE
 };
-use error::Error;
+pub use error::Error;
+pub use invoices::{Invoice, InvoiceStatus};
 
 /// Struct
 #[derive(Default)]
 pub struct ERC777Recipient {
     balance_uref: OnceCell<URef>,
-    erc777_uref: OnceCell<URef>
+    erc777_uref: OnceCell<URef>,
+    owner_uref: OnceCell<URef>,
+    movement_count_uref: OnceCell<URef>,
+    delegates_uref: OnceCell<URef>,
+    erc1820_uref: OnceCell<URef>,
+    served_accounts_uref: OnceCell<URef>,
+    policies_uref: OnceCell<URef>,
+    invoices_uref: OnceCell<URef>,
+    invoice_rules_uref: OnceCell<URef>
 }
 
 impl ERC777Recipient {
-    fn new(balance_uref: URef, erc777_uref: URef) -> Self {
+    fn new(
+        balance_uref: URef,
+        erc777_uref: URef,
+        owner_uref: URef,
+        movement_count_uref: URef,
+        delegates_uref: URef,
+        erc1820_uref: URef,
+        served_accounts_uref: URef,
+        policies_uref: URef,
+        invoices_uref: URef,
+        invoice_rules_uref: URef
+    ) -> Self {
         Self {
             balance_uref: balance_uref.into(),
-            erc777_uref: erc777_uref.into()
+            erc777_uref: erc777_uref.into(),
+            owner_uref: owner_uref.into(),
+            movement_count_uref: movement_count_uref.into(),
+            delegates_uref: delegates_uref.into(),
+            erc1820_uref: erc1820_uref.into(),
+            served_accounts_uref: served_accounts_uref.into(),
+            policies_uref: policies_uref.into(),
+            invoices_uref: invoices_uref.into(),
+            invoice_rules_uref: invoice_rules_uref.into()
         }
     }
 
+    /// it loads uref of the owner namekey
+    fn owner_uref(&self) -> URef {
+        *self.owner_uref.get_or_init(owner::get_owner_uref)
+    }
+
+    /// it loads uref of the delegates namekey
+    fn delegates_uref(&self) -> URef {
+        *self.delegates_uref.get_or_init(delegates::get_delegates_uref)
+    }
+
+    /// it loads uref of the erc1820 registry namekey
+    fn erc1820_uref(&self) -> URef {
+        *self.erc1820_uref.get_or_init(erc1820_registry::get_erc1820_uref)
+    }
+
+    /// it loads uref of the served accounts namekey
+    fn served_accounts_uref(&self) -> URef {
+        *self.served_accounts_uref.get_or_init(served_accounts::get_served_accounts_uref)
+    }
+
+    /// it loads uref of the receive policies namekey
+    fn policies_uref(&self) -> URef {
+        *self.policies_uref.get_or_init(policies::get_policies_uref)
+    }
+
+    /// it loads uref of the invoices namekey
+    fn invoices_uref(&self) -> URef {
+        *self.invoices_uref.get_or_init(invoices::get_invoices_uref)
+    }
+
+    /// it loads uref of the invoice rules namekey
+    fn invoice_rules_uref(&self) -> URef {
+        *self.invoice_rules_uref.get_or_init(invoices::get_invoice_rules_uref)
+    }
+
+    /// Returns an error unless the caller is the owner.
+    fn only_owner(&self) -> Result<(), Error> {
+        let owner = owner::read_owner(self.owner_uref());
+        if detail::get_immediate_caller_key()?.ne(&owner) {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Returns the caller when it is a served account, the receive policies are those of the caller.
+    fn only_served(&self) -> Result<Key, Error> {
+        let caller = detail::get_immediate_caller_key()?;
+        if !served_accounts::is_served(self.served_accounts_uref(), caller) {
+            return Err(Error::AccountNotServed);
+        }
+        Ok(caller)
+    }
+
+    /// Returns an error unless the caller is the erc1820 manager of `account`.
+    fn only_manager_of(&self, account: Key) -> Result<(), Error> {
+        let erc1820_hash = erc1820_registry::read_erc1820(self.erc1820_uref());
+        let manager = erc1820_registry::get_manager(account, erc1820_hash);
+        if detail::get_immediate_caller_key()?.ne(&manager) {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Returns an error unless the caller is the owner or one of its delegates.
+    fn only_owner_or_delegate(&self) -> Result<(), Error> {
+        let caller = detail::get_immediate_caller_key()?;
+        let owner = owner::read_owner(self.owner_uref());
+        if caller.ne(&owner) && !delegates::is_delegate(self.delegates_uref(), caller) {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
     /// it loads uref of erc777 namekey
     pub fn erc777_uref(&self) -> URef {
         *self.erc777_uref.get_or_init(erc777_registry::get_erc777_uref)
@@ -58,6 +173,11 @@ impl ERC777Recipient {
         *self.balance_uref.get_or_init(recipient_notifier::get_balance_uref)
     }
 
+    /// it loads uref of the movement count namekey
+    fn movement_count_uref(&self) -> URef {
+        *self.movement_count_uref.get_or_init(recipient_notifier::get_movement_count_uref)
+    }
+
     /// The movements or creations are performed in a registered account `to`.
     /// The type operation is conveyed by `from` being the zero address or not.
     pub fn tokens_received(
@@ -69,57 +189,241 @@ impl ERC777Recipient {
         data: Bytes,
         operator_data: Bytes
     ) -> Result<(), Error> {
-        //register or revert
+        let token = detail::get_immediate_caller_key()?;
+        if !erc777_registry::is_registered(self.erc777_uref(), token) {
+            return Err(Error::UnknownToken);
+        }
+
+        policies::check(self.policies_uref(), from, to, amount, &data)?;
+
+        // Invoices are created by the owner only, so only the movements to the owner pay them.
+        if to.eq(&owner::read_owner(self.owner_uref())) {
+            if let Some(invoice_id) = invoices::parse_invoice_id(&data)? {
+                invoices::pay(
+                    self.invoices_uref(),
+                    self.invoice_rules_uref(),
+                    &invoice_id,
+                    token,
+                    amount,
+                    u64::from(runtime::get_blocktime())
+                )?;
+            }
+        }
+
+        recipient_notifier::record(
+            self.balance_uref(),
+            self.movement_count_uref(),
+            token,
+            operator,
+            from,
+            to,
+            amount,
+            data,
+            operator_data
+        );
+        Ok(())
+    }
+
+    /// Returns the number of movements recorded by the hook.
+    pub fn movement_count(self) -> u64 {
+        recipient_notifier::movement_count(self.movement_count_uref())
+    }
+
+    /// Returns the movement recorded under the sequence number `index`.
+    pub fn get_movement(self, index: u64) -> BTreeMap<String, String> {
+        recipient_notifier::get_movement(self.balance_uref(), index)
+    }
+
+    /// Returns at most `limit` movements, starting with the sequence number `offset`.
+    pub fn get_movements(self, offset: u64, limit: u64) -> Vec<BTreeMap<String, String>> {
+        recipient_notifier::get_movements(self.balance_uref(), self.movement_count_uref(), offset, limit)
+    }
+
+    /// Adds `account` to the served accounts, so the contract agrees to be its `ERC777TokensRecipient`
+    /// implementer.
+    ///
+    /// The caller must be the erc1820 manager of `account`, which is `account` itself by default.
+    /// The manager then sets the contract as the implementer in the erc1820 registry itself, as the
+    /// registry only accepts changes from the manager.
+    pub fn register_for(self, account: Key) -> Result<(), Error> {
+        self.only_manager_of(account)?;
+        served_accounts::add_account(self.served_accounts_uref(), account);
+        Ok(())
+    }
 
-        recipient_notifier::tokens_received(self.balance_uref(), operator, from, to, amount, data, operator_data);
+    /// Removes `account` from the served accounts.
+    ///
+    /// The caller must be the erc1820 manager of `account`, which is `account` itself by default.
+    /// The manager clears the implementer in the erc1820 registry itself, tokens keep calling the
+    /// hook for `account` until then.
+    pub fn unregister_for(self, account: Key) -> Result<(), Error> {
+        self.only_manager_of(account)?;
+        served_accounts::remove_account(self.served_accounts_uref(), account)?;
         Ok(())
     }
 
-    /// it queries balance of some registered owner
-    pub fn balance_of(&self, owner: Key) -> Result<U256, Error> {
-        erc777_registry::balance_of(self.erc777_uref(), owner)
+    /// Returns the accounts the contract is registered for.
+    pub fn get_served_accounts(self) -> Vec<Key> {
+        served_accounts::read_served_accounts(self.served_accounts_uref())
+    }
+
+    /// Restricts the senders the caller accepts tokens from to `senders`, an empty list allows any.
+    pub fn set_sender_allowlist(self, senders: Vec<Key>) -> Result<(), Error> {
+        let holder = self.only_served()?;
+        policies::write_senders(self.policies_uref(), holder, senders);
+        Ok(())
+    }
+
+    /// Rejects every movement to the caller smaller than `amount`, zero removes the limit.
+    pub fn set_min_amount(self, amount: U256) -> Result<(), Error> {
+        let holder = self.only_served()?;
+        policies::write_amount(self.policies_uref(), holder, MIN_AMOUNT_POLICY, amount);
+        Ok(())
+    }
+
+    /// Rejects every movement to the caller greater than `amount`, zero removes the limit.
+    pub fn set_max_amount(self, amount: U256) -> Result<(), Error> {
+        let holder = self.only_served()?;
+        policies::write_amount(self.policies_uref(), holder, MAX_AMOUNT_POLICY, amount);
+        Ok(())
+    }
+
+    /// Requires the `data` of every movement to the caller to start with `prefix`, an empty prefix
+    /// removes the requirement.
+    pub fn set_data_prefix(self, prefix: Bytes) -> Result<(), Error> {
+        let holder = self.only_served()?;
+        policies::write_data_prefix(self.policies_uref(), holder, prefix);
+        Ok(())
+    }
+
+    /// Creates the invoice `invoice_id` of `amount` tokens of `token`, paid by any movement to the
+    /// owner whose `data` is `invoice:` followed by `invoice_id`.
+    ///
+    /// Invoices belong to the owner only: a movement to another served account never pays one,
+    /// whatever its `data`.
+    ///
+    /// `expiry` is the block time in milliseconds after which the invoice expires, zero for never.
+    pub fn create_invoice(
+        self,
+        invoice_id: String,
+        token: Key,
+        amount: U256,
+        expiry: u64
+    ) -> Result<(), Error> {
+        self.only_owner()?;
+        invoices::create(self.invoices_uref(), &invoice_id, token, amount, expiry)
+    }
+
+    /// Returns the state of the invoice `invoice_id`.
+    pub fn invoice_status(self, invoice_id: String) -> Result<InvoiceStatus, Error> {
+        let invoice = invoices::read_invoice(self.invoices_uref(), &invoice_id)
+            .ok_or(Error::InvoiceNotFound)?;
+        Ok(invoice.status_at(u64::from(runtime::get_blocktime())))
+    }
+
+    /// Sets which invoice payments are rejected: the payments greater or smaller than the amount
+    /// due, and the payments of expired invoices. Every rule is on by default.
+    pub fn set_invoice_rules(
+        self,
+        reject_overpayment: bool,
+        reject_underpayment: bool,
+        reject_expired: bool
+    ) -> Result<(), Error> {
+        self.only_owner()?;
+        let rules_uref = self.invoice_rules_uref();
+        invoices::write_rule(rules_uref, REJECT_OVERPAYMENT_RULE, reject_overpayment);
+        invoices::write_rule(rules_uref, REJECT_UNDERPAYMENT_RULE, reject_underpayment);
+        invoices::write_rule(rules_uref, REJECT_EXPIRED_RULE, reject_expired);
+        Ok(())
+    }
+
+    /// Registers `token`, the key of an erc777 contract package, so that its movements go through
+    /// the hook and the contract can move it.
+    pub fn add_token(self, token: Key) -> Result<(), Error> {
+        self.only_owner()?;
+        erc777_registry::add_token(self.erc777_uref(), token)
+    }
+
+    /// Unregisters `token`, its movements are rejected by the hook from then on.
+    pub fn remove_token(self, token: Key) -> Result<(), Error> {
+        self.only_owner()?;
+        erc777_registry::remove_token(self.erc777_uref(), token);
+        Ok(())
+    }
+
+    /// Allows `delegate` to move funds through **transfer** and **burn**, along with the owner.
+    pub fn add_delegate(self, delegate: Key) -> Result<(), Error> {
+        self.only_owner()?;
+        delegates::write_delegate(self.delegates_uref(), delegate, true);
+        Ok(())
+    }
+
+    /// Disallows `delegate` to move funds.
+    pub fn remove_delegate(self, delegate: Key) -> Result<(), Error> {
+        self.only_owner()?;
+        delegates::write_delegate(self.delegates_uref(), delegate, false);
+        Ok(())
+    }
+
+    /// it queries balance of some registered owner in `token`
+    pub fn balance_of(&self, token: Key, owner: Key) -> Result<U256, Error> {
+        erc777_registry::balance_of(self.erc777_uref(), token, owner)
     }
 
     /// it transfers tokens from erc777's operator_send
     pub fn transfer(
         self,
+        token: Key,
         from: Key,
         to: Key,
         amount: U256,
         user_data: Bytes,
         operator_data: Bytes
     ) -> Result<(), Error>{
-        erc777_registry::transfer(self.erc777_uref() , from, to, amount, user_data, operator_data);
-
-        Ok(())
+        self.only_owner_or_delegate()?;
+        erc777_registry::transfer(self.erc777_uref(), token, from, to, amount, user_data, operator_data)
     }
 
     /// Allows burning a ´amount´ tokens on behalf of the tokens' owner.
     pub fn burn(
         self,
+        token: Key,
         account: Key,
         amount: U256,
         user_data: Bytes,
         operator_data: Bytes
     ) -> Result<(), Error>{
+        self.only_owner_or_delegate()?;
         erc777_registry::burn(
             self.erc777_uref(),
+            token,
             account,
             amount,
             user_data,
             operator_data
-        );
-        Ok(())
+        )
+    }
+
+    /// Answers the ERC1820 registry whether this contract agrees to be the `ERC777TokensRecipient`
+    /// implementer of `account`, which is only the case for the accounts it serves.
+    pub fn can_implement_interface_for_address(self, i_hash: Bytes, account: Key) -> Bytes {
+        if i_hash.eq(&interface_hash(HASH_ERC1820_RECIPIENT))
+            && served_accounts::is_served(self.served_accounts_uref(), account) {
+            accept_magic()
+        } else {
+            Bytes::default()
+        }
     }
 
     /// Installs the ERC777Recipient contract with the default set of entry points.
     ///
     /// This should be called from within `fn call()` of your contract.
-    pub fn install(erc1820_hash: ContractHash, erc777_hash: ContractHash) -> Result<ERC777Recipient, Error> {
+    ///
+    /// Tokens are registered afterwards with [`ERC777Recipient::add_token`].
+    pub fn install(erc1820_hash: ContractHash) -> Result<ERC777Recipient, Error> {
         let default_entry_points = entry_points::default();
         ERC777Recipient::install_custom(
             erc1820_hash,
-            erc777_hash,
             ERC777_RECIPIENT_CONTRACT_NAME,
             default_entry_points,
         )
@@ -135,12 +439,20 @@ impl ERC777Recipient {
     #[doc(hidden)]
     pub fn install_custom(
         erc1820_hash: ContractHash,
-        erc777_hash: ContractHash,
         contract_key_name: &str,
         entry_points: EntryPoints,
     ) -> Result<ERC777Recipient, Error> {
         let balance_uref = storage::new_dictionary(BALANCES_REGISTRY_KEY_NAME).unwrap_or_revert();
         let erc777_uref = storage::new_dictionary(ERC777_REGISTRY_KEY_NAME).unwrap_or_revert();
+        let delegates_uref = storage::new_dictionary(DELEGATES_REGISTRY_KEY_NAME).unwrap_or_revert();
+        let policies_uref = storage::new_dictionary(POLICIES_REGISTRY_KEY_NAME).unwrap_or_revert();
+        let invoices_uref = storage::new_dictionary(INVOICES_REGISTRY_KEY_NAME).unwrap_or_revert();
+        let invoice_rules_uref = storage::new_dictionary(INVOICE_RULES_REGISTRY_KEY_NAME).unwrap_or_revert();
+
+        let owner_uref = storage::new_uref(Key::from(runtime::get_caller())).into_read();
+        let movement_count_uref = storage::new_uref(0u64).into_read_write();
+        let erc1820_uref = storage::new_uref(erc1820_hash).into_read();
+        let served_accounts_uref = storage::new_dictionary(SERVED_ACCOUNTS_KEY_NAME).unwrap_or_revert();
 
         let mut named_keys = NamedKeys::new();
 
@@ -150,13 +462,47 @@ impl ERC777Recipient {
         };
 
         let erc777_key = {
-            erc777_registry::save_erc777_contract(erc777_uref, erc777_hash);
             runtime::remove_key(ERC777_REGISTRY_KEY_NAME);
             Key::from(erc777_uref)
         };
 
+        let delegates_key = {
+            runtime::remove_key(DELEGATES_REGISTRY_KEY_NAME);
+            Key::from(delegates_uref)
+        };
+
+        let served_accounts_key = {
+            // The account which installs the contract is served from the start.
+            served_accounts::add_account(served_accounts_uref, Key::from(runtime::get_caller()));
+            runtime::remove_key(SERVED_ACCOUNTS_KEY_NAME);
+            Key::from(served_accounts_uref)
+        };
+
+        let policies_key = {
+            runtime::remove_key(POLICIES_REGISTRY_KEY_NAME);
+            Key::from(policies_uref)
+        };
+
+        let invoices_key = {
+            runtime::remove_key(INVOICES_REGISTRY_KEY_NAME);
+            Key::from(invoices_uref)
+        };
+
+        let invoice_rules_key = {
+            runtime::remove_key(INVOICE_RULES_REGISTRY_KEY_NAME);
+            Key::from(invoice_rules_uref)
+        };
+
         named_keys.insert(BALANCES_REGISTRY_KEY_NAME.to_string(), movement_key);
         named_keys.insert(ERC777_REGISTRY_KEY_NAME.to_string(), erc777_key);
+        named_keys.insert(OWNER_KEY_NAME.to_string(), Key::from(owner_uref));
+        named_keys.insert(MOVEMENT_COUNT_KEY_NAME.to_string(), Key::from(movement_count_uref));
+        named_keys.insert(DELEGATES_REGISTRY_KEY_NAME.to_string(), delegates_key);
+        named_keys.insert(ERC1820_REGISTRY_KEY_NAME.to_string(), Key::from(erc1820_uref));
+        named_keys.insert(SERVED_ACCOUNTS_KEY_NAME.to_string(), served_accounts_key);
+        named_keys.insert(POLICIES_REGISTRY_KEY_NAME.to_string(), policies_key);
+        named_keys.insert(INVOICES_REGISTRY_KEY_NAME.to_string(), invoices_key);
+        named_keys.insert(INVOICE_RULES_REGISTRY_KEY_NAME.to_string(), invoice_rules_key);
 
         let (contract_hash, _version) =
             storage::new_contract(entry_points, Some(named_keys), None, None);
@@ -168,14 +514,22 @@ impl ERC777Recipient {
         let account_hash = runtime::get_caller();
         erc1820_registry::set_implementer(
             Key::from(account_hash),
-            Bytes::from(HASH_ERC1820_RECIPIENT.to_bytes().unwrap()),
+            interface_hash(HASH_ERC1820_RECIPIENT),
             contract_key,
             erc1820_hash
         );
 
         Ok(ERC777Recipient::new(
             balance_uref,
-            erc777_uref
+            erc777_uref,
+            owner_uref,
+            movement_count_uref,
+            delegates_uref,
+            erc1820_uref,
+            served_accounts_uref,
+            policies_uref,
+            invoices_uref,
+            invoice_rules_uref
         ))
     }
 }
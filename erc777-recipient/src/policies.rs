@@ -0,0 +1,94 @@
+//! Implementation of the receive policies, the rules a holder sets on its incoming movements.
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryInto;
+
+use casper_contract::{contract_api::{runtime, storage}, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{ApiError, CLTyped, Key, URef, U256};
+use casper_types::bytesrepr::{Bytes, FromBytes, ToBytes};
+use constants::{
+    POLICIES_REGISTRY_KEY_NAME, SENDER_ALLOWLIST_POLICY, MIN_AMOUNT_POLICY, MAX_AMOUNT_POLICY,
+    DATA_PREFIX_POLICY
+};
+use error::Error;
+
+/// get the policies uref.
+#[inline]
+pub(crate) fn get_policies_uref() -> URef {
+    let key = runtime::get_key(POLICIES_REGISTRY_KEY_NAME)
+        .ok_or(ApiError::MissingKey)
+        .unwrap_or_revert();
+    key.try_into().unwrap_or_revert()
+}
+
+/// Stores the only senders `holder` accepts tokens from, an empty list disables the policy.
+pub(crate) fn write_senders(policies_uref: URef, holder: Key, senders: Vec<Key>) {
+    storage::dictionary_put(
+        policies_uref,
+        make_dictionary_item_key(holder, SENDER_ALLOWLIST_POLICY).as_str(),
+        senders
+    );
+}
+
+/// Stores the amount of a `policy` of `holder`, zero disables the policy.
+pub(crate) fn write_amount(policies_uref: URef, holder: Key, policy: &str, amount: U256) {
+    storage::dictionary_put(policies_uref, make_dictionary_item_key(holder, policy).as_str(), amount);
+}
+
+/// Stores the prefix the `data` of movements to `holder` must start with, an empty prefix
+/// disables the policy.
+pub(crate) fn write_data_prefix(policies_uref: URef, holder: Key, prefix: Bytes) {
+    storage::dictionary_put(
+        policies_uref,
+        make_dictionary_item_key(holder, DATA_PREFIX_POLICY).as_str(),
+        prefix
+    );
+}
+
+/// Checks a movement of tokens to `to` against every policy `to` has set.
+pub(crate) fn check(
+    policies_uref: URef,
+    from: Key,
+    to: Key,
+    amount: U256,
+    data: &Bytes
+) -> Result<(), Error> {
+    let allowed_senders: Vec<Key> = read(policies_uref, to, SENDER_ALLOWLIST_POLICY);
+    if !allowed_senders.is_empty() && !allowed_senders.contains(&from) {
+        return Err(Error::SenderNotAllowed);
+    }
+
+    let min_amount: U256 = read(policies_uref, to, MIN_AMOUNT_POLICY);
+    if amount < min_amount {
+        return Err(Error::AmountTooSmall);
+    }
+
+    let max_amount: U256 = read(policies_uref, to, MAX_AMOUNT_POLICY);
+    if !max_amount.is_zero() && amount > max_amount {
+        return Err(Error::AmountTooLarge);
+    }
+
+    let prefix: Bytes = read(policies_uref, to, DATA_PREFIX_POLICY);
+    if !data.starts_with(&prefix) {
+        return Err(Error::MissingDataPrefix);
+    }
+
+    Ok(())
+}
+
+fn read<T: CLTyped + FromBytes + Default>(
+    policies_uref: URef,
+    holder: Key,
+    policy: &str
+) -> T {
+    storage::dictionary_get(policies_uref, make_dictionary_item_key(holder, policy).as_str())
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+#[inline]
+fn make_dictionary_item_key(holder: Key, policy: &str) -> String {
+    let mut preimage = holder.to_bytes().unwrap_or_revert();
+    preimage.extend_from_slice(policy.as_bytes());
+    hex::encode(runtime::blake2b(&preimage))
+}
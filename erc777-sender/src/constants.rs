@@ -1,11 +1,51 @@
 //! Constants used by the ERC777 Sender contract.
+use casper_types::Key;
+
 /// Contract Name
 pub const ERC777_SENDER_CONTRACT_NAME: &str = "erc777_sender_contract";
 
 /// Key name `movement_registry`
 pub const MOVEMENTS_REGISTRY_KEY_NAME: &str = "movement_registry";
-/// Key name `movement_registry`
+/// Key name `erc777_sender_registry`, the registered tokens
 pub const ERC777_REGISTRY_KEY_NAME: &str = "erc777_sender_registry";
+/// Key name `movement_count`
+pub const MOVEMENT_COUNT_KEY_NAME: &str = "movement_count";
+/// Key name `erc1820_registry`
+pub const ERC1820_REGISTRY_KEY_NAME: &str = "erc1820_registry";
+/// Key name `served_accounts`
+pub const SERVED_ACCOUNTS_KEY_NAME: &str = "served_accounts";
+/// Owner of the list of served accounts in the `served_accounts` dictionary, which holds a single list
+pub const SERVED_ACCOUNTS_LIST_OWNER: Key = Key::Hash([0u8; 32]);
+/// Key name `owner`
+pub const OWNER_KEY_NAME: &str = "owner";
+/// Key name `send_policies`
+pub const POLICIES_REGISTRY_KEY_NAME: &str = "send_policies";
+
+/// Key name `delegates`
+pub const DELEGATES_REGISTRY_KEY_NAME: &str = "delegates";
+
+/// Key name `daily_limits`
+pub const DAILY_LIMITS_REGISTRY_KEY_NAME: &str = "daily_limits";
+
+/// ------------ Daily limits
+/// Tag of the amount a holder can send per day
+pub const DAILY_LIMIT_TAG: &str = "limit";
+/// Tag of the start of the 24 hours window of a holder and the amount it sent in it
+pub const DAILY_SPENT_TAG: &str = "spent";
+/// Length of a day in block time
+pub const DAY_IN_MILLISECONDS: u64 = 86_400_000;
+
+/// ------------ Send policies
+/// Policy with the only recipients the holder can send to
+pub const RECIPIENT_ALLOWLIST_POLICY: &str = "recipient_allowlist";
+/// Policy with the recipients the holder cannot send to
+pub const RECIPIENT_BLOCKLIST_POLICY: &str = "recipient_blocklist";
+/// Policy with the maximum amount of a single movement
+pub const MAX_AMOUNT_POLICY: &str = "max_amount";
+/// Policy with the prefix the `user_data` must start with
+pub const DATA_PREFIX_POLICY: &str = "data_prefix";
+/// Policy with the only operators which can move the tokens of the holder
+pub const OPERATOR_ALLOWLIST_POLICY: &str = "operator_allowlist";
 
 
 /// ------------ Entry Points
@@ -15,6 +55,40 @@ pub const TOKENS_TO_SEND_ENTRY_POINT: &str = "tokens_to_send";
 pub const TRANSFER_ENTRY_POINT: &str = "transfer";
 /// Entry point named `transfer`
 pub const BURN_ENTRY_POINT: &str = "burn";
+/// Entry point named `set_recipient_allowlist`
+pub const SET_RECIPIENT_ALLOWLIST_ENTRY_POINT: &str = "set_recipient_allowlist";
+/// Entry point named `set_recipient_blocklist`
+pub const SET_RECIPIENT_BLOCKLIST_ENTRY_POINT: &str = "set_recipient_blocklist";
+/// Entry point named `set_max_amount`
+pub const SET_MAX_AMOUNT_ENTRY_POINT: &str = "set_max_amount";
+/// Entry point named `set_data_prefix`
+pub const SET_DATA_PREFIX_ENTRY_POINT: &str = "set_data_prefix";
+/// Entry point named `set_operator_allowlist`
+pub const SET_OPERATOR_ALLOWLIST_ENTRY_POINT: &str = "set_operator_allowlist";
+/// Entry point named `movement_count`
+pub const MOVEMENT_COUNT_ENTRY_POINT: &str = "movement_count";
+/// Entry point named `get_movement`
+pub const GET_MOVEMENT_ENTRY_POINT: &str = "get_movement";
+/// Entry point named `get_movements`
+pub const GET_MOVEMENTS_ENTRY_POINT: &str = "get_movements";
+/// Entry point named `register_for`
+pub const REGISTER_FOR_ENTRY_POINT: &str = "register_for";
+/// Entry point named `unregister_for`
+pub const UNREGISTER_FOR_ENTRY_POINT: &str = "unregister_for";
+/// Entry point named `get_served_accounts`
+pub const GET_SERVED_ACCOUNTS_ENTRY_POINT: &str = "get_served_accounts";
+/// Entry point named `add_token`
+pub const ADD_TOKEN_ENTRY_POINT: &str = "add_token";
+/// Entry point named `remove_token`
+pub const REMOVE_TOKEN_ENTRY_POINT: &str = "remove_token";
+/// Entry point named `add_delegate`
+pub const ADD_DELEGATE_ENTRY_POINT: &str = "add_delegate";
+/// Entry point named `remove_delegate`
+pub const REMOVE_DELEGATE_ENTRY_POINT: &str = "remove_delegate";
+/// Entry point named `set_daily_limit`
+pub const SET_DAILY_LIMIT_ENTRY_POINT: &str = "set_daily_limit";
+/// Entry point named `remaining_allowance_today`
+pub const REMAINING_ALLOWANCE_TODAY_ENTRY_POINT: &str = "remaining_allowance_today";
 
 
 /// Entry point's parameter
@@ -30,15 +104,35 @@ pub const AMOUNT_RUNTIME_ARG_NAME: &str = "amount";
 pub const USER_DATA_RUNTIME_ARG_NAME: &str = "user_data";
 /// Parameter operator_data
 pub const OPERATOR_DATA_RUNTIME_ARG_NAME: &str = "operator_data";
+/// Parameter recipients
+pub const RECIPIENTS_RUNTIME_ARG_NAME: &str = "recipients";
+/// Parameter operators
+pub const OPERATORS_RUNTIME_ARG_NAME: &str = "operators";
+/// Parameter prefix
+pub const PREFIX_RUNTIME_ARG_NAME: &str = "prefix";
+/// Parameter index
+pub const INDEX_RUNTIME_ARG_NAME: &str = "index";
+/// Parameter offset
+pub const OFFSET_RUNTIME_ARG_NAME: &str = "offset";
+/// Parameter limit
+pub const LIMIT_RUNTIME_ARG_NAME: &str = "limit";
+/// Parameter delegate
+pub const DELEGATE_RUNTIME_ARG_NAME: &str = "delegate";
+/// Parameter token
+pub const TOKEN_RUNTIME_ARG_NAME: &str = "token";
 /// Parameter erc1820_contract
 pub const ERC1820_CONTRACT_ARG_NAME: &str = "erc1820_contract";
-/// Parameter erc777_contract
-pub const ERC777_CONTRACT_ARG_NAME: &str = "erc777_contract";
 
 
 ///-------- External Contract
 /// Registry contract's entry points
 pub const SET_INTERFACE_OF_EXTERNAL_ENTRY_POINT: &str = "set_interface_implementer";
+/// Registry contract's entry points
+pub const GET_INTERFACE_OF_EXTERNAL_ENTRY_POINT: &str = "get_interface_implementer";
+/// Registry contract's entry points
+pub const CLEAR_INTERFACE_OF_EXTERNAL_ENTRY_POINT: &str = "clear_interface_implementer";
+/// Registry contract's entry points
+pub const GET_MANAGER_OF_EXTERNAL_ENTRY_POINT: &str = "get_manager";
 
 /// Name of `account` runtime argument.
 pub const ACCOUNT_RUNTIME_ARG_NAME: &str = "account";
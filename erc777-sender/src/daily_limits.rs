@@ -0,0 +1,100 @@
+//! Implementation of the daily limits, the amount of tokens a holder can send per 24 hours.
+//!
+//! The spending of a holder is counted in a window opened by its first movement, which lasts
+//! [`DAY_IN_MILLISECONDS`] from then whatever the calendar day.
+use alloc::string::String;
+use core::convert::TryInto;
+
+use casper_contract::{contract_api::{runtime, storage}, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{ApiError, Key, URef, U256};
+use casper_types::bytesrepr::ToBytes;
+use constants::{DAILY_LIMITS_REGISTRY_KEY_NAME, DAILY_LIMIT_TAG, DAILY_SPENT_TAG, DAY_IN_MILLISECONDS};
+use error::Error;
+
+/// get the daily limits uref.
+#[inline]
+pub(crate) fn get_daily_limits_uref() -> URef {
+    let key = runtime::get_key(DAILY_LIMITS_REGISTRY_KEY_NAME)
+        .ok_or(ApiError::MissingKey)
+        .unwrap_or_revert();
+    key.try_into().unwrap_or_revert()
+}
+
+/// Stores the amount of `token` that `holder` can send per day, zero removes the limit.
+pub(crate) fn write_limit(limits_uref: URef, holder: Key, token: Key, amount: U256) {
+    storage::dictionary_put(
+        limits_uref,
+        make_dictionary_item_key(holder, token, DAILY_LIMIT_TAG).as_str(),
+        amount
+    );
+}
+
+/// Returns the amount of `token` that `holder` can still send in its current window, which is
+/// `U256::MAX` when there is no limit.
+pub(crate) fn remaining_today(limits_uref: URef, holder: Key, token: Key) -> U256 {
+    let limit = read_limit(limits_uref, holder, token);
+    if limit.is_zero() {
+        return U256::MAX;
+    }
+
+    let (_window_start, spent) = read_window(limits_uref, holder, token, now());
+    limit.saturating_sub(spent)
+}
+
+/// Adds `amount` to what `holder` sent of `token` in its current window, unless it goes over the
+/// daily limit.
+pub(crate) fn spend(limits_uref: URef, holder: Key, token: Key, amount: U256) -> Result<(), Error> {
+    let limit = read_limit(limits_uref, holder, token);
+    if limit.is_zero() {
+        return Ok(());
+    }
+
+    let (window_start, spent) = read_window(limits_uref, holder, token, now());
+    let spent = spent.checked_add(amount).ok_or(Error::Overflow)?;
+    if spent > limit {
+        return Err(Error::DailyLimitExceeded);
+    }
+
+    storage::dictionary_put(
+        limits_uref,
+        make_dictionary_item_key(holder, token, DAILY_SPENT_TAG).as_str(),
+        (window_start, spent)
+    );
+    Ok(())
+}
+
+fn read_limit(limits_uref: URef, holder: Key, token: Key) -> U256 {
+    storage::dictionary_get(
+        limits_uref,
+        make_dictionary_item_key(holder, token, DAILY_LIMIT_TAG).as_str()
+    ).unwrap_or_revert().unwrap_or_default()
+}
+
+/// Returns the start of the window of `holder` and what it sent in it, a new window starting at
+/// `now` once a whole day has passed since the start of the previous one.
+fn read_window(limits_uref: URef, holder: Key, token: Key, now: u64) -> (u64, U256) {
+    let stored: Option<(u64, U256)> = storage::dictionary_get(
+        limits_uref,
+        make_dictionary_item_key(holder, token, DAILY_SPENT_TAG).as_str()
+    ).unwrap_or_revert();
+
+    match stored {
+        Some((window_start, spent)) if now.saturating_sub(window_start) < DAY_IN_MILLISECONDS => {
+            (window_start, spent)
+        }
+        _ => (now, U256::zero()),
+    }
+}
+
+#[inline]
+fn now() -> u64 {
+    u64::from(runtime::get_blocktime())
+}
+
+#[inline]
+fn make_dictionary_item_key(holder: Key, token: Key, tag: &str) -> String {
+    let mut preimage = holder.to_bytes().unwrap_or_revert();
+    preimage.append(&mut token.to_bytes().unwrap_or_revert());
+    preimage.extend_from_slice(tag.as_bytes());
+    hex::encode(runtime::blake2b(&preimage))
+}
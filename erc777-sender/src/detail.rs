@@ -0,0 +1,28 @@
+//! Implementation details.
+use casper_contract::contract_api::runtime;
+use casper_types::{system::CallStackElement, Key};
+
+use error::Error;
+
+/// Returns the key of a [`CallStackElement`], contracts are identified by their package hash.
+fn call_stack_element_to_key(call_stack_element: CallStackElement) -> Key {
+    match call_stack_element {
+        CallStackElement::Session { account_hash } => Key::from(account_hash),
+        CallStackElement::StoredSession { account_hash, .. } => Key::from(account_hash),
+        CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        } => Key::from(contract_package_hash),
+    }
+}
+
+/// Gets the immediate caller of the current execution: the token calling a hook, or the account
+/// or contract calling an entry point.
+pub(crate) fn get_immediate_caller_key() -> Result<Key, Error> {
+    runtime::get_call_stack()
+        .into_iter()
+        .rev()
+        .nth(1)
+        .map(call_stack_element_to_key)
+        .ok_or(Error::InvalidContext)
+}
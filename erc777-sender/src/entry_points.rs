@@ -1,14 +1,23 @@
 //! Contains definition of the entry points.
-use alloc::{string::String, vec};
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
 
 use casper_types::{{CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter}, U256, Key};
 use casper_types::bytesrepr::Bytes;
+use casper_erc1820::entry_points::can_implement_interface_for_address;
 use constants::{ACCOUNT_RUNTIME_ARG_NAME, BURN_ENTRY_POINT};
 
 use crate::constants::{
     TOKENS_TO_SEND_ENTRY_POINT, TRANSFER_ENTRY_POINT,
     OPERATOR_RUNTIME_ARG_NAME, FROM_RUNTIME_ARG_NAME, TO_RUNTIME_ARG_NAME,
-    AMOUNT_RUNTIME_ARG_NAME, USER_DATA_RUNTIME_ARG_NAME, OPERATOR_DATA_RUNTIME_ARG_NAME
+    AMOUNT_RUNTIME_ARG_NAME, USER_DATA_RUNTIME_ARG_NAME, OPERATOR_DATA_RUNTIME_ARG_NAME,
+    SET_RECIPIENT_ALLOWLIST_ENTRY_POINT, SET_RECIPIENT_BLOCKLIST_ENTRY_POINT, SET_MAX_AMOUNT_ENTRY_POINT,
+    SET_DATA_PREFIX_ENTRY_POINT, SET_OPERATOR_ALLOWLIST_ENTRY_POINT, RECIPIENTS_RUNTIME_ARG_NAME,
+    OPERATORS_RUNTIME_ARG_NAME, PREFIX_RUNTIME_ARG_NAME, SET_DAILY_LIMIT_ENTRY_POINT,
+    REMAINING_ALLOWANCE_TODAY_ENTRY_POINT, TOKEN_RUNTIME_ARG_NAME, MOVEMENT_COUNT_ENTRY_POINT,
+    GET_MOVEMENT_ENTRY_POINT, GET_MOVEMENTS_ENTRY_POINT, INDEX_RUNTIME_ARG_NAME, OFFSET_RUNTIME_ARG_NAME,
+    LIMIT_RUNTIME_ARG_NAME, ADD_TOKEN_ENTRY_POINT, REMOVE_TOKEN_ENTRY_POINT,
+    ADD_DELEGATE_ENTRY_POINT, REMOVE_DELEGATE_ENTRY_POINT, DELEGATE_RUNTIME_ARG_NAME,
+    REGISTER_FOR_ENTRY_POINT, UNREGISTER_FOR_ENTRY_POINT, GET_SERVED_ACCOUNTS_ENTRY_POINT
 };
 
 /// `tokens_to_send` Entry Point
@@ -34,6 +43,7 @@ pub fn transfer() -> EntryPoint {
     EntryPoint::new(
         String::from(TRANSFER_ENTRY_POINT),
         vec![
+            Parameter::new(TOKEN_RUNTIME_ARG_NAME, Key::cl_type()),
             Parameter::new(FROM_RUNTIME_ARG_NAME, Key::cl_type()),
             Parameter::new(TO_RUNTIME_ARG_NAME, Key::cl_type()),
             Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
@@ -51,6 +61,7 @@ pub fn burn() -> EntryPoint {
     EntryPoint::new(
         String::from(BURN_ENTRY_POINT),
         vec![
+            Parameter::new(TOKEN_RUNTIME_ARG_NAME, Key::cl_type()),
             Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type()),
             Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
             Parameter::new(USER_DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
@@ -62,11 +73,249 @@ pub fn burn() -> EntryPoint {
     )
 }
 
+/// Returns the `set_recipient_allowlist` entry point.
+pub fn set_recipient_allowlist() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_RECIPIENT_ALLOWLIST_ENTRY_POINT),
+        vec![
+            Parameter::new(RECIPIENTS_RUNTIME_ARG_NAME, Vec::<Key>::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_recipient_blocklist` entry point.
+pub fn set_recipient_blocklist() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_RECIPIENT_BLOCKLIST_ENTRY_POINT),
+        vec![
+            Parameter::new(RECIPIENTS_RUNTIME_ARG_NAME, Vec::<Key>::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_max_amount` entry point.
+pub fn set_max_amount() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_MAX_AMOUNT_ENTRY_POINT),
+        vec![
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_data_prefix` entry point.
+pub fn set_data_prefix() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_DATA_PREFIX_ENTRY_POINT),
+        vec![
+            Parameter::new(PREFIX_RUNTIME_ARG_NAME, Bytes::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_operator_allowlist` entry point.
+pub fn set_operator_allowlist() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_OPERATOR_ALLOWLIST_ENTRY_POINT),
+        vec![
+            Parameter::new(OPERATORS_RUNTIME_ARG_NAME, Vec::<Key>::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_daily_limit` entry point.
+pub fn set_daily_limit() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_DAILY_LIMIT_ENTRY_POINT),
+        vec![
+            Parameter::new(TOKEN_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `remaining_allowance_today` entry point.
+pub fn remaining_allowance_today() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REMAINING_ALLOWANCE_TODAY_ENTRY_POINT),
+        vec![
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(TOKEN_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `register_for` entry point.
+pub fn register_for() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REGISTER_FOR_ENTRY_POINT),
+        vec![
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `unregister_for` entry point.
+pub fn unregister_for() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNREGISTER_FOR_ENTRY_POINT),
+        vec![
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_served_accounts` entry point.
+pub fn get_served_accounts() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_SERVED_ACCOUNTS_ENTRY_POINT),
+        vec![],
+        Vec::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `add_token` entry point.
+pub fn add_token() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ADD_TOKEN_ENTRY_POINT),
+        vec![
+            Parameter::new(TOKEN_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `remove_token` entry point.
+pub fn remove_token() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REMOVE_TOKEN_ENTRY_POINT),
+        vec![
+            Parameter::new(TOKEN_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `add_delegate` entry point.
+pub fn add_delegate() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ADD_DELEGATE_ENTRY_POINT),
+        vec![
+            Parameter::new(DELEGATE_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `remove_delegate` entry point.
+pub fn remove_delegate() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REMOVE_DELEGATE_ENTRY_POINT),
+        vec![
+            Parameter::new(DELEGATE_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `movement_count` entry point.
+pub fn movement_count() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MOVEMENT_COUNT_ENTRY_POINT),
+        vec![],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_movement` entry point.
+pub fn get_movement() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_MOVEMENT_ENTRY_POINT),
+        vec![
+            Parameter::new(INDEX_RUNTIME_ARG_NAME, u64::cl_type())
+        ],
+        BTreeMap::<String, String>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_movements` entry point.
+pub fn get_movements() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_MOVEMENTS_ENTRY_POINT),
+        vec![
+            Parameter::new(OFFSET_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(LIMIT_RUNTIME_ARG_NAME, u64::cl_type())
+        ],
+        Vec::<BTreeMap<String, String>>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the default set of ERC777 Sender token entry points.
 pub fn default() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
     entry_points.add_entry_point(tokens_to_send());
     entry_points.add_entry_point(transfer());
     entry_points.add_entry_point(burn());
+    entry_points.add_entry_point(can_implement_interface_for_address());
+    entry_points.add_entry_point(set_recipient_allowlist());
+    entry_points.add_entry_point(set_recipient_blocklist());
+    entry_points.add_entry_point(set_max_amount());
+    entry_points.add_entry_point(set_data_prefix());
+    entry_points.add_entry_point(set_operator_allowlist());
+    entry_points.add_entry_point(set_daily_limit());
+    entry_points.add_entry_point(remaining_allowance_today());
+    entry_points.add_entry_point(register_for());
+    entry_points.add_entry_point(unregister_for());
+    entry_points.add_entry_point(get_served_accounts());
+    entry_points.add_entry_point(add_token());
+    entry_points.add_entry_point(remove_token());
+    entry_points.add_entry_point(add_delegate());
+    entry_points.add_entry_point(remove_delegate());
+    entry_points.add_entry_point(movement_count());
+    entry_points.add_entry_point(get_movement());
+    entry_points.add_entry_point(get_movements());
     entry_points
 }
@@ -1,11 +1,29 @@
-use casper_contract::{contract_api::runtime};
-use casper_types::{ContractHash, Key, runtime_args, RuntimeArgs};
+//! Implementation of the calls to the erc1820 registry.
+use core::convert::TryInto;
+
+use casper_contract::{contract_api::{runtime, storage}, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{ApiError, ContractHash, Key, URef, runtime_args, RuntimeArgs};
 use casper_types::bytesrepr::Bytes;
 use constants::{
     SET_INTERFACE_OF_EXTERNAL_ENTRY_POINT, ACCOUNT_RUNTIME_ARG_NAME,
-    I_HASH_RUNTIME_ARG_NAME, IMPLEMENTER_RUNTIME_ARG_NAME,
+    I_HASH_RUNTIME_ARG_NAME, IMPLEMENTER_RUNTIME_ARG_NAME, ERC1820_REGISTRY_KEY_NAME,
+    GET_MANAGER_OF_EXTERNAL_ENTRY_POINT
 };
 
+/// get the erc1820 uref.
+#[inline]
+pub(crate) fn get_erc1820_uref() -> URef {
+    let key = runtime::get_key(ERC1820_REGISTRY_KEY_NAME)
+        .ok_or(ApiError::MissingKey)
+        .unwrap_or_revert();
+    key.try_into().unwrap_or_revert()
+}
+
+/// reads the erc1820 registry the contract was installed with.
+pub(crate) fn read_erc1820(erc1820_uref: URef) -> ContractHash {
+    storage::read(erc1820_uref).unwrap_or_revert().unwrap_or_revert()
+}
+
 pub(crate) fn set_implementer(
     account: Key,
     i_hash: Bytes,
@@ -24,4 +42,16 @@ pub(crate) fn set_implementer(
         registry_args,
     );
 
-}
\ No newline at end of file
+}
+
+/// Returns the manager of `account`.
+pub(crate) fn get_manager(account: Key, contract_hash: ContractHash) -> Key {
+    let registry_args = runtime_args! {
+        ACCOUNT_RUNTIME_ARG_NAME => account
+    };
+    runtime::call_contract::<Key>(
+        contract_hash,
+        GET_MANAGER_OF_EXTERNAL_ENTRY_POINT,
+        registry_args,
+    )
+}
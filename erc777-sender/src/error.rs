@@ -11,6 +11,9 @@ use casper_types::ApiError;
 ///
 /// Such a user error should be in the range `[0..(u16::MAX - 4)]` (i.e. [0, 65532]) to avoid
 /// conflicting with the other `Error` variants.
+///
+/// The errors of the send policies, of the daily limits, of the registered tokens and of the served
+/// accounts use the range `[1300..1399]`.
 pub enum Error {
     /// ERC20 contract called from within an invalid context.
     InvalidContext,
@@ -25,7 +28,25 @@ pub enum Error {
     ///
     InvalidAddress,
     ///
-    InvalidOperator
+    InvalidOperator,
+    /// The caller is not allowed to configure the contract.
+    Unauthorized,
+    /// The recipient is not in the recipient allowlist of the holder.
+    RecipientNotAllowed,
+    /// The recipient is in the recipient blocklist of the holder.
+    RecipientBlocked,
+    /// The amount is greater than the maximum amount of a single movement of the holder.
+    AmountTooLarge,
+    /// The `user_data` does not start with the prefix required by the holder.
+    MissingDataPrefix,
+    /// The operator is not in the operator allowlist of the holder.
+    OperatorNotAllowed,
+    /// The amount goes over what the holder can still send today.
+    DailyLimitExceeded,
+    /// The token is not registered in the contract.
+    UnknownToken,
+    /// The contract is not registered for the account.
+    AccountNotServed
 }
 
 const ERROR_INVALID_CONTEXT: u16 = u16::MAX;
@@ -34,6 +55,15 @@ const ERROR_INSUFFICIENT_ALLOWANCE: u16 = u16::MAX - 2;
 const ERROR_OVERFLOW: u16 = u16::MAX - 3;
 const ERROR_ADDRESS_UNKNOWN: u16 = u16::MAX - 4;
 const ERROR_OPERATOR_NOT_FOUND: u16 = u16::MAX - 5;
+const ERROR_UNAUTHORIZED: u16 = 1300;
+const ERROR_RECIPIENT_NOT_ALLOWED: u16 = 1301;
+const ERROR_RECIPIENT_BLOCKED: u16 = 1302;
+const ERROR_AMOUNT_TOO_LARGE: u16 = 1303;
+const ERROR_MISSING_DATA_PREFIX: u16 = 1304;
+const ERROR_OPERATOR_NOT_ALLOWED: u16 = 1305;
+const ERROR_DAILY_LIMIT_EXCEEDED: u16 = 1306;
+const ERROR_UNKNOWN_TOKEN: u16 = 1307;
+const ERROR_ACCOUNT_NOT_SERVED: u16 = 1308;
 
 impl From<Error> for ApiError {
     fn from(error: Error) -> Self {
@@ -44,7 +74,16 @@ impl From<Error> for ApiError {
             Error::Overflow => ERROR_OVERFLOW,
             Error::User(user_error) => user_error,
             Error::InvalidAddress => ERROR_ADDRESS_UNKNOWN,
-            Error::InvalidOperator => ERROR_OPERATOR_NOT_FOUND
+            Error::InvalidOperator => ERROR_OPERATOR_NOT_FOUND,
+            Error::Unauthorized => ERROR_UNAUTHORIZED,
+            Error::RecipientNotAllowed => ERROR_RECIPIENT_NOT_ALLOWED,
+            Error::RecipientBlocked => ERROR_RECIPIENT_BLOCKED,
+            Error::AmountTooLarge => ERROR_AMOUNT_TOO_LARGE,
+            Error::MissingDataPrefix => ERROR_MISSING_DATA_PREFIX,
+            Error::OperatorNotAllowed => ERROR_OPERATOR_NOT_ALLOWED,
+            Error::DailyLimitExceeded => ERROR_DAILY_LIMIT_EXCEEDED,
+            Error::UnknownToken => ERROR_UNKNOWN_TOKEN,
+            Error::AccountNotServed => ERROR_ACCOUNT_NOT_SERVED
         };
         ApiError::User(user_error)
     }
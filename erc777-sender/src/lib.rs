@@ -10,6 +10,7 @@ extern crate alloc;
 extern crate casper_types;
 extern crate casper_contract;
 extern crate once_cell;
+extern crate casper_erc1820;
 
 pub mod constants;
 pub mod entry_points;
@@ -17,8 +18,16 @@ mod error;
 mod erc1820_registry;
 mod erc777_registry;
 mod register_movements;
+mod owner;
+mod policies;
+mod daily_limits;
+mod detail;
+mod delegates;
+mod served_accounts;
 
-use alloc::string::{ToString};
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 use once_cell::unsync::OnceCell;
 
@@ -27,26 +36,124 @@ use casper_contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{{contracts::NamedKeys, EntryPoints, Key, URef}, U256, ContractHash};
-use casper_types::bytesrepr::{Bytes, ToBytes};
+use casper_types::bytesrepr::Bytes;
+use casper_erc1820::{accept_magic, interface_hash};
 
-use constants::{ERC777_REGISTRY_KEY_NAME, ERC777_SENDER_CONTRACT_NAME, HASH_ERC1820_SENDER, MOVEMENTS_REGISTRY_KEY_NAME};
+use constants::{
+    ERC777_REGISTRY_KEY_NAME, ERC777_SENDER_CONTRACT_NAME, HASH_ERC1820_SENDER, MOVEMENTS_REGISTRY_KEY_NAME,
+    OWNER_KEY_NAME, POLICIES_REGISTRY_KEY_NAME, RECIPIENT_ALLOWLIST_POLICY, RECIPIENT_BLOCKLIST_POLICY,
+    OPERATOR_ALLOWLIST_POLICY, DAILY_LIMITS_REGISTRY_KEY_NAME, MOVEMENT_COUNT_KEY_NAME, DELEGATES_REGISTRY_KEY_NAME,
+    ERC1820_REGISTRY_KEY_NAME, SERVED_ACCOUNTS_KEY_NAME
+};
 pub use error::Error;
 
 /// Struct
 #[derive(Default)]
 pub struct ERC777Sender {
     registry_uref: OnceCell<URef>,
-    erc777_uref: OnceCell<URef>
+    erc777_uref: OnceCell<URef>,
+    owner_uref: OnceCell<URef>,
+    policies_uref: OnceCell<URef>,
+    daily_limits_uref: OnceCell<URef>,
+    movement_count_uref: OnceCell<URef>,
+    delegates_uref: OnceCell<URef>,
+    erc1820_uref: OnceCell<URef>,
+    served_accounts_uref: OnceCell<URef>
 }
 
 impl ERC777Sender {
-    fn new(registry_uref: URef, erc777_uref: URef) -> Self {
+    fn new(
+        registry_uref: URef,
+        erc777_uref: URef,
+        owner_uref: URef,
+        policies_uref: URef,
+        daily_limits_uref: URef,
+        movement_count_uref: URef,
+        delegates_uref: URef,
+        erc1820_uref: URef,
+        served_accounts_uref: URef
+    ) -> Self {
         Self {
             registry_uref: registry_uref.into(),
-            erc777_uref: erc777_uref.into()
+            erc777_uref: erc777_uref.into(),
+            owner_uref: owner_uref.into(),
+            policies_uref: policies_uref.into(),
+            daily_limits_uref: daily_limits_uref.into(),
+            movement_count_uref: movement_count_uref.into(),
+            delegates_uref: delegates_uref.into(),
+            erc1820_uref: erc1820_uref.into(),
+            served_accounts_uref: served_accounts_uref.into()
         }
     }
 
+    /// it loads uref of the owner namekey
+    fn owner_uref(&self) -> URef {
+        *self.owner_uref.get_or_init(owner::get_owner_uref)
+    }
+
+    /// it loads uref of the send policies namekey
+    fn policies_uref(&self) -> URef {
+        *self.policies_uref.get_or_init(policies::get_policies_uref)
+    }
+
+    /// it loads uref of the daily limits namekey
+    fn daily_limits_uref(&self) -> URef {
+        *self.daily_limits_uref.get_or_init(daily_limits::get_daily_limits_uref)
+    }
+
+    /// it loads uref of the delegates namekey
+    fn delegates_uref(&self) -> URef {
+        *self.delegates_uref.get_or_init(delegates::get_delegates_uref)
+    }
+
+    /// it loads uref of the erc1820 registry namekey
+    fn erc1820_uref(&self) -> URef {
+        *self.erc1820_uref.get_or_init(erc1820_registry::get_erc1820_uref)
+    }
+
+    /// it loads uref of the served accounts namekey
+    fn served_accounts_uref(&self) -> URef {
+        *self.served_accounts_uref.get_or_init(served_accounts::get_served_accounts_uref)
+    }
+
+    /// Returns an error unless the caller is the owner.
+    fn only_owner(&self) -> Result<(), Error> {
+        let owner = owner::read_owner(self.owner_uref());
+        if detail::get_immediate_caller_key()?.ne(&owner) {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Returns the caller when it is a served account, the send policies are those of the caller.
+    fn only_served(&self) -> Result<Key, Error> {
+        let caller = detail::get_immediate_caller_key()?;
+        if !served_accounts::is_served(self.served_accounts_uref(), caller) {
+            return Err(Error::AccountNotServed);
+        }
+        Ok(caller)
+    }
+
+    /// Returns an error unless the caller is the erc1820 manager of `account`.
+    fn only_manager_of(&self, account: Key) -> Result<(), Error> {
+        let erc1820_hash = erc1820_registry::read_erc1820(self.erc1820_uref());
+        let manager = erc1820_registry::get_manager(account, erc1820_hash);
+        if detail::get_immediate_caller_key()?.ne(&manager) {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Returns an error unless the caller is the owner or one of its delegates.
+    fn only_owner_or_delegate(&self) -> Result<(), Error> {
+        let caller = detail::get_immediate_caller_key()?;
+        let owner = owner::read_owner(self.owner_uref());
+        if caller.ne(&owner) && !delegates::is_delegate(self.delegates_uref(), caller) {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
     /// it loads uref of the erc777 namekey
     fn erc777_uref(&self) -> URef {
         *self.erc777_uref.get_or_init(erc777_registry::get_erc777_uref)
@@ -57,14 +164,31 @@ impl ERC777Sender {
         *self.registry_uref.get_or_init(register_movements::get_registry_uref)
     }
 
+    /// it loads uref of the movement count namekey
+    fn movement_count_uref(&self) -> URef {
+        *self.movement_count_uref.get_or_init(register_movements::get_movement_count_uref)
+    }
+
+    /// Answers the ERC1820 registry whether this contract agrees to be the `ERC777TokensSender`
+    /// implementer of `account`, which is only the case for the accounts it serves.
+    pub fn can_implement_interface_for_address(self, i_hash: Bytes, account: Key) -> Bytes {
+        if i_hash.eq(&interface_hash(HASH_ERC1820_SENDER))
+            && served_accounts::is_served(self.served_accounts_uref(), account) {
+            accept_magic()
+        } else {
+            Bytes::default()
+        }
+    }
+
     /// Installs the ERC20 contract with the default set of entry points.
     ///
     /// This should be called from within `fn call()` of your contract.
-    pub fn install(erc1820_hash: ContractHash, erc777_hash: ContractHash) -> Result<ERC777Sender, Error> {
+    ///
+    /// Tokens are registered afterwards with [`ERC777Sender::add_token`].
+    pub fn install(erc1820_hash: ContractHash) -> Result<ERC777Sender, Error> {
         let default_entry_points = entry_points::default();
         ERC777Sender::install_custom(
             erc1820_hash,
-            erc777_hash,
             ERC777_SENDER_CONTRACT_NAME,
             default_entry_points,
         )
@@ -82,47 +206,192 @@ impl ERC777Sender {
         user_data: Bytes,
         operator_data: Bytes
     ) -> Result<(), Error> {
+        let token = detail::get_immediate_caller_key()?;
+        if !erc777_registry::is_registered(self.erc777_uref(), token) {
+            return Err(Error::UnknownToken);
+        }
+
+        policies::check(self.policies_uref(), operator, from, to, amount, &user_data)?;
+        daily_limits::spend(self.daily_limits_uref(), from, token, amount)?;
+
+        register_movements::record(
+            self.registry_uref(),
+            self.movement_count_uref(),
+            token,
+            operator,
+            from,
+            to,
+            amount,
+            user_data,
+            operator_data
+        );
+        Ok(())
+    }
+
+    /// Returns the number of movements recorded by the hook.
+    pub fn movement_count(self) -> u64 {
+        register_movements::movement_count(self.movement_count_uref())
+    }
 
-        register_movements::tokens_received(self.registry_uref(), operator, from, to, amount, user_data, operator_data);
+    /// Returns the movement recorded under the sequence number `index`.
+    pub fn get_movement(self, index: u64) -> BTreeMap<String, String> {
+        register_movements::get_movement(self.registry_uref(), index)
+    }
+
+    /// Returns at most `limit` movements, starting with the sequence number `offset`.
+    pub fn get_movements(self, offset: u64, limit: u64) -> Vec<BTreeMap<String, String>> {
+        register_movements::get_movements(self.registry_uref(), self.movement_count_uref(), offset, limit)
+    }
+
+    /// Adds `account` to the served accounts, so the contract agrees to be its `ERC777TokensSender`
+    /// implementer.
+    ///
+    /// The caller must be the erc1820 manager of `account`, which is `account` itself by default.
+    /// The manager then sets the contract as the implementer in the erc1820 registry itself, as the
+    /// registry only accepts changes from the manager.
+    pub fn register_for(self, account: Key) -> Result<(), Error> {
+        self.only_manager_of(account)?;
+        served_accounts::add_account(self.served_accounts_uref(), account);
         Ok(())
     }
 
+    /// Removes `account` from the served accounts.
+    ///
+    /// The caller must be the erc1820 manager of `account`, which is `account` itself by default.
+    /// The manager clears the implementer in the erc1820 registry itself, tokens keep calling the
+    /// hook for `account` until then.
+    pub fn unregister_for(self, account: Key) -> Result<(), Error> {
+        self.only_manager_of(account)?;
+        served_accounts::remove_account(self.served_accounts_uref(), account)?;
+        Ok(())
+    }
+
+    /// Returns the accounts the contract is registered for.
+    pub fn get_served_accounts(self) -> Vec<Key> {
+        served_accounts::read_served_accounts(self.served_accounts_uref())
+    }
+
+    /// Registers `token`, the key of an erc777 contract package, so that its movements go through
+    /// the hook and the contract can move it.
+    pub fn add_token(self, token: Key) -> Result<(), Error> {
+        self.only_owner()?;
+        erc777_registry::add_token(self.erc777_uref(), token)
+    }
+
+    /// Unregisters `token`, its movements are rejected by the hook from then on.
+    pub fn remove_token(self, token: Key) -> Result<(), Error> {
+        self.only_owner()?;
+        erc777_registry::remove_token(self.erc777_uref(), token);
+        Ok(())
+    }
+
+    /// Allows `delegate` to move funds through **transfer** and **burn**, along with the owner.
+    pub fn add_delegate(self, delegate: Key) -> Result<(), Error> {
+        self.only_owner()?;
+        delegates::write_delegate(self.delegates_uref(), delegate, true);
+        Ok(())
+    }
+
+    /// Disallows `delegate` to move funds.
+    pub fn remove_delegate(self, delegate: Key) -> Result<(), Error> {
+        self.only_owner()?;
+        delegates::write_delegate(self.delegates_uref(), delegate, false);
+        Ok(())
+    }
+
+    /// Restricts the recipients of the caller's tokens to `recipients`, an empty list allows any.
+    pub fn set_recipient_allowlist(self, recipients: Vec<Key>) -> Result<(), Error> {
+        let holder = self.only_served()?;
+        policies::write_keys(self.policies_uref(), holder, RECIPIENT_ALLOWLIST_POLICY, recipients);
+        Ok(())
+    }
+
+    /// Forbids sending the caller's tokens to `recipients`.
+    pub fn set_recipient_blocklist(self, recipients: Vec<Key>) -> Result<(), Error> {
+        let holder = self.only_served()?;
+        policies::write_keys(self.policies_uref(), holder, RECIPIENT_BLOCKLIST_POLICY, recipients);
+        Ok(())
+    }
+
+    /// Limits every movement of the caller's tokens to `amount`, zero removes the limit.
+    pub fn set_max_amount(self, amount: U256) -> Result<(), Error> {
+        let holder = self.only_served()?;
+        policies::write_max_amount(self.policies_uref(), holder, amount);
+        Ok(())
+    }
+
+    /// Requires the `user_data` of every movement of the caller's tokens to start with `prefix`,
+    /// an empty prefix removes the requirement.
+    pub fn set_data_prefix(self, prefix: Bytes) -> Result<(), Error> {
+        let holder = self.only_served()?;
+        policies::write_data_prefix(self.policies_uref(), holder, prefix);
+        Ok(())
+    }
+
+    /// Restricts the operators which can move the caller's tokens to `operators`, an empty list
+    /// allows any.
+    pub fn set_operator_allowlist(self, operators: Vec<Key>) -> Result<(), Error> {
+        let holder = self.only_served()?;
+        policies::write_keys(self.policies_uref(), holder, OPERATOR_ALLOWLIST_POLICY, operators);
+        Ok(())
+    }
+
+    /// Limits the amount of `token` the caller can send per 24 hours to `amount`, zero removes the
+    /// limit.
+    ///
+    /// `token` is the key of the token contract package. The 24 hours start with the first
+    /// movement counted against the limit and follow the block time.
+    pub fn set_daily_limit(self, token: Key, amount: U256) -> Result<(), Error> {
+        let holder = self.only_served()?;
+        daily_limits::write_limit(self.daily_limits_uref(), holder, token, amount);
+        Ok(())
+    }
+
+    /// Returns the amount of `token` that `account` can still send in its current 24 hours.
+    pub fn remaining_allowance_today(self, account: Key, token: Key) -> U256 {
+        daily_limits::remaining_today(self.daily_limits_uref(), account, token)
+    }
+
     /// it transfers tokens from erc777's operator_send
     pub fn transfer(
         self,
+        token: Key,
         from: Key,
         to: Key,
         amount: U256,
         user_data: Bytes,
         operator_data: Bytes
     ) -> Result<(), Error>{
+        self.only_owner_or_delegate()?;
         erc777_registry::transfer(
             self.erc777_uref(),
+            token,
             from,
             to,
             amount,
             user_data,
             operator_data
-        );
-        Ok(())
+        )
     }
 
     /// Allows burning a ´amount´ tokens on behalf of the tokens' owner.
     pub fn burn(
         self,
+        token: Key,
         account: Key,
         amount: U256,
         user_data: Bytes,
         operator_data: Bytes
     ) -> Result<(), Error>{
+        self.only_owner_or_delegate()?;
         erc777_registry::burn(
             self.erc777_uref(),
+            token,
             account,
             amount,
             user_data,
             operator_data
-        );
-        Ok(())
+        )
     }
 
     /// Installs the ERC777 Sender contract with a custom set of entry points.
@@ -135,12 +404,19 @@ impl ERC777Sender {
     #[doc(hidden)]
     pub fn install_custom(
         erc1820_hash: ContractHash,
-        erc777_hash: ContractHash,
         contract_key_name: &str,
         entry_points: EntryPoints,
     ) -> Result<ERC777Sender, Error> {
         let registry_uref = storage::new_dictionary(MOVEMENTS_REGISTRY_KEY_NAME).unwrap_or_revert();
         let erc777_uref = storage::new_dictionary(ERC777_REGISTRY_KEY_NAME).unwrap_or_revert();
+        let policies_uref = storage::new_dictionary(POLICIES_REGISTRY_KEY_NAME).unwrap_or_revert();
+        let daily_limits_uref = storage::new_dictionary(DAILY_LIMITS_REGISTRY_KEY_NAME).unwrap_or_revert();
+        let delegates_uref = storage::new_dictionary(DELEGATES_REGISTRY_KEY_NAME).unwrap_or_revert();
+
+        let owner_uref = storage::new_uref(Key::from(runtime::get_caller())).into_read();
+        let movement_count_uref = storage::new_uref(0u64).into_read_write();
+        let erc1820_uref = storage::new_uref(erc1820_hash).into_read();
+        let served_accounts_uref = storage::new_dictionary(SERVED_ACCOUNTS_KEY_NAME).unwrap_or_revert();
 
         let mut named_keys = NamedKeys::new();
 
@@ -150,13 +426,41 @@ impl ERC777Sender {
         };
 
         let erc777_key = {
-            erc777_registry::save_erc777_contract(erc777_uref, erc777_hash);
             runtime::remove_key(ERC777_REGISTRY_KEY_NAME);
             Key::from(erc777_uref)
         };
 
+        let policies_key = {
+            runtime::remove_key(POLICIES_REGISTRY_KEY_NAME);
+            Key::from(policies_uref)
+        };
+
+        let daily_limits_key = {
+            runtime::remove_key(DAILY_LIMITS_REGISTRY_KEY_NAME);
+            Key::from(daily_limits_uref)
+        };
+
+        let delegates_key = {
+            runtime::remove_key(DELEGATES_REGISTRY_KEY_NAME);
+            Key::from(delegates_uref)
+        };
+
+        let served_accounts_key = {
+            // The account which installs the contract is served from the start.
+            served_accounts::add_account(served_accounts_uref, Key::from(runtime::get_caller()));
+            runtime::remove_key(SERVED_ACCOUNTS_KEY_NAME);
+            Key::from(served_accounts_uref)
+        };
+
         named_keys.insert(MOVEMENTS_REGISTRY_KEY_NAME.to_string(), movement_key);
         named_keys.insert(ERC777_REGISTRY_KEY_NAME.to_string(), erc777_key);
+        named_keys.insert(OWNER_KEY_NAME.to_string(), Key::from(owner_uref));
+        named_keys.insert(POLICIES_REGISTRY_KEY_NAME.to_string(), policies_key);
+        named_keys.insert(DAILY_LIMITS_REGISTRY_KEY_NAME.to_string(), daily_limits_key);
+        named_keys.insert(MOVEMENT_COUNT_KEY_NAME.to_string(), Key::from(movement_count_uref));
+        named_keys.insert(DELEGATES_REGISTRY_KEY_NAME.to_string(), delegates_key);
+        named_keys.insert(ERC1820_REGISTRY_KEY_NAME.to_string(), Key::from(erc1820_uref));
+        named_keys.insert(SERVED_ACCOUNTS_KEY_NAME.to_string(), served_accounts_key);
 
         let (contract_hash, _version) =
             storage::new_contract(entry_points, Some(named_keys), None, None);
@@ -168,14 +472,21 @@ impl ERC777Sender {
         let account_hash = runtime::get_caller();
         erc1820_registry::set_implementer(
             Key::from(account_hash),
-            Bytes::from(HASH_ERC1820_SENDER.to_bytes().unwrap()),
+            interface_hash(HASH_ERC1820_SENDER),
             contract_key,
             erc1820_hash
         );
 
         Ok(ERC777Sender::new(
             registry_uref,
-            erc777_uref
+            erc777_uref,
+            owner_uref,
+            policies_uref,
+            daily_limits_uref,
+            movement_count_uref,
+            delegates_uref,
+            erc1820_uref,
+            served_accounts_uref
         ))
     }
 }
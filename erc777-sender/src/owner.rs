@@ -0,0 +1,20 @@
+//! Implementation of the owner, the account the contract acts for.
+use core::convert::TryInto;
+
+use casper_contract::{contract_api::{runtime, storage}, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{ApiError, Key, URef};
+use constants::OWNER_KEY_NAME;
+
+/// get the owner uref.
+#[inline]
+pub(crate) fn get_owner_uref() -> URef {
+    let key = runtime::get_key(OWNER_KEY_NAME)
+        .ok_or(ApiError::MissingKey)
+        .unwrap_or_revert();
+    key.try_into().unwrap_or_revert()
+}
+
+/// reads the account which installed the contract.
+pub(crate) fn read_owner(owner_uref: URef) -> Key {
+    storage::read(owner_uref).unwrap_or_revert().unwrap_or_revert()
+}
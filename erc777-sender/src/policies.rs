@@ -0,0 +1,104 @@
+//! Implementation of the send policies, the rules a holder sets on its outgoing movements.
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryInto;
+
+use casper_contract::{contract_api::{runtime, storage}, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{ApiError, CLTyped, Key, URef, U256};
+use casper_types::bytesrepr::{Bytes, FromBytes, ToBytes};
+use constants::{
+    POLICIES_REGISTRY_KEY_NAME, RECIPIENT_ALLOWLIST_POLICY, RECIPIENT_BLOCKLIST_POLICY,
+    MAX_AMOUNT_POLICY, DATA_PREFIX_POLICY, OPERATOR_ALLOWLIST_POLICY
+};
+use error::Error;
+
+/// get the policies uref.
+#[inline]
+pub(crate) fn get_policies_uref() -> URef {
+    let key = runtime::get_key(POLICIES_REGISTRY_KEY_NAME)
+        .ok_or(ApiError::MissingKey)
+        .unwrap_or_revert();
+    key.try_into().unwrap_or_revert()
+}
+
+/// Stores the list of keys of a `policy` of `holder`, an empty list disables the policy.
+pub(crate) fn write_keys(policies_uref: URef, holder: Key, policy: &str, keys: Vec<Key>) {
+    storage::dictionary_put(policies_uref, make_dictionary_item_key(holder, policy).as_str(), keys);
+}
+
+/// Stores the maximum amount of a single movement of `holder`, zero disables the policy.
+pub(crate) fn write_max_amount(policies_uref: URef, holder: Key, amount: U256) {
+    storage::dictionary_put(
+        policies_uref,
+        make_dictionary_item_key(holder, MAX_AMOUNT_POLICY).as_str(),
+        amount
+    );
+}
+
+/// Stores the prefix the `user_data` of `holder` movements must start with, an empty prefix
+/// disables the policy.
+pub(crate) fn write_data_prefix(policies_uref: URef, holder: Key, prefix: Bytes) {
+    storage::dictionary_put(
+        policies_uref,
+        make_dictionary_item_key(holder, DATA_PREFIX_POLICY).as_str(),
+        prefix
+    );
+}
+
+/// Checks a movement of the tokens of `from` against every policy `from` has set.
+pub(crate) fn check(
+    policies_uref: URef,
+    operator: Key,
+    from: Key,
+    to: Key,
+    amount: U256,
+    user_data: &Bytes
+) -> Result<(), Error> {
+    let allowed_recipients = read_keys(policies_uref, from, RECIPIENT_ALLOWLIST_POLICY);
+    if !allowed_recipients.is_empty() && !allowed_recipients.contains(&to) {
+        return Err(Error::RecipientNotAllowed);
+    }
+
+    if read_keys(policies_uref, from, RECIPIENT_BLOCKLIST_POLICY).contains(&to) {
+        return Err(Error::RecipientBlocked);
+    }
+
+    let max_amount: U256 = read(policies_uref, from, MAX_AMOUNT_POLICY);
+    if !max_amount.is_zero() && amount > max_amount {
+        return Err(Error::AmountTooLarge);
+    }
+
+    let prefix: Bytes = read(policies_uref, from, DATA_PREFIX_POLICY);
+    if !user_data.starts_with(&prefix) {
+        return Err(Error::MissingDataPrefix);
+    }
+
+    // The holder moving its own tokens is not an operator.
+    let allowed_operators = read_keys(policies_uref, from, OPERATOR_ALLOWLIST_POLICY);
+    if operator.ne(&from) && !allowed_operators.is_empty() && !allowed_operators.contains(&operator) {
+        return Err(Error::OperatorNotAllowed);
+    }
+
+    Ok(())
+}
+
+fn read_keys(policies_uref: URef, holder: Key, policy: &str) -> Vec<Key> {
+    read(policies_uref, holder, policy)
+}
+
+fn read<T: CLTyped + FromBytes + Default>(
+    policies_uref: URef,
+    holder: Key,
+    policy: &str
+) -> T {
+    storage::dictionary_get(policies_uref, make_dictionary_item_key(holder, policy).as_str())
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+#[inline]
+fn make_dictionary_item_key(holder: Key, policy: &str) -> String {
+    let mut preimage = holder.to_bytes().unwrap_or_revert();
+    preimage.extend_from_slice(policy.as_bytes());
+    hex::encode(runtime::blake2b(&preimage))
+}
@@ -1,11 +1,13 @@
+//! Implementation of the movement ledger, every call of the hook appended under the next index.
 use alloc::collections::BTreeMap;
-use alloc::string::String;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use core::convert::TryInto;
 use casper_contract::contract_api::{runtime, storage};
 use casper_contract::unwrap_or_revert::UnwrapOrRevert;
 use casper_types::{ApiError, Key, U256, URef};
-use casper_types::bytesrepr::{Bytes, ToBytes};
-use ::{MOVEMENTS_REGISTRY_KEY_NAME};
+use casper_types::bytesrepr::Bytes;
+use constants::{MOVEMENTS_REGISTRY_KEY_NAME, MOVEMENT_COUNT_KEY_NAME};
 
 
 #[inline]
@@ -16,31 +18,66 @@ pub(crate) fn get_registry_uref() -> URef {
     key.try_into().unwrap_or_revert()
 }
 
-pub(crate) fn tokens_received(
+#[inline]
+pub(crate) fn get_movement_count_uref() -> URef {
+    let key = runtime::get_key(MOVEMENT_COUNT_KEY_NAME)
+        .ok_or(ApiError::MissingKey)
+        .unwrap_or_revert();
+    key.try_into().unwrap_or_revert()
+}
+
+/// Appends a movement of `token` to the ledger under the next sequence number.
+pub(crate) fn record(
     registry_uref: URef,
+    movement_count_uref: URef,
+    token: Key,
     operator: Key,
     from: Key,
     to: Key,
-    _amount: U256,
+    amount: U256,
     data: Bytes,
     operator_data: Bytes
 ) {
+    let sequence = movement_count(movement_count_uref);
 
-    if operator.eq(&from) {
-        //if this is the same owner, it doesn't make sense to save data
-        return;
-    }
+    let mut movement = BTreeMap::new();
+    movement.insert("sequence".to_string(), sequence.to_string());
+    movement.insert("token".to_string(), token.to_formatted_string());
+    movement.insert("operator".to_string(), operator.to_formatted_string());
+    movement.insert("from".to_string(), from.to_formatted_string());
+    movement.insert("to".to_string(), to.to_formatted_string());
+    movement.insert("amount".to_string(), amount.to_string());
+    movement.insert("data".to_string(), hex::encode(data.to_vec()));
+    movement.insert("operator_data".to_string(), hex::encode(operator_data.to_vec()));
+    movement.insert("block_time".to_string(), u64::from(runtime::get_blocktime()).to_string());
 
-    let operator_key= make_dictionary_item_key(operator);
-    let mut map = BTreeMap::new();
-    map.insert(to, data);
-    map.insert(operator, operator_data);
+    storage::dictionary_put(registry_uref, &sequence.to_string(), movement);
+    storage::write(movement_count_uref, sequence + 1);
+}
 
-    storage::dictionary_put(registry_uref, operator_key.as_str(), map);
+/// Returns the number of movements in the ledger.
+pub(crate) fn movement_count(movement_count_uref: URef) -> u64 {
+    storage::read(movement_count_uref)
+        .unwrap_or_revert()
+        .unwrap_or_default()
 }
 
-#[inline]
-fn make_dictionary_item_key(address: Key) -> String {
-    let preimage = address.to_bytes().unwrap_or_revert();
-    base64::encode(&preimage)
-}
\ No newline at end of file
+/// Returns the movement with the sequence number `index`, or an empty map when there is none.
+pub(crate) fn get_movement(registry_uref: URef, index: u64) -> BTreeMap<String, String> {
+    storage::dictionary_get(registry_uref, &index.to_string())
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Returns at most `limit` movements, starting with the sequence number `offset`.
+pub(crate) fn get_movements(
+    registry_uref: URef,
+    movement_count_uref: URef,
+    offset: u64,
+    limit: u64
+) -> Vec<BTreeMap<String, String>> {
+    let end = movement_count(movement_count_uref).min(offset.saturating_add(limit));
+    (offset..end)
+        .map(|index| get_movement(registry_uref, index))
+        .collect()
+}
@@ -0,0 +1,47 @@
+//! Implementation of the served accounts, the holders the contract is registered for.
+//!
+//! The accounts are kept in an indexed list, so checking whether an account is served, adding it
+//! or removing it never loads the other ones.
+use alloc::vec::Vec;
+use core::convert::TryInto;
+
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_erc1820::indexed_list;
+use casper_types::{ApiError, Key, URef};
+use constants::{SERVED_ACCOUNTS_KEY_NAME, SERVED_ACCOUNTS_LIST_OWNER};
+use error::Error;
+
+/// get the served accounts uref.
+#[inline]
+pub(crate) fn get_served_accounts_uref() -> URef {
+    let key = runtime::get_key(SERVED_ACCOUNTS_KEY_NAME)
+        .ok_or(ApiError::MissingKey)
+        .unwrap_or_revert();
+    key.try_into().unwrap_or_revert()
+}
+
+/// Returns the accounts the contract is registered for.
+pub(crate) fn read_served_accounts(served_accounts_uref: URef) -> Vec<Key> {
+    indexed_list::page(served_accounts_uref, SERVED_ACCOUNTS_LIST_OWNER, 0, u64::MAX)
+}
+
+/// Returns whether the contract is registered for `account`.
+pub(crate) fn is_served(served_accounts_uref: URef, account: Key) -> bool {
+    indexed_list::contains(served_accounts_uref, SERVED_ACCOUNTS_LIST_OWNER, &account)
+}
+
+/// Adds `account` to the served accounts, adding it twice has no effect.
+pub(crate) fn add_account(served_accounts_uref: URef, account: Key) {
+    indexed_list::add(served_accounts_uref, SERVED_ACCOUNTS_LIST_OWNER, account);
+}
+
+/// Removes `account` from the served accounts.
+///
+/// The last served account takes the place of the removed one.
+pub(crate) fn remove_account(served_accounts_uref: URef, account: Key) -> Result<(), Error> {
+    if !is_served(served_accounts_uref, account) {
+        return Err(Error::AccountNotServed);
+    }
+    indexed_list::remove(served_accounts_uref, SERVED_ACCOUNTS_LIST_OWNER, &account);
+    Ok(())
+}
@@ -0,0 +1,20 @@
+//! Constants used by the Vault contract.
+//!
+//! The names shared with the other contracts built on `casper-erc777-package-recipient` are
+//! re-exported from it.
+
+pub use casper_erc777_package_recipient::constants::*;
+
+/// Name of named-key for `contract`
+pub const VAULT_CONTRACT_NAME: &str = "erc777_vault_contract";
+
+/// Name of named-key for the amounts deposited by every depositor
+pub const DEPOSITS_REGISTRY_KEY_NAME: &str = "deposits";
+
+/// Name of `deposited_balance` entry point.
+pub const DEPOSITED_BALANCE_ENTRY_POINT_NAME: &str = "deposited_balance";
+/// Name of `withdraw` entry point.
+pub const WITHDRAW_ENTRY_POINT_NAME: &str = "withdraw";
+
+/// Name of `depositor` runtime argument.
+pub const DEPOSITOR_RUNTIME_ARG_NAME: &str = "depositor";
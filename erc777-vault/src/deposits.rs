@@ -0,0 +1,66 @@
+//! Implementation of the internal ledger of the tokens every depositor holds in the vault.
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc777_package_recipient::detail;
+use casper_types::{bytesrepr::ToBytes, Key, URef, U256};
+
+use crate::{constants::DEPOSITS_REGISTRY_KEY_NAME, error::Error};
+
+/// Getter for the uref of the deposits dictionary.
+#[inline]
+pub(crate) fn get_deposits_uref() -> URef {
+    detail::get_uref(DEPOSITS_REGISTRY_KEY_NAME)
+}
+
+/// Creates a dictionary item key for the amount of `token` deposited by `depositor`.
+#[inline]
+fn make_dictionary_item_key(depositor: Key, token: Key) -> String {
+    let mut preimage: Vec<u8> = depositor.to_bytes().unwrap_or_revert();
+    preimage.append(&mut token.to_bytes().unwrap_or_revert());
+    hex::encode(runtime::blake2b(&preimage))
+}
+
+/// Reads the amount of `token` deposited by `depositor`.
+///
+/// A depositor which has never deposited `token` has a zero amount.
+pub(crate) fn read_deposit(deposits_uref: URef, depositor: Key, token: Key) -> U256 {
+    storage::dictionary_get(deposits_uref, &make_dictionary_item_key(depositor, token))
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+fn write_deposit(deposits_uref: URef, depositor: Key, token: Key, amount: U256) {
+    storage::dictionary_put(deposits_uref, &make_dictionary_item_key(depositor, token), amount);
+}
+
+/// Credits `depositor` with `amount` tokens.
+pub(crate) fn credit(
+    deposits_uref: URef,
+    depositor: Key,
+    token: Key,
+    amount: U256,
+) -> Result<(), Error> {
+    let deposit = read_deposit(deposits_uref, depositor, token)
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+    write_deposit(deposits_uref, depositor, token, deposit);
+    Ok(())
+}
+
+/// Debits `amount` tokens from `depositor`, which cannot take more than it has deposited.
+pub(crate) fn debit(
+    deposits_uref: URef,
+    depositor: Key,
+    token: Key,
+    amount: U256,
+) -> Result<(), Error> {
+    let deposit = read_deposit(deposits_uref, depositor, token)
+        .checked_sub(amount)
+        .ok_or(Error::InsufficientDeposit)?;
+    write_deposit(deposits_uref, depositor, token, deposit);
+    Ok(())
+}
@@ -0,0 +1,48 @@
+//! Contains definition of the entry points.
+use alloc::{string::String, vec};
+
+use casper_types::{
+    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter,
+    U256,
+};
+
+use crate::constants::{
+    AMOUNT_RUNTIME_ARG_NAME, DEPOSITED_BALANCE_ENTRY_POINT_NAME, DEPOSITOR_RUNTIME_ARG_NAME,
+    TOKEN_RUNTIME_ARG_NAME, WITHDRAW_ENTRY_POINT_NAME,
+};
+
+/// Returns the `deposited_balance` entry point.
+pub fn deposited_balance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DEPOSITED_BALANCE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(DEPOSITOR_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(TOKEN_RUNTIME_ARG_NAME, Key::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `withdraw` entry point.
+pub fn withdraw() -> EntryPoint {
+    EntryPoint::new(
+        String::from(WITHDRAW_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the default set of Vault entry points.
+pub fn default() -> EntryPoints {
+    let mut entry_points = casper_erc777_package_recipient::entry_points::default();
+    entry_points.add_entry_point(deposited_balance());
+    entry_points.add_entry_point(withdraw());
+    entry_points
+}
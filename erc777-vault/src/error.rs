@@ -0,0 +1,58 @@
+//! Error handling on the casper platform.
+use casper_erc777_package_recipient::Error as PackageRecipientError;
+use casper_types::ApiError;
+
+/// Errors which can be returned by the library.
+///
+/// When an `Error` is returned from a smart contract, it is converted to an [`ApiError::User`].
+/// The errors of the vault use the range `[1600..1699]`.
+pub enum Error {
+    /// The vault was called from within an invalid context.
+    InvalidContext,
+    /// The caller is not allowed to configure the contract.
+    Unauthorized,
+    /// The token is not registered in the contract.
+    UnknownToken,
+    /// The token is not the key of a contract package.
+    InvalidToken,
+    /// The tokens do not come from a depositor, as when they are minted to the vault.
+    InvalidDepositor,
+    /// The depositor has not deposited enough tokens.
+    InsufficientDeposit,
+    /// Operation would cause an integer overflow.
+    Overflow,
+}
+
+const ERROR_INVALID_CONTEXT: u16 = 1600;
+const ERROR_UNAUTHORIZED: u16 = 1601;
+const ERROR_UNKNOWN_TOKEN: u16 = 1602;
+const ERROR_INVALID_TOKEN: u16 = 1603;
+const ERROR_INVALID_DEPOSITOR: u16 = 1604;
+const ERROR_INSUFFICIENT_DEPOSIT: u16 = 1605;
+const ERROR_OVERFLOW: u16 = 1606;
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        let user_error = match error {
+            Error::InvalidContext => ERROR_INVALID_CONTEXT,
+            Error::Unauthorized => ERROR_UNAUTHORIZED,
+            Error::UnknownToken => ERROR_UNKNOWN_TOKEN,
+            Error::InvalidToken => ERROR_INVALID_TOKEN,
+            Error::InvalidDepositor => ERROR_INVALID_DEPOSITOR,
+            Error::InsufficientDeposit => ERROR_INSUFFICIENT_DEPOSIT,
+            Error::Overflow => ERROR_OVERFLOW,
+        };
+        ApiError::User(user_error)
+    }
+}
+
+impl From<PackageRecipientError> for Error {
+    fn from(error: PackageRecipientError) -> Self {
+        match error {
+            PackageRecipientError::InvalidContext => Error::InvalidContext,
+            PackageRecipientError::Unauthorized => Error::Unauthorized,
+            PackageRecipientError::UnknownToken => Error::UnknownToken,
+            PackageRecipientError::InvalidToken => Error::InvalidToken,
+        }
+    }
+}
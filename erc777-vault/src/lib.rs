@@ -0,0 +1,156 @@
+//! A library for keeping the ERC777 tokens of several depositors in a single contract on the
+//! Casper network.
+//!
+//! The main functionality is provided via the [`Vault`] struct. The contract is the
+//! `ERC777TokensRecipient` implementer of its own contract package, so every registered token
+//! sent to the package goes through [`Vault::tokens_received`], which credits the sender in an
+//! internal ledger. Depositors only ever withdraw what they have been credited with.
+//!
+//! The hook plumbing, the owner and the registered tokens come from
+//! [`casper_erc777_package_recipient`], which the payment splitter is built on as well.
+
+#![warn(missing_docs)]
+#![no_std]
+
+extern crate alloc;
+
+pub mod constants;
+mod deposits;
+pub mod entry_points;
+mod error;
+
+use alloc::string::ToString;
+
+use once_cell::unsync::OnceCell;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc777_package_recipient::{detail, PackageRecipient};
+use casper_types::{
+    account::AccountHash, bytesrepr::Bytes, contracts::NamedKeys, ContractHash, EntryPoints, Key,
+    URef, U256,
+};
+
+use constants::{DEPOSITS_REGISTRY_KEY_NAME, VAULT_CONTRACT_NAME};
+pub use error::Error;
+
+/// Implementation of a recipient keeping the tokens of several depositors.
+#[derive(Default)]
+pub struct Vault {
+    recipient: PackageRecipient,
+    deposits_uref: OnceCell<URef>,
+}
+
+impl Vault {
+    fn new(recipient: PackageRecipient, deposits_uref: URef) -> Self {
+        Self {
+            recipient,
+            deposits_uref: deposits_uref.into(),
+        }
+    }
+
+    fn deposits_uref(&self) -> URef {
+        *self.deposits_uref.get_or_init(deposits::get_deposits_uref)
+    }
+
+    /// Installs the Vault contract with the default set of entry points.
+    ///
+    /// Tokens are registered afterwards with [`Vault::add_token`].
+    ///
+    /// This should be called from within `fn call()` of your contract.
+    pub fn install(erc1820_hash: ContractHash) -> Result<Vault, Error> {
+        Vault::install_custom(erc1820_hash, VAULT_CONTRACT_NAME, entry_points::default())
+    }
+
+    /// Installs the Vault contract with a custom set of entry points.
+    ///
+    /// # Warning
+    ///
+    /// Contract developers should use [`Vault::install`] instead, as it will create the default
+    /// set of entry points. The custom set must keep `register_self`, which is called during the
+    /// install.
+    #[doc(hidden)]
+    pub fn install_custom(
+        erc1820_hash: ContractHash,
+        contract_key_name: &str,
+        entry_points: EntryPoints,
+    ) -> Result<Vault, Error> {
+        let deposits_uref = storage::new_dictionary(DEPOSITS_REGISTRY_KEY_NAME).unwrap_or_revert();
+
+        let mut named_keys = NamedKeys::new();
+
+        let deposits_key = {
+            runtime::remove_key(DEPOSITS_REGISTRY_KEY_NAME);
+            Key::from(deposits_uref)
+        };
+
+        named_keys.insert(DEPOSITS_REGISTRY_KEY_NAME.to_string(), deposits_key);
+
+        let recipient =
+            PackageRecipient::install(erc1820_hash, contract_key_name, entry_points, named_keys);
+
+        Ok(Vault::new(recipient, deposits_uref))
+    }
+
+    /// Registers the contract as the `ERC777TokensRecipient` implementer of its own contract
+    /// package in the erc1820 registry.
+    ///
+    /// It is called during the install, calling it again has no effect.
+    pub fn register_self(&self) -> Result<(), Error> {
+        Ok(self.recipient.register_self()?)
+    }
+
+    /// Answers the ERC1820 registry whether this contract agrees to be the `ERC777TokensRecipient`
+    /// implementer of `account`, which is only the case for its own contract package.
+    pub fn can_implement_interface_for_address(&self, i_hash: Bytes, account: Key) -> Bytes {
+        self.recipient.can_implement_interface_for_address(i_hash, account)
+    }
+
+    /// Credits `from` with the `amount` tokens received by the vault.
+    ///
+    /// Only registered tokens are accepted, and minted tokens, which come from no depositor, are
+    /// rejected.
+    pub fn tokens_received(
+        &self,
+        _operator: Key,
+        from: Key,
+        to: Key,
+        amount: U256,
+        _data: Bytes,
+        _operator_data: Bytes,
+    ) -> Result<(), Error> {
+        let token = self.recipient.received_token(to)?;
+        if from.eq(&Key::from(AccountHash::default())) {
+            return Err(Error::InvalidDepositor);
+        }
+
+        deposits::credit(self.deposits_uref(), from, token, amount)
+    }
+
+    /// Returns the amount of `token` `depositor` can withdraw.
+    pub fn deposited_balance(&self, depositor: Key, token: Key) -> U256 {
+        deposits::read_deposit(self.deposits_uref(), depositor, token)
+    }
+
+    /// Sends `amount` of the `token` deposited by the caller back to it.
+    ///
+    /// The ledger is debited before the tokens are sent, so the caller cannot take more than it
+    /// has deposited. Tokens removed from the registered ones can still be withdrawn.
+    pub fn withdraw(&self, token: Key, amount: U256) -> Result<(), Error> {
+        let depositor = detail::get_immediate_caller_key()?;
+        deposits::debit(self.deposits_uref(), depositor, token, amount)?;
+        Ok(self.recipient.send(token, depositor, amount, Bytes::default())?)
+    }
+
+    /// Registers `token`, the key of an erc777 contract package, so that it can be deposited.
+    pub fn add_token(&self, token: Key) -> Result<(), Error> {
+        Ok(self.recipient.add_token(token)?)
+    }
+
+    /// Unregisters `token`, the hook rejects new deposits of it from then on.
+    pub fn remove_token(&self, token: Key) -> Result<(), Error> {
+        Ok(self.recipient.remove_token(token)?)
+    }
+}
@@ -0,0 +1,20 @@
+//! Constants used by the Wrapped CSPR contract.
+
+/// Name of named-key for `contract`
+pub const WRAPPED_CSPR_CONTRACT_NAME: &str = "erc777_wrapped_cspr_contract";
+/// Name of named-key for the purse holding the deposited motes
+pub const CONTRACT_PURSE_KEY_NAME: &str = "wrapped_cspr_purse";
+/// Decimals of the wrapped token, which are the decimals of CSPR
+pub const WRAPPED_CSPR_DECIMALS: u8 = 9;
+
+/// Name of `deposit` entry point.
+pub const DEPOSIT_ENTRY_POINT_NAME: &str = "deposit";
+/// Name of `withdraw` entry point.
+pub const WITHDRAW_ENTRY_POINT_NAME: &str = "withdraw";
+
+/// Name of `purse` runtime argument.
+pub const PURSE_RUNTIME_ARG_NAME: &str = "purse";
+/// Name of `amount` runtime argument.
+pub const AMOUNT_RUNTIME_ARG_NAME: &str = "amount";
+/// Name of `wrapped_cspr_contract` runtime argument.
+pub const WRAPPED_CSPR_CONTRACT_ARG_NAME: &str = "wrapped_cspr_contract";
@@ -0,0 +1,28 @@
+//! Implementation details.
+use casper_contract::contract_api::runtime;
+use casper_erc777::Address;
+use casper_types::system::CallStackElement;
+
+use crate::error::Error;
+
+/// Returns address based on a [`CallStackElement`].
+fn call_stack_element_to_address(call_stack_element: CallStackElement) -> Address {
+    match call_stack_element {
+        CallStackElement::Session { account_hash } => Address::from(account_hash),
+        CallStackElement::StoredSession { account_hash, .. } => Address::from(account_hash),
+        CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        } => Address::from(contract_package_hash),
+    }
+}
+
+/// Gets the immediate caller of the current execution.
+pub(crate) fn get_immediate_caller_address() -> Result<Address, Error> {
+    runtime::get_call_stack()
+        .into_iter()
+        .rev()
+        .nth(1)
+        .map(call_stack_element_to_address)
+        .ok_or(Error::InvalidContext)
+}
@@ -0,0 +1,42 @@
+//! Contains definition of the entry points.
+use alloc::{string::String, vec};
+
+use casper_types::{
+    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter, URef,
+    U256,
+};
+
+use crate::constants::{
+    AMOUNT_RUNTIME_ARG_NAME, DEPOSIT_ENTRY_POINT_NAME, PURSE_RUNTIME_ARG_NAME,
+    WITHDRAW_ENTRY_POINT_NAME,
+};
+
+/// Returns the `deposit` entry point.
+pub fn deposit() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DEPOSIT_ENTRY_POINT_NAME),
+        vec![Parameter::new(PURSE_RUNTIME_ARG_NAME, URef::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `withdraw` entry point.
+pub fn withdraw() -> EntryPoint {
+    EntryPoint::new(
+        String::from(WITHDRAW_ENTRY_POINT_NAME),
+        vec![Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the default set of ERC777 entry points together with `deposit` and `withdraw`.
+pub fn default() -> EntryPoints {
+    let mut entry_points = casper_erc777::entry_points::default();
+    entry_points.add_entry_point(deposit());
+    entry_points.add_entry_point(withdraw());
+    entry_points
+}
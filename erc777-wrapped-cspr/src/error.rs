@@ -0,0 +1,53 @@
+//! Error handling on the casper platform.
+use casper_erc777::Error as TokenError;
+use casper_types::ApiError;
+
+/// Errors which can be returned by the library.
+///
+/// When an `Error` is returned from a smart contract, it is converted to an [`ApiError::User`].
+/// Errors of the underlying token keep the codes of [`casper_erc777::Error`], and the errors
+/// specific to the wrapper use the range `[1100..1199]`.
+pub enum Error {
+    /// An error of the underlying ERC777 token.
+    Token(TokenError),
+    /// The amount is not a multiple of the token granularity.
+    InvalidGranularity,
+    /// The deposited purse does not hold any motes.
+    EmptyPurse,
+    /// The amount cannot be represented as motes or as tokens.
+    Overflow,
+    /// Moving motes between purses failed.
+    TransferFailed,
+    /// The contract was called outside of a contract call.
+    InvalidContext,
+    /// Motes can only be withdrawn to an account.
+    NotAnAccount,
+}
+
+const ERROR_INVALID_GRANULARITY: u16 = 1100;
+const ERROR_EMPTY_PURSE: u16 = 1101;
+const ERROR_OVERFLOW: u16 = 1102;
+const ERROR_TRANSFER_FAILED: u16 = 1103;
+const ERROR_INVALID_CONTEXT: u16 = 1104;
+const ERROR_NOT_AN_ACCOUNT: u16 = 1105;
+
+impl From<TokenError> for Error {
+    fn from(error: TokenError) -> Self {
+        Error::Token(error)
+    }
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        let user_error = match error {
+            Error::Token(token_error) => return ApiError::from(token_error),
+            Error::InvalidGranularity => ERROR_INVALID_GRANULARITY,
+            Error::EmptyPurse => ERROR_EMPTY_PURSE,
+            Error::Overflow => ERROR_OVERFLOW,
+            Error::TransferFailed => ERROR_TRANSFER_FAILED,
+            Error::InvalidContext => ERROR_INVALID_CONTEXT,
+            Error::NotAnAccount => ERROR_NOT_AN_ACCOUNT,
+        };
+        ApiError::User(user_error)
+    }
+}
@@ -0,0 +1,126 @@
+//! A library for wrapping CSPR into an ERC777 token on the Casper network.
+//!
+//! The main functionality is provided via the [`WrappedCSPR`] struct. It is built on top of
+//! [`ERC777`], owns a purse holding the deposited motes and mints one token per mote, so the token
+//! uses the 9 decimals of CSPR.
+
+#![warn(missing_docs)]
+#![no_std]
+
+extern crate alloc;
+
+pub mod constants;
+mod detail;
+pub mod entry_points;
+mod error;
+mod purse;
+
+use alloc::string::{String, ToString};
+
+use once_cell::unsync::OnceCell;
+
+use casper_contract::{contract_api::system, unwrap_or_revert::UnwrapOrRevert};
+use casper_erc777::ERC777;
+use casper_types::{bytesrepr::Bytes, contracts::NamedKeys, ContractHash, Key, URef, U256};
+
+use constants::{CONTRACT_PURSE_KEY_NAME, WRAPPED_CSPR_CONTRACT_NAME, WRAPPED_CSPR_DECIMALS};
+pub use error::Error;
+
+/// Implementation of an ERC777 token backed by native motes.
+#[derive(Default)]
+pub struct WrappedCSPR {
+    token: ERC777,
+    purse_uref: OnceCell<URef>,
+}
+
+impl WrappedCSPR {
+    fn new(token: ERC777, purse_uref: URef) -> Self {
+        Self {
+            token,
+            purse_uref: purse_uref.into(),
+        }
+    }
+
+    fn purse_uref(&self) -> URef {
+        *self.purse_uref.get_or_init(purse::contract_purse)
+    }
+
+    fn ensure_granularity(&self, amount: U256) -> Result<(), Error> {
+        if amount % self.token.granularity() != U256::zero() {
+            return Err(Error::InvalidGranularity);
+        }
+        Ok(())
+    }
+
+    /// Installs the Wrapped CSPR contract with the default set of entry points.
+    ///
+    /// This should be called from within `fn call()` of your contract.
+    pub fn install(
+        name: String,
+        symbol: String,
+        granularity: U256,
+        erc1820_hash: ContractHash,
+    ) -> Result<WrappedCSPR, Error> {
+        let purse_uref = system::create_purse();
+
+        let mut named_keys = NamedKeys::new();
+        named_keys.insert(CONTRACT_PURSE_KEY_NAME.to_string(), Key::from(purse_uref));
+
+        let token = ERC777::install_with_named_keys(
+            name,
+            symbol,
+            WRAPPED_CSPR_DECIMALS,
+            granularity,
+            U256::zero(),
+            WRAPPED_CSPR_CONTRACT_NAME,
+            None,
+            entry_points::default(),
+            erc1820_hash,
+            named_keys,
+        )?;
+
+        Ok(WrappedCSPR::new(token, purse_uref))
+    }
+
+    /// Moves every mote of `purse` into the contract purse and mints the same amount of tokens to
+    /// the immediate caller.
+    ///
+    /// `purse` is expected to be a purse created by the session code of the caller, which transfers
+    /// the motes to wrap into it beforehand.
+    pub fn deposit(&mut self, purse: URef) -> Result<(), Error> {
+        let motes = system::get_purse_balance(purse).unwrap_or_revert();
+        let amount = purse::motes_to_tokens(motes)?;
+        if amount.is_zero() {
+            return Err(Error::EmptyPurse);
+        }
+        self.ensure_granularity(amount)?;
+
+        system::transfer_from_purse_to_purse(purse, self.purse_uref(), motes, None)
+            .map_err(|_| Error::TransferFailed)?;
+
+        let owner = detail::get_immediate_caller_address()?;
+        self.token.mint(owner, amount)?;
+        Ok(())
+    }
+
+    /// Burns `amount` tokens of the immediate caller and transfers the same amount of motes back to
+    /// its main purse.
+    ///
+    /// The immediate caller has to be an account, as contracts have no main purse.
+    pub fn withdraw(&mut self, amount: U256) -> Result<(), Error> {
+        self.ensure_granularity(amount)?;
+
+        let owner = detail::get_immediate_caller_address()?;
+        let account_hash = *owner.as_account_hash().ok_or(Error::NotAnAccount)?;
+        self.token.burn(amount, Bytes::default())?;
+
+        system::transfer_from_purse_to_account(
+            self.purse_uref(),
+            account_hash,
+            purse::tokens_to_motes(amount),
+            None,
+        )
+        .map_err(|_| Error::TransferFailed)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+//! Implementation of the purse holding the deposited motes.
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{ApiError, URef, U256, U512};
+
+use crate::{constants::CONTRACT_PURSE_KEY_NAME, error::Error};
+
+/// Getter for the purse holding the deposited motes.
+pub(crate) fn contract_purse() -> URef {
+    runtime::get_key(CONTRACT_PURSE_KEY_NAME)
+        .ok_or(ApiError::MissingKey)
+        .unwrap_or_revert()
+        .into_uref()
+        .ok_or(ApiError::UnexpectedKeyVariant)
+        .unwrap_or_revert()
+}
+
+/// Converts an amount of motes into the same amount of tokens.
+pub(crate) fn motes_to_tokens(motes: U512) -> Result<U256, Error> {
+    let mut bytes = [0u8; 64];
+    motes.to_little_endian(&mut bytes);
+    if bytes[32..].iter().any(|byte| *byte != 0) {
+        return Err(Error::Overflow);
+    }
+    Ok(U256::from_little_endian(&bytes[..32]))
+}
+
+/// Converts an amount of tokens into the same amount of motes.
+pub(crate) fn tokens_to_motes(amount: U256) -> U512 {
+    let mut bytes = [0u8; 32];
+    amount.to_little_endian(&mut bytes);
+    U512::from_little_endian(&bytes)
+}
@@ -0,0 +1,31 @@
+//! Constants used by the Wrapped ERC20 contract.
+
+/// Name of named-key for `contract`
+pub const WRAPPED_ERC20_CONTRACT_NAME: &str = "erc777_wrapped_erc20_contract";
+/// Name of named-key for the hash of the contract package, which holds the underlying tokens
+pub const WRAPPED_ERC20_PACKAGE_HASH_KEY_NAME: &str = "erc777_wrapped_erc20_contract_package_hash";
+/// Name of named-key for the hash of the underlying ERC20 token
+pub const UNDERLYING_TOKEN_KEY_NAME: &str = "underlying_token";
+
+/// Name of `deposit` entry point.
+pub const DEPOSIT_ENTRY_POINT_NAME: &str = "deposit";
+/// Name of `withdraw` entry point.
+pub const WITHDRAW_ENTRY_POINT_NAME: &str = "withdraw";
+/// Name of `underlying_token` entry point.
+pub const UNDERLYING_TOKEN_ENTRY_POINT_NAME: &str = "underlying_token";
+
+/// Name of `decimals` entry point of the underlying token.
+pub const DECIMALS_OF_EXTERNAL_ENTRY_POINT: &str = "decimals";
+/// Name of `transfer` entry point of the underlying token.
+pub const TRANSFER_OF_EXTERNAL_ENTRY_POINT: &str = "transfer";
+/// Name of `transfer_from` entry point of the underlying token.
+pub const TRANSFER_FROM_OF_EXTERNAL_ENTRY_POINT: &str = "transfer_from";
+
+/// Name of `amount` runtime argument.
+pub const AMOUNT_RUNTIME_ARG_NAME: &str = "amount";
+/// Name of `owner` runtime argument.
+pub const OWNER_RUNTIME_ARG_NAME: &str = "owner";
+/// Name of `recipient` runtime argument.
+pub const RECIPIENT_RUNTIME_ARG_NAME: &str = "recipient";
+/// Name of `underlying_token` runtime argument.
+pub const UNDERLYING_TOKEN_RUNTIME_ARG_NAME: &str = "underlying_token";
@@ -0,0 +1,37 @@
+//! Error handling on the casper platform.
+use casper_erc777::Error as TokenError;
+use casper_types::ApiError;
+
+/// Errors which can be returned by the library.
+///
+/// When an `Error` is returned from a smart contract, it is converted to an [`ApiError::User`].
+/// Errors of the wrapped token keep the codes of [`casper_erc777::Error`], and the errors
+/// specific to the wrapper use the range `[1200..1299]`.
+pub enum Error {
+    /// An error of the wrapped ERC777 token.
+    Token(TokenError),
+    /// The wrapper was called from within an invalid context.
+    InvalidContext,
+    /// The amount is not a multiple of the token granularity.
+    InvalidGranularity,
+}
+
+const ERROR_INVALID_CONTEXT: u16 = 1200;
+const ERROR_INVALID_GRANULARITY: u16 = 1201;
+
+impl From<TokenError> for Error {
+    fn from(error: TokenError) -> Self {
+        Error::Token(error)
+    }
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        let user_error = match error {
+            Error::Token(token_error) => return ApiError::from(token_error),
+            Error::InvalidContext => ERROR_INVALID_CONTEXT,
+            Error::InvalidGranularity => ERROR_INVALID_GRANULARITY,
+        };
+        ApiError::User(user_error)
+    }
+}
@@ -0,0 +1,128 @@
+//! A library for wrapping ERC20 tokens into ERC777 tokens on the Casper network.
+//!
+//! The main functionality is provided via the [`WrappedERC20`] struct. It is built on top of
+//! [`ERC777`], holds the deposited underlying tokens and mints one wrapped token per underlying
+//! token, so the wrapped token mirrors the decimals of the underlying one.
+
+#![warn(missing_docs)]
+#![no_std]
+
+extern crate alloc;
+
+pub mod constants;
+mod detail;
+pub mod entry_points;
+mod error;
+mod underlying;
+
+use alloc::string::{String, ToString};
+
+use once_cell::unsync::OnceCell;
+
+use casper_contract::contract_api::storage;
+use casper_erc777::ERC777;
+use casper_types::{bytesrepr::Bytes, contracts::NamedKeys, ContractHash, Key, URef, U256};
+
+use constants::{
+    UNDERLYING_TOKEN_KEY_NAME, WRAPPED_ERC20_CONTRACT_NAME, WRAPPED_ERC20_PACKAGE_HASH_KEY_NAME,
+};
+pub use error::Error;
+
+/// Implementation of an ERC777 token backed by an ERC20 token.
+#[derive(Default)]
+pub struct WrappedERC20 {
+    token: ERC777,
+    underlying_token_uref: OnceCell<URef>,
+}
+
+impl WrappedERC20 {
+    fn new(token: ERC777, underlying_token_uref: URef) -> Self {
+        Self {
+            token,
+            underlying_token_uref: underlying_token_uref.into(),
+        }
+    }
+
+    fn underlying_token_uref(&self) -> URef {
+        *self
+            .underlying_token_uref
+            .get_or_init(underlying::underlying_token_uref)
+    }
+
+    fn ensure_granularity(&self, amount: U256) -> Result<(), Error> {
+        if amount % self.token.granularity() != U256::zero() {
+            return Err(Error::InvalidGranularity);
+        }
+        Ok(())
+    }
+
+    /// Installs the Wrapped ERC20 contract with the default set of entry points.
+    ///
+    /// The decimals of the wrapped token are read from `underlying_token` during the install.
+    ///
+    /// This should be called from within `fn call()` of your contract.
+    pub fn install(
+        name: String,
+        symbol: String,
+        granularity: U256,
+        underlying_token: ContractHash,
+        erc1820_hash: ContractHash,
+    ) -> Result<WrappedERC20, Error> {
+        let decimals = underlying::decimals(underlying_token);
+        let underlying_token_uref = storage::new_uref(underlying_token).into_read();
+
+        let mut named_keys = NamedKeys::new();
+        named_keys.insert(
+            UNDERLYING_TOKEN_KEY_NAME.to_string(),
+            Key::from(underlying_token_uref),
+        );
+
+        let token = ERC777::install_with_named_keys(
+            name,
+            symbol,
+            decimals,
+            granularity,
+            U256::zero(),
+            WRAPPED_ERC20_CONTRACT_NAME,
+            Some(WRAPPED_ERC20_PACKAGE_HASH_KEY_NAME),
+            entry_points::default(),
+            erc1820_hash,
+            named_keys,
+        )?;
+
+        Ok(WrappedERC20::new(token, underlying_token_uref))
+    }
+
+    /// Returns the hash of the underlying ERC20 token.
+    pub fn underlying_token(&self) -> ContractHash {
+        underlying::read_underlying_token_from(self.underlying_token_uref())
+    }
+
+    /// Pulls `amount` underlying tokens from the caller and mints the same amount of wrapped tokens
+    /// to it.
+    ///
+    /// The caller has to approve the wrapper contract package to spend `amount` of its underlying
+    /// tokens beforehand.
+    pub fn deposit(&mut self, amount: U256) -> Result<(), Error> {
+        self.ensure_granularity(amount)?;
+
+        let owner = detail::get_immediate_caller_address()?;
+        let wrapper = detail::get_self_address()?;
+        underlying::transfer_from(self.underlying_token(), owner, wrapper, amount);
+
+        self.token.mint(owner, amount)?;
+        Ok(())
+    }
+
+    /// Burns `amount` wrapped tokens of the caller and releases the same amount of underlying
+    /// tokens to it.
+    pub fn withdraw(&mut self, amount: U256) -> Result<(), Error> {
+        self.ensure_granularity(amount)?;
+
+        let owner = detail::get_immediate_caller_address()?;
+        self.token.burn(amount, Bytes::default())?;
+
+        underlying::transfer(self.underlying_token(), owner, amount);
+        Ok(())
+    }
+}
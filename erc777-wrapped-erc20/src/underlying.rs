@@ -0,0 +1,53 @@
+//! Implementation of the calls to the underlying ERC20 token.
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc777::Address;
+use casper_types::{runtime_args, ApiError, ContractHash, RuntimeArgs, URef, U256};
+
+use crate::constants::{
+    AMOUNT_RUNTIME_ARG_NAME, DECIMALS_OF_EXTERNAL_ENTRY_POINT, OWNER_RUNTIME_ARG_NAME,
+    RECIPIENT_RUNTIME_ARG_NAME, TRANSFER_FROM_OF_EXTERNAL_ENTRY_POINT,
+    TRANSFER_OF_EXTERNAL_ENTRY_POINT, UNDERLYING_TOKEN_KEY_NAME,
+};
+
+/// Getter for the uref holding the hash of the underlying token.
+pub(crate) fn underlying_token_uref() -> URef {
+    runtime::get_key(UNDERLYING_TOKEN_KEY_NAME)
+        .ok_or(ApiError::MissingKey)
+        .unwrap_or_revert()
+        .into_uref()
+        .ok_or(ApiError::UnexpectedKeyVariant)
+        .unwrap_or_revert()
+}
+
+/// Reads the hash of the underlying token.
+pub(crate) fn read_underlying_token_from(uref: URef) -> ContractHash {
+    storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Asks the underlying token for its decimals.
+pub(crate) fn decimals(token: ContractHash) -> u8 {
+    runtime::call_contract(token, DECIMALS_OF_EXTERNAL_ENTRY_POINT, runtime_args! {})
+}
+
+/// Pulls `amount` underlying tokens from `owner` to `recipient` using the allowance given to the
+/// wrapper.
+pub(crate) fn transfer_from(token: ContractHash, owner: Address, recipient: Address, amount: U256) {
+    let args = runtime_args! {
+        OWNER_RUNTIME_ARG_NAME => owner,
+        RECIPIENT_RUNTIME_ARG_NAME => recipient,
+        AMOUNT_RUNTIME_ARG_NAME => amount
+    };
+    runtime::call_contract::<()>(token, TRANSFER_FROM_OF_EXTERNAL_ENTRY_POINT, args);
+}
+
+/// Sends `amount` underlying tokens held by the wrapper to `recipient`.
+pub(crate) fn transfer(token: ContractHash, recipient: Address, amount: U256) {
+    let args = runtime_args! {
+        RECIPIENT_RUNTIME_ARG_NAME => recipient,
+        AMOUNT_RUNTIME_ARG_NAME => amount
+    };
+    runtime::call_contract::<()>(token, TRANSFER_OF_EXTERNAL_ENTRY_POINT, args);
+}
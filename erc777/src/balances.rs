@@ -8,7 +8,8 @@ use casper_types::bytesrepr::Bytes;
 
 use crate::{constants::{BALANCES_KEY_NAME, HASH_ERC1820_RECIPIENT, HASH_ERC1820_SENDER}, detail, error::Error, Address};
 use crate::Address::Account;
-use crate::external_contracts::{get_interface, tokens_received, tokens_to_send};
+use crate::external_contracts::{get_interface, has_registry, tokens_received, tokens_to_send};
+use casper_erc1820::interface_hash;
 
 /// Creates a dictionary item key for a dictionary item.
 #[inline]
@@ -72,9 +73,48 @@ pub(crate) fn transfer_balance(
     Ok(())
 }
 
+/// Notifies the `ERC777TokensSender` hook of `sender`, if any, that `operator` moves `amount` of
+/// its tokens to `recipient` through the ERC20 entry points.
+///
+/// The hook can revert the movement, so that a holder's send policies and limits also apply to
+/// `transfer` and `transfer_from`. Entries which move no tokens do not call it.
+pub(crate) fn notify_transfer(
+    registry_uref: URef,
+    operator: Address,
+    sender: Address,
+    recipient: Address,
+    amount: U256
+) {
+    if sender == recipient || amount.is_zero() || !has_registry(registry_uref) {
+        return;
+    }
+
+    let implementer = get_interface(
+        registry_uref,
+        sender,
+        interface_hash(HASH_ERC1820_SENDER)
+    );
+
+    if implementer.into_hash().is_some() {
+        tokens_to_send(
+            operator,
+            sender,
+            recipient,
+            amount,
+            Bytes::default(),
+            Bytes::default(),
+            implementer
+        );
+    }
+}
+
+/// Sends `amount` tokens from `sender` to `recipient` on behalf of `operator`, notifying the
+/// `ERC777TokensSender` hook of `sender` before and the `ERC777TokensRecipient` hook of `recipient`
+/// after the balances are updated.
 pub(crate) fn send_balance(
     balances_uref: URef,
     registry_uref: URef,
+    operator: Address,
     sender: Address,
     recipient: Address,
     amount: U256,
@@ -90,12 +130,12 @@ pub(crate) fn send_balance(
     let implementer = get_interface(
         registry_uref,
         sender,
-        Bytes::from(HASH_ERC1820_SENDER.to_bytes().unwrap())
+        interface_hash(HASH_ERC1820_SENDER)
     );
 
     if implementer.into_hash().is_some() {
         tokens_to_send(
-            sender,
+            operator,
             sender,
             recipient,
             amount,
@@ -112,13 +152,13 @@ pub(crate) fn send_balance(
 
     let implementer = get_interface(
         registry_uref,
-        sender,
-        Bytes::from(HASH_ERC1820_RECIPIENT.to_bytes().unwrap())
+        recipient,
+        interface_hash(HASH_ERC1820_RECIPIENT)
     );
 
     if implementer.into_hash().is_some() {
         tokens_received(
-            sender,
+            operator,
             sender,
             recipient,
             amount,
@@ -151,7 +191,7 @@ pub fn _mint(
     let implementer = get_interface(
         registry_uref,
         owner,
-        Bytes::from(HASH_ERC1820_RECIPIENT.to_bytes().unwrap())
+        interface_hash(HASH_ERC1820_RECIPIENT)
     );
 
     if implementer.into_hash().is_some() {
@@ -172,6 +212,7 @@ pub fn _mint(
 pub fn burn(
     balances_uref: URef,
     registry_uref: URef,
+    operator: Address,
     owner: Address,
     amount: U256,
     total_supply: U256,
@@ -186,12 +227,12 @@ pub fn burn(
     let implementer = get_interface(
         registry_uref,
         owner,
-        Bytes::from(HASH_ERC1820_SENDER.to_bytes().unwrap())
+        interface_hash(HASH_ERC1820_SENDER)
     );
 
     if implementer.into_hash().is_some() {
         tokens_to_send(
-            owner,
+            operator,
             owner,
             Account(AccountHash::default()),
             amount,
@@ -214,4 +255,92 @@ pub fn burn(
     write_balance_to(balances_uref, owner, new_balance);
 
     Ok(new_total_supply)
-}
\ No newline at end of file
+}
+
+/// Sends `amounts[i]` tokens from `sender` to `recipients[i]` for every index.
+///
+/// The entries are expected to move tokens: no recipient is `sender` and no amount is zero.
+///
+/// The balance of `sender` is read and written only once, the `ERC777TokensSender` hook of
+/// `sender` is notified about every movement before any state is updated, and the
+/// `ERC777TokensRecipient` hook of every recipient is notified afterwards. Any error leaves the
+/// whole batch unapplied.
+pub(crate) fn batch_send_balance(
+    balances_uref: URef,
+    registry_uref: URef,
+    operator: Address,
+    sender: Address,
+    recipients: &[Address],
+    amounts: &[U256],
+    data: Bytes,
+    operator_data: Bytes,
+    is_operator: bool
+) -> Result<(), Error> {
+    if !is_operator {
+        return Err(Error::InvalidOperator);
+    }
+
+    if recipients.len() != amounts.len() {
+        return Err(Error::BatchLengthMismatch);
+    }
+
+    let implementer = get_interface(
+        registry_uref,
+        sender,
+        interface_hash(HASH_ERC1820_SENDER)
+    );
+
+    if implementer.into_hash().is_some() {
+        for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+            tokens_to_send(
+                operator,
+                sender,
+                *recipient,
+                *amount,
+                data.clone(),
+                operator_data.clone(),
+                implementer
+            );
+        }
+    }
+
+    let total = amounts
+        .iter()
+        .try_fold(U256::zero(), |total, amount| total.checked_add(*amount))
+        .ok_or(Error::Overflow)?;
+
+    let new_sender_balance = read_balance_from(balances_uref, sender)
+        .checked_sub(total)
+        .ok_or(Error::InsufficientBalance)?;
+
+    for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+        let new_recipient_balance = read_balance_from(balances_uref, *recipient)
+            .checked_add(*amount)
+            .ok_or(Error::Overflow)?;
+        write_balance_to(balances_uref, *recipient, new_recipient_balance);
+    }
+
+    write_balance_to(balances_uref, sender, new_sender_balance);
+
+    for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+        let implementer = get_interface(
+            registry_uref,
+            *recipient,
+            interface_hash(HASH_ERC1820_RECIPIENT)
+        );
+
+        if implementer.into_hash().is_some() {
+            tokens_received(
+                operator,
+                sender,
+                *recipient,
+                *amount,
+                data.clone(),
+                operator_data.clone(),
+                implementer
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -12,6 +12,9 @@ pub const DECIMALS_KEY_VALUE: u8 = 18;
 pub const GRANULARITY_KEY_NAME: &str = "granularity";
 /// Name of named-key for `contract`
 pub const ERC20_TOKEN_CONTRACT_NAME: &str = "erc777_token_contract";
+/// Suffix of the named-key under which the installer of a contract opting in keeps the hash of
+/// the contract package
+pub const PACKAGE_HASH_KEY_NAME_SUFFIX: &str = "_package_hash";
 /// Name of dictionary-key for `balances`
 pub const BALANCES_KEY_NAME: &str = "balances";
 /// Name of dictionary-key for `allowances`
@@ -20,6 +23,16 @@ pub const ALLOWANCES_KEY_NAME: &str = "allowances";
 pub const TOTAL_SUPPLY_KEY_NAME: &str = "total_supply";
 /// Name of named-key for `operators`
 pub const OPERATORS_KEY_NAME: &str = "operators";
+/// Name of dictionary-key for `nonces`
+pub const NONCES_KEY_NAME: &str = "nonces";
+/// Name of dictionary-key for `holds`
+pub const HOLDS_KEY_NAME: &str = "holds";
+/// Name of dictionary-key for `held_balances`
+pub const HELD_BALANCES_KEY_NAME: &str = "held_balances";
+/// Name of dictionary-key for `events`
+pub const EVENTS_KEY_NAME: &str = "events";
+/// Name of named-key for `events_count`
+pub const EVENTS_COUNT_KEY_NAME: &str = "events_count";
 
 /// Name of `name` entry point.
 pub const NAME_ENTRY_POINT_NAME: &str = "name";
@@ -49,11 +62,32 @@ pub const DEFAULT_OPERATORS_ENTRY_POINT_NAME: &str = "default_operators";
 pub const OPERATOR_SEND_ENTRY_POINT_NAME: &str = "operator_send";
 /// Name of `operator_burn` entry point.
 pub const OPERATOR_BURN_ENTRY_POINT_NAME: &str = "operator_burn";
+/// Name of `batch_send` entry point.
+pub const BATCH_SEND_ENTRY_POINT_NAME: &str = "batch_send";
+/// Name of `operator_batch_send` entry point.
+pub const OPERATOR_BATCH_SEND_ENTRY_POINT_NAME: &str = "operator_batch_send";
+/// Name of `hold` entry point.
+pub const HOLD_ENTRY_POINT_NAME: &str = "hold";
+/// Name of `execute_hold` entry point.
+pub const EXECUTE_HOLD_ENTRY_POINT_NAME: &str = "execute_hold";
+/// Name of `release_hold` entry point.
+pub const RELEASE_HOLD_ENTRY_POINT_NAME: &str = "release_hold";
+/// Name of `balance_on_hold` entry point.
+pub const BALANCE_ON_HOLD_ENTRY_POINT_NAME: &str = "balance_on_hold";
+/// Name of `spendable_balance_of` entry point.
+pub const SPENDABLE_BALANCE_OF_ENTRY_POINT_NAME: &str = "spendable_balance_of";
 /// Name of `set_registry` entry point.
 pub const SET_REGISTRY_ENTRY_POINT_NAME: &str = "set_registry";
 /// Name of `set_interface_registry` entry point.
 pub const SET_INTERFACE_REGISTRY_ENTRY_POINT_NAME_2: &str = "set_interface_registry2";
 
+/// Name of `permit` entry point.
+pub const PERMIT_ENTRY_POINT_NAME: &str = "permit";
+/// Name of `permit_operator` entry point.
+pub const PERMIT_OPERATOR_ENTRY_POINT_NAME: &str = "permit_operator";
+/// Name of `nonce_of` entry point.
+pub const NONCE_OF_ENTRY_POINT_NAME: &str = "nonce_of";
+
 /// Name of `approve` entry point.
 pub const APPROVE_ENTRY_POINT_NAME: &str = "approve";
 /// Name of `allowance` entry point.
@@ -97,6 +131,24 @@ pub const OPERATORS_RUNTIME_ARG_NAME: &str = "operators";
 pub const TOKEN_HOLDER_RUNTIME_ARG_NAME: &str = "token_holder";
 /// Name of `account` runtime argument.
 pub const ACCOUNT_RUNTIME_ARG_NAME: &str = "account";
+/// Name of `recipients` runtime argument.
+pub const RECIPIENTS_RUNTIME_ARG_NAME: &str = "recipients";
+/// Name of `amounts` runtime argument.
+pub const AMOUNTS_RUNTIME_ARG_NAME: &str = "amounts";
+/// Name of `holder` runtime argument.
+pub const HOLDER_RUNTIME_ARG_NAME: &str = "holder";
+/// Name of `hold_id` runtime argument.
+pub const HOLD_ID_RUNTIME_ARG_NAME: &str = "hold_id";
+/// Name of `notary` runtime argument.
+pub const NOTARY_RUNTIME_ARG_NAME: &str = "notary";
+/// Name of `expiration` runtime argument.
+pub const EXPIRATION_RUNTIME_ARG_NAME: &str = "expiration";
+/// Name of `owner_public_key` runtime argument.
+pub const OWNER_PUBLIC_KEY_RUNTIME_ARG_NAME: &str = "owner_public_key";
+/// Name of `deadline` runtime argument.
+pub const DEADLINE_RUNTIME_ARG_NAME: &str = "deadline";
+/// Name of `signature` runtime argument.
+pub const SIGNATURE_RUNTIME_ARG_NAME: &str = "signature";
 
 /// External contracts
 pub const REGISTRY_CONTRACT_NAME: &str = "erc1820_global_registry";
@@ -129,6 +181,11 @@ pub const HASH_ERC777_TOKEN: &str = "ERC777Token";
 /// Constant to retrieve an implementer to receive tokens
 pub const HASH_ERC20_TOKEN: &str = "ERC20Token";
 
+/// Domain tag prepended to the message signed for a `permit`
+pub const PERMIT_DOMAIN_TAG: &str = "ERC777Permit";
+/// Domain tag prepended to the message signed for a `permit_operator`
+pub const PERMIT_OPERATOR_DOMAIN_TAG: &str = "ERC777PermitOperator";
+
 /// This hash must be configured to use with the actual ERC1820 deployed.
 pub const HASH_ERC1820_REGISTRY: &str = "contract-dbd88acafb7c031f8c9f2aa3a8da7a3a6b74c655921496cdc3bd0d50f61aa997";
 
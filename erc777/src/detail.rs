@@ -5,7 +5,9 @@ use casper_contract::{
     contract_api::{runtime, storage},
     unwrap_or_revert::UnwrapOrRevert,
 };
-use casper_types::{bytesrepr::FromBytes, system::CallStackElement, ApiError, CLTyped, URef};
+use casper_types::{
+    bytesrepr::FromBytes, system::CallStackElement, ApiError, CLTyped, ContractHash, URef,
+};
 
 use crate::{error::Error, Address};
 
@@ -75,3 +77,14 @@ pub(crate) fn get_caller_address() -> Result<Address, Error> {
     let address = call_stack_element_to_address(top_of_the_stack);
     Ok(address)
 }
+
+/// Gets the hash of the contract currently being executed.
+///
+/// Fails with [`Error::InvalidContext`] when not called from within a stored contract.
+pub(crate) fn get_self_contract_hash() -> Result<ContractHash, Error> {
+    let call_stack = runtime::get_call_stack();
+    match call_stack.into_iter().rev().next() {
+        Some(CallStackElement::StoredContract { contract_hash, .. }) => Ok(contract_hash),
+        _ => Err(Error::InvalidContext),
+    }
+}
@@ -2,7 +2,7 @@
 use alloc::{string::String, vec, vec::Vec};
 use alloc::boxed::Box;
 
-use casper_types::{CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter, U256};
+use casper_types::{CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter, PublicKey, U256};
 use casper_types::bytesrepr::Bytes;
 
 use crate::{
@@ -18,7 +18,14 @@ use crate::{
         IS_OPERATOR_FOR_ENTRY_POINT_NAME, OPERATOR_BURN_ENTRY_POINT_NAME,
         OPERATOR_DATA_RUNTIME_ARG_NAME, OPERATOR_RUNTIME_ARG_NAME, OPERATOR_SEND_ENTRY_POINT_NAME,
         REVOKE_OPERATOR_ENTRY_POINT_NAME, SEND_ENTRY_POINT_NAME,
-        SENDER_RUNTIME_ARG_NAME, TOKEN_HOLDER_RUNTIME_ARG_NAME
+        SENDER_RUNTIME_ARG_NAME, TOKEN_HOLDER_RUNTIME_ARG_NAME, PERMIT_ENTRY_POINT_NAME,
+        PERMIT_OPERATOR_ENTRY_POINT_NAME, NONCE_OF_ENTRY_POINT_NAME,
+        OWNER_PUBLIC_KEY_RUNTIME_ARG_NAME, DEADLINE_RUNTIME_ARG_NAME, SIGNATURE_RUNTIME_ARG_NAME,
+        BATCH_SEND_ENTRY_POINT_NAME, OPERATOR_BATCH_SEND_ENTRY_POINT_NAME,
+        RECIPIENTS_RUNTIME_ARG_NAME, AMOUNTS_RUNTIME_ARG_NAME, HOLDER_RUNTIME_ARG_NAME,
+        HOLD_ENTRY_POINT_NAME, EXECUTE_HOLD_ENTRY_POINT_NAME, RELEASE_HOLD_ENTRY_POINT_NAME,
+        BALANCE_ON_HOLD_ENTRY_POINT_NAME, SPENDABLE_BALANCE_OF_ENTRY_POINT_NAME,
+        HOLD_ID_RUNTIME_ARG_NAME, NOTARY_RUNTIME_ARG_NAME, EXPIRATION_RUNTIME_ARG_NAME
     },
 };
 
@@ -257,6 +264,143 @@ pub fn operator_burn() -> EntryPoint {
     )
 }
 
+/// Returns the `batch_send` entry point.
+pub fn batch_send() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BATCH_SEND_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENTS_RUNTIME_ARG_NAME, CLType::List(Box::new(Address::cl_type()))),
+            Parameter::new(AMOUNTS_RUNTIME_ARG_NAME, CLType::List(Box::new(U256::cl_type()))),
+            Parameter::new(DATA_RUNTIME_ARG_NAME, Bytes::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `operator_batch_send` entry point.
+pub fn operator_batch_send() -> EntryPoint {
+    EntryPoint::new(
+        String::from(OPERATOR_BATCH_SEND_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(HOLDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(RECIPIENTS_RUNTIME_ARG_NAME, CLType::List(Box::new(Address::cl_type()))),
+            Parameter::new(AMOUNTS_RUNTIME_ARG_NAME, CLType::List(Box::new(U256::cl_type()))),
+            Parameter::new(DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+            Parameter::new(OPERATOR_DATA_RUNTIME_ARG_NAME, Bytes::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `hold` entry point.
+pub fn hold() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HOLD_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(HOLD_ID_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(RECIPIENT_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(NOTARY_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(EXPIRATION_RUNTIME_ARG_NAME, u64::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `execute_hold` entry point.
+pub fn execute_hold() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EXECUTE_HOLD_ENTRY_POINT_NAME),
+        vec![Parameter::new(HOLD_ID_RUNTIME_ARG_NAME, String::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `release_hold` entry point.
+pub fn release_hold() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RELEASE_HOLD_ENTRY_POINT_NAME),
+        vec![Parameter::new(HOLD_ID_RUNTIME_ARG_NAME, String::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `balance_on_hold` entry point.
+pub fn balance_on_hold() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BALANCE_ON_HOLD_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `spendable_balance_of` entry point.
+pub fn spendable_balance_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SPENDABLE_BALANCE_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `permit` entry point.
+pub fn permit() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PERMIT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER_PUBLIC_KEY_RUNTIME_ARG_NAME, PublicKey::cl_type()),
+            Parameter::new(SPENDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DEADLINE_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(SIGNATURE_RUNTIME_ARG_NAME, Bytes::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `permit_operator` entry point.
+pub fn permit_operator() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PERMIT_OPERATOR_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER_PUBLIC_KEY_RUNTIME_ARG_NAME, PublicKey::cl_type()),
+            Parameter::new(OPERATOR_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(DEADLINE_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(SIGNATURE_RUNTIME_ARG_NAME, Bytes::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `nonce_of` entry point.
+pub fn nonce_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(NONCE_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type())],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the default set of ERC20 token entry points.
 pub fn default() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
@@ -278,5 +422,15 @@ pub fn default() -> EntryPoints {
     entry_points.add_entry_point(default_operators());
     entry_points.add_entry_point(operator_send());
     entry_points.add_entry_point(operator_burn());
+    entry_points.add_entry_point(permit());
+    entry_points.add_entry_point(permit_operator());
+    entry_points.add_entry_point(nonce_of());
+    entry_points.add_entry_point(batch_send());
+    entry_points.add_entry_point(operator_batch_send());
+    entry_points.add_entry_point(hold());
+    entry_points.add_entry_point(execute_hold());
+    entry_points.add_entry_point(release_hold());
+    entry_points.add_entry_point(balance_on_hold());
+    entry_points.add_entry_point(spendable_balance_of());
     entry_points
 }
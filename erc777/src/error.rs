@@ -9,7 +9,7 @@ use casper_types::ApiError;
 /// return those via the [`Error::User`] variant or equivalently via the [`ApiError::User`]
 /// variant.
 ///
-/// Such a user error should be in the range `[0..(u16::MAX - 4)]` (i.e. [0, 65532]) to avoid
+/// Such a user error should be in the range `[0..(u16::MAX - 14)]` (i.e. [0, 65521]) to avoid
 /// conflicting with the other `Error` variants.
 pub enum Error {
     /// ERC20 contract called from within an invalid context.
@@ -25,7 +25,23 @@ pub enum Error {
     /// The address does not exist
     InvalidAddress,
     /// The operator does not exist
-    InvalidOperator
+    InvalidOperator,
+    /// The permit signature does not match the owner's public key.
+    InvalidSignature,
+    /// The permit deadline has already passed.
+    PermitExpired,
+    /// The recipients and the amounts of a batch have different lengths.
+    BatchLengthMismatch,
+    /// A hold with the same id already exists.
+    HoldAlreadyExists,
+    /// There is no hold with the given id.
+    HoldNotFound,
+    /// The hold was already executed or released.
+    HoldNotOrdered,
+    /// The caller is not allowed to execute or release the hold.
+    InvalidHoldCaller,
+    /// The hold expired and can no longer be executed.
+    HoldExpired
 }
 
 const ERROR_INVALID_CONTEXT: u16 = u16::MAX;
@@ -34,6 +50,14 @@ const ERROR_INSUFFICIENT_ALLOWANCE: u16 = u16::MAX - 2;
 const ERROR_OVERFLOW: u16 = u16::MAX - 3;
 const ERROR_ADDRESS_UNKNOWN: u16 = u16::MAX - 4;
 const ERROR_OPERATOR_NOT_FOUND: u16 = u16::MAX - 5;
+const ERROR_INVALID_SIGNATURE: u16 = u16::MAX - 6;
+const ERROR_PERMIT_EXPIRED: u16 = u16::MAX - 7;
+const ERROR_BATCH_LENGTH_MISMATCH: u16 = u16::MAX - 8;
+const ERROR_HOLD_ALREADY_EXISTS: u16 = u16::MAX - 9;
+const ERROR_HOLD_NOT_FOUND: u16 = u16::MAX - 10;
+const ERROR_HOLD_NOT_ORDERED: u16 = u16::MAX - 11;
+const ERROR_INVALID_HOLD_CALLER: u16 = u16::MAX - 12;
+const ERROR_HOLD_EXPIRED: u16 = u16::MAX - 13;
 
 impl From<Error> for ApiError {
     fn from(error: Error) -> Self {
@@ -44,7 +68,15 @@ impl From<Error> for ApiError {
             Error::Overflow => ERROR_OVERFLOW,
             Error::User(user_error) => user_error,
             Error::InvalidAddress => ERROR_ADDRESS_UNKNOWN,
-            Error::InvalidOperator => ERROR_OPERATOR_NOT_FOUND
+            Error::InvalidOperator => ERROR_OPERATOR_NOT_FOUND,
+            Error::InvalidSignature => ERROR_INVALID_SIGNATURE,
+            Error::PermitExpired => ERROR_PERMIT_EXPIRED,
+            Error::BatchLengthMismatch => ERROR_BATCH_LENGTH_MISMATCH,
+            Error::HoldAlreadyExists => ERROR_HOLD_ALREADY_EXISTS,
+            Error::HoldNotFound => ERROR_HOLD_NOT_FOUND,
+            Error::HoldNotOrdered => ERROR_HOLD_NOT_ORDERED,
+            Error::InvalidHoldCaller => ERROR_INVALID_HOLD_CALLER,
+            Error::HoldExpired => ERROR_HOLD_EXPIRED
         };
         ApiError::User(user_error)
     }
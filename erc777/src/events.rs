@@ -0,0 +1,75 @@
+//! Implementation of the events log of the token.
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{bytesrepr::Bytes, Key, URef, U256};
+
+use crate::{
+    constants::{EVENTS_COUNT_KEY_NAME, EVENTS_KEY_NAME},
+    detail,
+    error::Error,
+    Address,
+};
+
+/// Events recorded by the token.
+pub(crate) enum ERC777Event {
+    /// `operator` moved `amount` tokens from `from` to `to`.
+    Sent {
+        operator: Address,
+        from: Address,
+        to: Address,
+        amount: U256,
+        data: Bytes,
+        operator_data: Bytes,
+    },
+}
+
+impl ERC777Event {
+    /// Converts the event into the map stored in the events dictionary.
+    fn to_map(&self) -> BTreeMap<String, String> {
+        let mut map = BTreeMap::new();
+        match self {
+            ERC777Event::Sent {
+                operator,
+                from,
+                to,
+                amount,
+                data,
+                operator_data,
+            } => {
+                map.insert("event_type".to_string(), "Sent".to_string());
+                map.insert("operator".to_string(), Key::from(*operator).to_formatted_string());
+                map.insert("from".to_string(), Key::from(*from).to_formatted_string());
+                map.insert("to".to_string(), Key::from(*to).to_formatted_string());
+                map.insert("amount".to_string(), amount.to_string());
+                map.insert("data".to_string(), hex::encode(data.to_vec()));
+                map.insert("operator_data".to_string(), hex::encode(operator_data.to_vec()));
+            }
+        }
+        map
+    }
+}
+
+#[inline]
+pub(crate) fn events_uref() -> URef {
+    detail::get_uref(EVENTS_KEY_NAME)
+}
+
+#[inline]
+pub(crate) fn events_count_uref() -> URef {
+    detail::get_uref(EVENTS_COUNT_KEY_NAME)
+}
+
+/// Appends `event` to the events dictionary under the next index.
+pub(crate) fn emit(events_uref: URef, events_count_uref: URef, event: ERC777Event) {
+    let index: u64 = storage::read(events_count_uref)
+        .unwrap_or_revert()
+        .unwrap_or_default();
+
+    storage::dictionary_put(events_uref, &index.to_string(), event.to_map());
+    let next_index = index.checked_add(1).unwrap_or_revert_with(Error::Overflow);
+    storage::write(events_count_uref, next_index);
+}
@@ -19,6 +19,13 @@ pub(crate) fn set_registry(registry_uref: URef, contract_hash: ContractHash) {
     storage::dictionary_put(registry_uref, REGISTRY_CONTRACT_NAME, contract_hash);
 }
 
+/// Returns whether the token was installed with an erc1820 registry, without one no hook is called.
+pub(crate) fn has_registry(registry_uref: URef) -> bool {
+    storage::dictionary_get::<ContractHash>(registry_uref, REGISTRY_CONTRACT_NAME)
+        .unwrap_or_default()
+        .is_some()
+}
+
 pub(crate) fn get_interface(registry_uref: URef, account: Address, i_hash: Bytes) -> Key {
 
     //let hash_contract = ContractHash::from_formatted_str(HASH_ERC1820_REGISTRY).unwrap();
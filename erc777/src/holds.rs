@@ -0,0 +1,180 @@
+//! Implementation of holds, i.e. tokens reserved for a future transfer.
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, URef, U256,
+};
+
+use crate::{
+    constants::{HELD_BALANCES_KEY_NAME, HOLDS_KEY_NAME},
+    detail, Address,
+};
+
+/// The state of a [`Hold`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum HoldStatus {
+    /// The tokens are reserved and can be executed or released.
+    Ordered,
+    /// The tokens were transferred to the recipient.
+    Executed,
+    /// The tokens were given back to the payer.
+    Released,
+}
+
+impl HoldStatus {
+    fn tag(&self) -> u8 {
+        match self {
+            HoldStatus::Ordered => 0,
+            HoldStatus::Executed => 1,
+            HoldStatus::Released => 2,
+        }
+    }
+}
+
+impl ToBytes for HoldStatus {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        self.tag().to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.tag().serialized_length()
+    }
+}
+
+impl FromBytes for HoldStatus {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        let status = match tag {
+            0 => HoldStatus::Ordered,
+            1 => HoldStatus::Executed,
+            2 => HoldStatus::Released,
+            _ => return Err(bytesrepr::Error::Formatting),
+        };
+        Ok((status, remainder))
+    }
+}
+
+/// Tokens of `payer` reserved to be transferred to `recipient` once `notary` executes the hold.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Hold {
+    /// Owner of the reserved tokens.
+    pub payer: Address,
+    /// Account receiving the tokens when the hold is executed.
+    pub recipient: Address,
+    /// Account allowed to execute or release the hold.
+    pub notary: Address,
+    /// Amount of reserved tokens.
+    pub amount: U256,
+    /// Block time in milliseconds after which the hold can no longer be executed, or 0 if the
+    /// hold never expires.
+    pub expiration: u64,
+    /// Current state of the hold.
+    pub status: HoldStatus,
+}
+
+impl Hold {
+    /// Returns `true` if the hold has an expiration and `now` is past it.
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expiration != 0 && now > self.expiration
+    }
+}
+
+impl CLTyped for Hold {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Hold {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.payer.to_bytes()?);
+        result.append(&mut self.recipient.to_bytes()?);
+        result.append(&mut self.notary.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.expiration.to_bytes()?);
+        result.append(&mut self.status.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.payer.serialized_length()
+            + self.recipient.serialized_length()
+            + self.notary.serialized_length()
+            + self.amount.serialized_length()
+            + self.expiration.serialized_length()
+            + self.status.serialized_length()
+    }
+}
+
+impl FromBytes for Hold {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (payer, remainder) = Address::from_bytes(bytes)?;
+        let (recipient, remainder) = Address::from_bytes(remainder)?;
+        let (notary, remainder) = Address::from_bytes(remainder)?;
+        let (amount, remainder) = U256::from_bytes(remainder)?;
+        let (expiration, remainder) = u64::from_bytes(remainder)?;
+        let (status, remainder) = HoldStatus::from_bytes(remainder)?;
+        let hold = Hold {
+            payer,
+            recipient,
+            notary,
+            amount,
+            expiration,
+            status,
+        };
+        Ok((hold, remainder))
+    }
+}
+
+#[inline]
+pub(crate) fn holds_uref() -> URef {
+    detail::get_uref(HOLDS_KEY_NAME)
+}
+
+#[inline]
+pub(crate) fn held_balances_uref() -> URef {
+    detail::get_uref(HELD_BALANCES_KEY_NAME)
+}
+
+/// Creates a dictionary item key for a hold id.
+fn make_hold_item_key(hold_id: &str) -> String {
+    let key_bytes = runtime::blake2b(hold_id.as_bytes());
+    hex::encode(&key_bytes)
+}
+
+/// Creates a dictionary item key for an owner.
+#[inline]
+fn make_owner_item_key(owner: Address) -> String {
+    let preimage = owner.to_bytes().unwrap_or_revert();
+    base64::encode(&preimage)
+}
+
+/// Reads the hold stored under `hold_id`, if any.
+pub(crate) fn read_hold_from(holds_uref: URef, hold_id: &str) -> Option<Hold> {
+    storage::dictionary_get(holds_uref, &make_hold_item_key(hold_id)).unwrap_or_revert()
+}
+
+/// Writes `hold` under `hold_id`.
+pub(crate) fn write_hold_to(holds_uref: URef, hold_id: &str, hold: Hold) {
+    storage::dictionary_put(holds_uref, &make_hold_item_key(hold_id), hold);
+}
+
+/// Reads the amount of `owner`'s tokens currently on hold.
+///
+/// If a given account has nothing on hold, then a 0 is returned.
+pub(crate) fn read_held_balance_from(held_balances_uref: URef, owner: Address) -> U256 {
+    storage::dictionary_get(held_balances_uref, &make_owner_item_key(owner))
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Writes the amount of `owner`'s tokens currently on hold.
+pub(crate) fn write_held_balance_to(held_balances_uref: URef, owner: Address, amount: U256) {
+    storage::dictionary_put(held_balances_uref, &make_owner_item_key(owner), amount);
+}
@@ -25,6 +25,11 @@ mod error;
 mod total_supply;
 mod operators;
 mod external_contracts;
+mod nonces;
+mod permits;
+mod events;
+mod holds;
+mod macros;
 
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
@@ -37,17 +42,22 @@ use casper_contract::{
 };
 use casper_types::{
     {contracts::NamedKeys, EntryPoints, Key, URef, U256},
-    ContractHash
+    ContractHash, PublicKey
 };
 use casper_types::bytesrepr::Bytes;
 
 pub use address::Address;
+pub use holds::{Hold, HoldStatus};
 use constants::{
     ALLOWANCES_KEY_NAME, BALANCES_KEY_NAME, DECIMALS_KEY_NAME, ERC20_TOKEN_CONTRACT_NAME,
     NAME_KEY_NAME, SYMBOL_KEY_NAME, TOTAL_SUPPLY_KEY_NAME,
-    DECIMALS_KEY_VALUE, GRANULARITY_KEY_NAME, OPERATORS_KEY_NAME, REGISTRY_CONTRACT_NAME
+    DECIMALS_KEY_VALUE, GRANULARITY_KEY_NAME, OPERATORS_KEY_NAME, REGISTRY_CONTRACT_NAME,
+    NONCES_KEY_NAME, EVENTS_KEY_NAME, EVENTS_COUNT_KEY_NAME, HOLDS_KEY_NAME, HELD_BALANCES_KEY_NAME
 };
+use events::ERC777Event;
 pub use error::Error;
+#[doc(hidden)]
+pub use macros::__private;
 
 /// Implementation of ERC20 standard functionality.
 #[derive(Default)]
@@ -56,17 +66,38 @@ pub struct ERC777 {
     allowances_uref: OnceCell<URef>,
     total_supply_uref: OnceCell<URef>,
     operators_uref: OnceCell<URef>,
-    registry_uref: OnceCell<URef>
+    registry_uref: OnceCell<URef>,
+    nonces_uref: OnceCell<URef>,
+    events_uref: OnceCell<URef>,
+    events_count_uref: OnceCell<URef>,
+    holds_uref: OnceCell<URef>,
+    held_balances_uref: OnceCell<URef>
 }
 
 impl ERC777 {
-    fn new(balances_uref: URef, allowances_uref: URef, total_supply_uref: URef, operators_uref: URef, registry_uref: URef) -> Self {
+    fn new(
+        balances_uref: URef,
+        allowances_uref: URef,
+        total_supply_uref: URef,
+        operators_uref: URef,
+        registry_uref: URef,
+        nonces_uref: URef,
+        events_uref: URef,
+        events_count_uref: URef,
+        holds_uref: URef,
+        held_balances_uref: URef
+    ) -> Self {
         Self {
             balances_uref: balances_uref.into(),
             allowances_uref: allowances_uref.into(),
             total_supply_uref: total_supply_uref.into(),
             operators_uref: operators_uref.into(),
-            registry_uref: registry_uref.into()
+            registry_uref: registry_uref.into(),
+            nonces_uref: nonces_uref.into(),
+            events_uref: events_uref.into(),
+            events_count_uref: events_count_uref.into(),
+            holds_uref: holds_uref.into(),
+            held_balances_uref: held_balances_uref.into()
         }
     }
 
@@ -115,12 +146,86 @@ impl ERC777 {
             .get_or_init(operators::operators_uref)
     }
 
+    fn nonces_uref(&self) -> URef {
+        *self.nonces_uref.get_or_init(nonces::nonces_uref)
+    }
+
+    fn events_uref(&self) -> URef {
+        *self.events_uref.get_or_init(events::events_uref)
+    }
+
+    fn events_count_uref(&self) -> URef {
+        *self.events_count_uref.get_or_init(events::events_count_uref)
+    }
+
+    fn emit(&self, event: ERC777Event) {
+        events::emit(self.events_uref(), self.events_count_uref(), event)
+    }
+
+    fn holds_uref(&self) -> URef {
+        *self.holds_uref.get_or_init(holds::holds_uref)
+    }
+
+    fn held_balances_uref(&self) -> URef {
+        *self.held_balances_uref.get_or_init(holds::held_balances_uref)
+    }
+
+    fn read_held_balance(&self, owner: Address) -> U256 {
+        holds::read_held_balance_from(self.held_balances_uref(), owner)
+    }
+
+    /// Fails with [`Error::InsufficientBalance`] if `amount` exceeds what `owner` has not put on
+    /// hold.
+    fn ensure_spendable(&self, owner: Address, amount: U256) -> Result<(), Error> {
+        if self.spendable_balance_of(owner) < amount {
+            return Err(Error::InsufficientBalance);
+        }
+        Ok(())
+    }
+
+    /// Gives the tokens reserved by `hold` back to the spendable balance of its payer.
+    fn unhold(&self, hold: &Hold) -> Result<(), Error> {
+        let new_held_balance = self
+            .read_held_balance(hold.payer)
+            .checked_sub(hold.amount)
+            .ok_or(Error::Overflow)?;
+        holds::write_held_balance_to(self.held_balances_uref(), hold.payer, new_held_balance);
+        Ok(())
+    }
+
+    /// Checks the deadline and the signature of a permit and consumes the owner's nonce.
+    fn use_permit<F>(
+        &mut self,
+        owner_public_key: &PublicKey,
+        deadline: u64,
+        signature: &Bytes,
+        make_digest: F
+    ) -> Result<Address, Error>
+    where
+        F: FnOnce(ContractHash, Address, u64) -> [u8; 32],
+    {
+        permits::check_deadline(deadline)?;
+
+        let owner = Address::from(owner_public_key.to_account_hash());
+        let nonce = nonces::read_nonce_from(self.nonces_uref(), owner);
+        let digest = make_digest(detail::get_self_contract_hash()?, owner, nonce);
+        permits::verify_signature(owner_public_key, &digest, signature)?;
+
+        let next_nonce = nonce.checked_add(1).ok_or(Error::Overflow)?;
+        nonces::write_nonce_to(self.nonces_uref(), owner, next_nonce);
+        Ok(owner)
+    }
+
+    /// Transfers `amount` of tokens from `sender` to `recipient` on behalf of `operator`, after
+    /// notifying the `ERC777TokensSender` hook of `sender`.
     fn transfer_balance(
         &mut self,
+        operator: Address,
         sender: Address,
         recipient: Address,
         amount: U256,
     ) -> Result<(), Error> {
+        balances::notify_transfer(self.registry_uref(), operator, sender, recipient, amount);
         balances::transfer_balance(self.balances_uref(), sender, recipient, amount)
     }
 
@@ -179,7 +284,8 @@ impl ERC777 {
     /// Transfers `amount` of tokens from the direct caller to `recipient`.
     pub fn transfer(&mut self, recipient: Address, amount: U256) -> Result<(), Error> {
         let sender = detail::get_immediate_caller_address()?;
-        self.transfer_balance(sender, recipient, amount)
+        self.ensure_spendable(sender, amount)?;
+        self.transfer_balance(sender, sender, recipient, amount)
     }
 
     /// Transfers `amount` of tokens from `owner` to `recipient` if the direct caller has been
@@ -198,7 +304,8 @@ impl ERC777 {
         let new_spender_allowance = spender_allowance
             .checked_sub(amount)
             .ok_or(Error::InsufficientAllowance)?;
-        self.transfer_balance(owner, recipient, amount)?;
+        self.ensure_spendable(owner, amount)?;
+        self.transfer_balance(spender, owner, recipient, amount)?;
         self.write_allowance(owner, spender, new_spender_allowance);
         Ok(())
     }
@@ -215,6 +322,59 @@ impl ERC777 {
         self.read_allowance(owner, spender)
     }
 
+    /// Returns the nonce `owner` has to sign in its next permit.
+    pub fn nonce_of(&self, owner: Address) -> u64 {
+        nonces::read_nonce_from(self.nonces_uref(), owner)
+    }
+
+    /// Allows `spender` to transfer up to `amount` of the owner's tokens using an off-chain
+    /// signature, so the owner does not need to send a deploy of its own.
+    ///
+    /// `signature` is the raw 64 bytes Ed25519 or secp256k1 signature made by `owner_public_key`
+    /// over the digest of the token contract hash, the owner, `spender`, `amount`, the owner's
+    /// current nonce and `deadline`. The nonce is incremented so a permit can be used only once.
+    ///
+    /// A secp256k1 signature is checked by `k256`, which hashes the digest again with SHA-256, so
+    /// the owner signs the 32 bytes digest as a message and not as a prehash.
+    pub fn permit(
+        &mut self,
+        owner_public_key: PublicKey,
+        spender: Address,
+        amount: U256,
+        deadline: u64,
+        signature: Bytes
+    ) -> Result<(), Error> {
+        let owner = self.use_permit(
+            &owner_public_key,
+            deadline,
+            &signature,
+            |token, owner, nonce| permits::permit_digest(token, owner, spender, amount, nonce, deadline)
+        )?;
+        self.write_allowance(owner, spender, amount);
+        Ok(())
+    }
+
+    /// Grants permission to an ´operator´ to send and burn tokens on behalf of the owner using an
+    /// off-chain signature.
+    ///
+    /// The signed digest covers the token contract hash, the owner, `operator`, the owner's current
+    /// nonce and `deadline`. As with [`ERC777::authorize_operator`], `operator` has to be an account.
+    pub fn permit_operator(
+        &mut self,
+        owner_public_key: PublicKey,
+        operator: Address,
+        deadline: u64,
+        signature: Bytes
+    ) -> Result<(), Error> {
+        let owner = self.use_permit(
+            &owner_public_key,
+            deadline,
+            &signature,
+            |token, owner, nonce| permits::permit_operator_digest(token, owner, operator, nonce, deadline)
+        )?;
+        self.add_operator(owner, operator)
+    }
+
     /// Mints `amount` new tokens and adds them to `owner`'s balance and to the token total supply.
     ///
     /// # Security
@@ -230,11 +390,13 @@ impl ERC777 {
     /// Allows burning a ´amount´ tokens straight of the caller's tokens.
     pub fn burn(&mut self, amount: U256, data: Bytes) -> Result<(), Error> {
         let owner: Address = detail::get_immediate_caller_address()?;
+        self.ensure_spendable(owner, amount)?;
 
         let new_total_supply: U256 = balances::burn(
             self.balances_uref(),
             self.registry_uref(),
             owner,
+            owner,
             amount,
             self.read_total_supply(),
             data,
@@ -255,11 +417,13 @@ impl ERC777 {
     /// This offers no security whatsoever, hence it is advised to NOT expose this method through a
     /// public entry point.
     pub fn _burn(&mut self, owner: Address, amount: U256) -> Result<(), Error> {
+        self.ensure_spendable(owner, amount)?;
 
         let new_total_supply: U256 = balances::burn(
             self.balances_uref(),
             self.registry_uref(),
             owner,
+            owner,
             amount,
             self.read_total_supply(),
             Bytes::default(),
@@ -272,21 +436,115 @@ impl ERC777 {
         Ok(())
     }
 
-    //todo event
     /// Allows sending a ´amount´ tokens to a ´recipient´ of the caller's tokens.
     pub fn send(&mut self, recipient: Address, amount: U256, data: Bytes) -> Result<(), Error> {
         let caller: Address = detail::get_immediate_caller_address()?;
+        self.ensure_spendable(caller, amount)?;
 
         balances::send_balance(
             self.balances_uref(),
             self.registry_uref(),
             caller,
+            caller,
             recipient,
             amount,
-            data,
+            data.clone(),
             Bytes::default(),
             true
-        )
+        )?;
+
+        self.emit(ERC777Event::Sent {
+            operator: caller,
+            from: caller,
+            to: recipient,
+            amount,
+            data,
+            operator_data: Bytes::default()
+        });
+        Ok(())
+    }
+
+    /// Allows sending `amounts[i]` tokens to `recipients[i]` of the caller's tokens in a single
+    /// atomic operation.
+    pub fn batch_send(
+        &mut self,
+        recipients: Vec<Address>,
+        amounts: Vec<U256>,
+        data: Bytes
+    ) -> Result<(), Error> {
+        let caller: Address = detail::get_immediate_caller_address()?;
+
+        self.send_batch(caller, caller, recipients, amounts, data, Bytes::default(), true)
+    }
+
+    /// Allows sending `amounts[i]` tokens to `recipients[i]` on behalf of the ´holder´ in a single
+    /// atomic operation.
+    pub fn operator_batch_send(
+        &mut self,
+        holder: Address,
+        recipients: Vec<Address>,
+        amounts: Vec<U256>,
+        data: Bytes,
+        operator_data: Bytes
+    ) -> Result<(), Error> {
+        let operator = Address::Account(runtime::get_caller());
+
+        let result = operators::check_if_exists(self.operators_uref(), holder, operator)?;
+
+        self.send_batch(operator, holder, recipients, amounts, data, operator_data, result)
+    }
+
+    fn send_batch(
+        &mut self,
+        operator: Address,
+        holder: Address,
+        recipients: Vec<Address>,
+        amounts: Vec<U256>,
+        data: Bytes,
+        operator_data: Bytes,
+        is_operator: bool
+    ) -> Result<(), Error> {
+        if recipients.len() != amounts.len() {
+            return Err(Error::BatchLengthMismatch);
+        }
+
+        // Entries sending to the holder itself or sending nothing move no tokens, so the hooks,
+        // the balances and the events all skip them.
+        let (recipients, amounts): (Vec<Address>, Vec<U256>) = recipients
+            .into_iter()
+            .zip(amounts.into_iter())
+            .filter(|(recipient, amount)| *recipient != holder && !amount.is_zero())
+            .unzip();
+
+        let total = amounts
+            .iter()
+            .try_fold(U256::zero(), |total, amount| total.checked_add(*amount))
+            .ok_or(Error::Overflow)?;
+        self.ensure_spendable(holder, total)?;
+
+        balances::batch_send_balance(
+            self.balances_uref(),
+            self.registry_uref(),
+            operator,
+            holder,
+            &recipients,
+            &amounts,
+            data.clone(),
+            operator_data.clone(),
+            is_operator
+        )?;
+
+        for (recipient, amount) in recipients.into_iter().zip(amounts.into_iter()) {
+            self.emit(ERC777Event::Sent {
+                operator,
+                from: holder,
+                to: recipient,
+                amount,
+                data: data.clone(),
+                operator_data: operator_data.clone()
+            });
+        }
+        Ok(())
     }
 
     /// Check up if the ´operator´ exists for this account.
@@ -300,9 +558,17 @@ impl ERC777 {
     /// Grant permission to an ´operator´ to send and burn tokens in behalf of the owner.
     pub fn authorize_operator(&mut self, operator: Address) -> Result<(), Error> {
         let caller: Address = detail::get_immediate_caller_address()?;
+        self.add_operator(caller, operator)
+    }
+
+    /// Adds `operator` to the operators of `owner`, operators are accounts only.
+    fn add_operator(&mut self, owner: Address, operator: Address) -> Result<(), Error> {
+        if operator.as_account_hash().is_none() {
+            return Err(Error::InvalidOperator);
+        }
         operators::concat_in_string(
             self.operators_uref(),
-            caller,
+            owner,
             operator
         );
         Ok(())
@@ -340,20 +606,32 @@ impl ERC777 {
         data: Bytes,
         operator_data: Bytes
     ) -> Result<(), Error> {
-        let caller = runtime::get_caller();
+        let operator = Address::Account(runtime::get_caller());
 
-        let result = operators::check_if_exists(self.operators_uref(), sender,Address::Account(caller))?;
+        let result = operators::check_if_exists(self.operators_uref(), sender, operator)?;
+        self.ensure_spendable(sender, amount)?;
 
         balances::send_balance(
             self.balances_uref(),
             self.registry_uref(),
+            operator,
             sender,
             recipient,
             amount,
-            data,
-            operator_data,
+            data.clone(),
+            operator_data.clone(),
             result
-        )
+        )?;
+
+        self.emit(ERC777Event::Sent {
+            operator,
+            from: sender,
+            to: recipient,
+            amount,
+            data,
+            operator_data
+        });
+        Ok(())
     }
 
     /// Allows burning a ´amount´ tokens in behalf of the tokens' owner.
@@ -364,23 +642,146 @@ impl ERC777 {
         data: Bytes,
         operator_data: Bytes
     ) -> Result<(), Error>{
-        let owner= runtime::get_caller();
+        let operator = Address::Account(runtime::get_caller());
+        self.ensure_spendable(account, amount)?;
 
         let new_total_supply: U256 = balances::burn(
             self.balances_uref(),
             self.registry_uref(),
+            operator,
             account,
             amount,
             self.read_total_supply(),
             data,
             operator_data,
-            operators::check_if_exists(self.operators_uref(), account,Address::Account(owner)).unwrap_or_default()
+            operators::check_if_exists(self.operators_uref(), account, operator).unwrap_or_default()
         ).unwrap_or_revert();
 
         self.write_total_supply(new_total_supply);
         Ok(())
     }
 
+    /// Reserves `amount` of the caller's tokens under `hold_id` so that `notary` can later transfer
+    /// them to `recipient`.
+    ///
+    /// Held tokens stay in the caller's balance but cannot be sent, transferred or burned until the
+    /// hold is released. An `expiration` of 0 means the hold never expires.
+    pub fn hold(
+        &mut self,
+        hold_id: String,
+        recipient: Address,
+        notary: Address,
+        amount: U256,
+        expiration: u64
+    ) -> Result<(), Error> {
+        let payer: Address = detail::get_immediate_caller_address()?;
+
+        if holds::read_hold_from(self.holds_uref(), &hold_id).is_some() {
+            return Err(Error::HoldAlreadyExists);
+        }
+        self.ensure_spendable(payer, amount)?;
+
+        let new_held_balance = self
+            .read_held_balance(payer)
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+        holds::write_held_balance_to(self.held_balances_uref(), payer, new_held_balance);
+
+        holds::write_hold_to(
+            self.holds_uref(),
+            &hold_id,
+            Hold {
+                payer,
+                recipient,
+                notary,
+                amount,
+                expiration,
+                status: HoldStatus::Ordered
+            }
+        );
+        Ok(())
+    }
+
+    /// Transfers the tokens reserved under `hold_id` to its recipient.
+    ///
+    /// Only the notary of the hold can execute it, and only before it expires.
+    pub fn execute_hold(&mut self, hold_id: String) -> Result<(), Error> {
+        let caller: Address = detail::get_immediate_caller_address()?;
+
+        let mut hold = holds::read_hold_from(self.holds_uref(), &hold_id).ok_or(Error::HoldNotFound)?;
+        if hold.status != HoldStatus::Ordered {
+            return Err(Error::HoldNotOrdered);
+        }
+        if caller != hold.notary {
+            return Err(Error::InvalidHoldCaller);
+        }
+        if hold.is_expired(runtime::get_blocktime().into()) {
+            return Err(Error::HoldExpired);
+        }
+
+        self.unhold(&hold)?;
+        hold.status = HoldStatus::Executed;
+        holds::write_hold_to(self.holds_uref(), &hold_id, hold.clone());
+
+        balances::send_balance(
+            self.balances_uref(),
+            self.registry_uref(),
+            caller,
+            hold.payer,
+            hold.recipient,
+            hold.amount,
+            Bytes::default(),
+            Bytes::default(),
+            true
+        )?;
+
+        self.emit(ERC777Event::Sent {
+            operator: hold.notary,
+            from: hold.payer,
+            to: hold.recipient,
+            amount: hold.amount,
+            data: Bytes::default(),
+            operator_data: Bytes::default()
+        });
+        Ok(())
+    }
+
+    /// Gives the tokens reserved under `hold_id` back to its payer.
+    ///
+    /// The notary and the recipient can release a hold at any time, the payer only once it has
+    /// expired.
+    pub fn release_hold(&mut self, hold_id: String) -> Result<(), Error> {
+        let caller: Address = detail::get_immediate_caller_address()?;
+
+        let mut hold = holds::read_hold_from(self.holds_uref(), &hold_id).ok_or(Error::HoldNotFound)?;
+        if hold.status != HoldStatus::Ordered {
+            return Err(Error::HoldNotOrdered);
+        }
+
+        let is_allowed = caller == hold.notary
+            || caller == hold.recipient
+            || (caller == hold.payer && hold.is_expired(runtime::get_blocktime().into()));
+        if !is_allowed {
+            return Err(Error::InvalidHoldCaller);
+        }
+
+        self.unhold(&hold)?;
+        hold.status = HoldStatus::Released;
+        holds::write_hold_to(self.holds_uref(), &hold_id, hold);
+        Ok(())
+    }
+
+    /// Returns the amount of `owner`'s tokens currently on hold.
+    pub fn balance_on_hold(&self, owner: Address) -> U256 {
+        self.read_held_balance(owner)
+    }
+
+    /// Returns the amount of `owner`'s tokens that are not on hold.
+    pub fn spendable_balance_of(&self, owner: Address) -> U256 {
+        self.read_balance(owner)
+            .saturating_sub(self.read_held_balance(owner))
+    }
+
     /// Installs the ERC20 contract with a custom set of entry points.
     ///
     /// # Warning
@@ -397,16 +798,55 @@ impl ERC777 {
         contract_key_name: &str,
         entry_points: EntryPoints,
         erc1820_hash: ContractHash
+    ) -> Result<ERC777, Error> {
+        ERC777::install_with_named_keys(
+            name,
+            symbol,
+            DECIMALS_KEY_VALUE,
+            granularity,
+            initial_supply,
+            contract_key_name,
+            None,
+            entry_points,
+            erc1820_hash,
+            NamedKeys::new(),
+        )
+    }
+
+    /// Installs the ERC20 contract like [`ERC777::install_custom`], with custom `decimals`.
+    ///
+    /// `named_keys` are added to the named keys of the installed contract, which lets contracts
+    /// built on top of [`ERC777`] keep their own state next to the token's.
+    ///
+    /// When `package_hash_key_name` is set, the hash of the contract package is stored under that
+    /// named key of the installer, so the contract can be given allowances on other tokens.
+    #[doc(hidden)]
+    pub fn install_with_named_keys(
+        name: String,
+        symbol: String,
+        decimals: u8,
+        granularity: U256,
+        initial_supply: U256,
+        contract_key_name: &str,
+        package_hash_key_name: Option<&str>,
+        entry_points: EntryPoints,
+        erc1820_hash: ContractHash,
+        mut named_keys: NamedKeys
     ) -> Result<ERC777, Error> {
         let balances_uref = storage::new_dictionary(BALANCES_KEY_NAME).unwrap_or_revert();
         let allowances_uref = storage::new_dictionary(ALLOWANCES_KEY_NAME).unwrap_or_revert();
         let operators_uref = storage::new_dictionary(OPERATORS_KEY_NAME).unwrap_or_revert();
         let registry_uref = storage::new_dictionary(REGISTRY_CONTRACT_NAME).unwrap_or_revert();
+        let nonces_uref = storage::new_dictionary(NONCES_KEY_NAME).unwrap_or_revert();
+        let events_uref = storage::new_dictionary(EVENTS_KEY_NAME).unwrap_or_revert();
+        let events_count_uref = storage::new_uref(0u64).into_read_write();
+        let holds_uref = storage::new_dictionary(HOLDS_KEY_NAME).unwrap_or_revert();
+        let held_balances_uref = storage::new_dictionary(HELD_BALANCES_KEY_NAME).unwrap_or_revert();
         // We need to hold on a RW access rights because tokens can be minted or burned.
         let total_supply_uref = storage::new_uref(initial_supply).into_read_write();
 
         let decimals_key = {
-            let decimals_uref = storage::new_uref(DECIMALS_KEY_VALUE).into_read();
+            let decimals_uref = storage::new_uref(decimals).into_read();
             Key::from(decimals_uref)
         };
 
@@ -415,8 +855,6 @@ impl ERC777 {
             Key::from(granularity_uref)
         };
 
-        let mut named_keys = NamedKeys::new();
-
         let name_key = {
             let name_uref = storage::new_uref(name).into_read();
             Key::from(name_uref)
@@ -462,6 +900,26 @@ impl ERC777 {
             Key::from(registry_uref)
         };
 
+        let nonces_dictionary_key = {
+            runtime::remove_key(NONCES_KEY_NAME);
+            Key::from(nonces_uref)
+        };
+
+        let events_dictionary_key = {
+            runtime::remove_key(EVENTS_KEY_NAME);
+            Key::from(events_uref)
+        };
+
+        let holds_dictionary_key = {
+            runtime::remove_key(HOLDS_KEY_NAME);
+            Key::from(holds_uref)
+        };
+
+        let held_balances_dictionary_key = {
+            runtime::remove_key(HELD_BALANCES_KEY_NAME);
+            Key::from(held_balances_uref)
+        };
+
         named_keys.insert(NAME_KEY_NAME.to_string(), name_key);
         named_keys.insert(SYMBOL_KEY_NAME.to_string(), symbol_key);
         named_keys.insert(DECIMALS_KEY_NAME.to_string(), decimals_key);
@@ -471,9 +929,18 @@ impl ERC777 {
         named_keys.insert(TOTAL_SUPPLY_KEY_NAME.to_string(), total_supply_key);
         named_keys.insert(OPERATORS_KEY_NAME.to_string(), operators_dictionary_key);
         named_keys.insert(REGISTRY_CONTRACT_NAME.to_string(), registry_key);
-
-        let (contract_hash, _version) =
-            storage::new_contract(entry_points, Some(named_keys), None, None);
+        named_keys.insert(NONCES_KEY_NAME.to_string(), nonces_dictionary_key);
+        named_keys.insert(EVENTS_KEY_NAME.to_string(), events_dictionary_key);
+        named_keys.insert(EVENTS_COUNT_KEY_NAME.to_string(), Key::from(events_count_uref));
+        named_keys.insert(HOLDS_KEY_NAME.to_string(), holds_dictionary_key);
+        named_keys.insert(HELD_BALANCES_KEY_NAME.to_string(), held_balances_dictionary_key);
+
+        let (contract_hash, _version) = storage::new_contract(
+            entry_points,
+            Some(named_keys),
+            package_hash_key_name.map(String::from),
+            None
+        );
 
         // Hash of the installed contract will be reachable through named keys.
         runtime::put_key(contract_key_name, Key::from(contract_hash));
@@ -483,7 +950,12 @@ impl ERC777 {
             allowances_uref,
             total_supply_uref,
             operators_uref,
-            registry_uref
+            registry_uref,
+            nonces_uref,
+            events_uref,
+            events_count_uref,
+            holds_uref,
+            held_balances_uref
         ))
     }
 }
@@ -0,0 +1,307 @@
+//! Macro exporting the standard ERC777 entry points from a contract.
+
+/// Defines an `extern "C"` function for every entry point of [`entry_points::default`].
+///
+/// Contracts built on top of [`ERC777`] invoke it once in their `main.rs`, next to their own entry
+/// points and `call` function, so that every token contract exposes the same standard interface:
+///
+/// ```ignore
+/// casper_erc777::erc777_entry_points!();
+/// ```
+///
+/// [`entry_points::default`]: crate::entry_points::default
+/// [`ERC777`]: crate::ERC777
+#[macro_export]
+macro_rules! erc777_entry_points {
+    () => {
+        #[no_mangle]
+        pub extern "C" fn name() {
+            let name = $crate::ERC777::default().name();
+            $crate::__private::ret(name);
+        }
+
+        #[no_mangle]
+        pub extern "C" fn symbol() {
+            let symbol = $crate::ERC777::default().symbol();
+            $crate::__private::ret(symbol);
+        }
+
+        #[no_mangle]
+        pub extern "C" fn decimals() {
+            let decimals = $crate::ERC777::default().decimals();
+            $crate::__private::ret(decimals);
+        }
+
+        #[no_mangle]
+        pub extern "C" fn total_supply() {
+            let total_supply = $crate::ERC777::default().total_supply();
+            $crate::__private::ret(total_supply);
+        }
+
+        #[no_mangle]
+        pub extern "C" fn balance_of() {
+            let address = $crate::__private::arg($crate::constants::ADDRESS_RUNTIME_ARG_NAME);
+            let balance = $crate::ERC777::default().balance_of(address);
+            $crate::__private::ret(balance);
+        }
+
+        #[no_mangle]
+        pub extern "C" fn transfer() {
+            let recipient = $crate::__private::arg($crate::constants::RECIPIENT_RUNTIME_ARG_NAME);
+            let amount = $crate::__private::arg($crate::constants::AMOUNT_RUNTIME_ARG_NAME);
+
+            $crate::__private::revert_on_error(
+                $crate::ERC777::default().transfer(recipient, amount)
+            );
+        }
+
+        #[no_mangle]
+        pub extern "C" fn approve() {
+            let spender = $crate::__private::arg($crate::constants::SPENDER_RUNTIME_ARG_NAME);
+            let amount = $crate::__private::arg($crate::constants::AMOUNT_RUNTIME_ARG_NAME);
+
+            $crate::__private::revert_on_error(
+                $crate::ERC777::default().approve(spender, amount)
+            );
+        }
+
+        #[no_mangle]
+        pub extern "C" fn allowance() {
+            let owner = $crate::__private::arg($crate::constants::OWNER_RUNTIME_ARG_NAME);
+            let spender = $crate::__private::arg($crate::constants::SPENDER_RUNTIME_ARG_NAME);
+            let val = $crate::ERC777::default().allowance(owner, spender);
+            $crate::__private::ret(val);
+        }
+
+        #[no_mangle]
+        pub extern "C" fn transfer_from() {
+            let owner = $crate::__private::arg($crate::constants::OWNER_RUNTIME_ARG_NAME);
+            let recipient = $crate::__private::arg($crate::constants::RECIPIENT_RUNTIME_ARG_NAME);
+            let amount = $crate::__private::arg($crate::constants::AMOUNT_RUNTIME_ARG_NAME);
+
+            $crate::__private::revert_on_error(
+                $crate::ERC777::default().transfer_from(owner, recipient, amount)
+            );
+        }
+
+        #[no_mangle]
+        pub extern "C" fn granularity() {
+            let granularity = $crate::ERC777::default().granularity();
+            $crate::__private::ret(granularity);
+        }
+
+        #[no_mangle]
+        pub extern "C" fn burn() {
+            let amount = $crate::__private::arg($crate::constants::AMOUNT_RUNTIME_ARG_NAME);
+            let data = $crate::__private::arg($crate::constants::DATA_RUNTIME_ARG_NAME);
+
+            $crate::__private::revert_on_error($crate::ERC777::default().burn(amount, data));
+        }
+
+        #[no_mangle]
+        pub extern "C" fn send() {
+            let recipient = $crate::__private::arg($crate::constants::RECIPIENT_RUNTIME_ARG_NAME);
+            let amount = $crate::__private::arg($crate::constants::AMOUNT_RUNTIME_ARG_NAME);
+            let data = $crate::__private::arg($crate::constants::DATA_RUNTIME_ARG_NAME);
+
+            $crate::__private::revert_on_error(
+                $crate::ERC777::default().send(recipient, amount, data)
+            );
+        }
+
+        #[no_mangle]
+        pub extern "C" fn is_operator_for() {
+            let operator = $crate::__private::arg($crate::constants::OPERATOR_RUNTIME_ARG_NAME);
+            let token = $crate::__private::arg($crate::constants::TOKEN_HOLDER_RUNTIME_ARG_NAME);
+            let is_operator = $crate::__private::revert_on_error(
+                $crate::ERC777::default().is_operator_for(operator, token)
+            );
+            $crate::__private::ret(is_operator);
+        }
+
+        #[no_mangle]
+        pub extern "C" fn authorize_operator() {
+            let operator = $crate::__private::arg($crate::constants::OPERATOR_RUNTIME_ARG_NAME);
+
+            $crate::__private::revert_on_error(
+                $crate::ERC777::default().authorize_operator(operator)
+            );
+        }
+
+        #[no_mangle]
+        pub extern "C" fn revoke_operator() {
+            let operator = $crate::__private::arg($crate::constants::OPERATOR_RUNTIME_ARG_NAME);
+
+            $crate::__private::revert_on_error(
+                $crate::ERC777::default().revoke_operator(operator)
+            );
+        }
+
+        #[no_mangle]
+        pub extern "C" fn default_operators() {
+            let operators = $crate::__private::revert_on_error(
+                $crate::ERC777::default().default_operators()
+            );
+            $crate::__private::ret(operators);
+        }
+
+        #[no_mangle]
+        pub extern "C" fn operator_send() {
+            let sender = $crate::__private::arg($crate::constants::SENDER_RUNTIME_ARG_NAME);
+            let recipient = $crate::__private::arg($crate::constants::RECIPIENT_RUNTIME_ARG_NAME);
+            let amount = $crate::__private::arg($crate::constants::AMOUNT_RUNTIME_ARG_NAME);
+            let data = $crate::__private::arg($crate::constants::DATA_RUNTIME_ARG_NAME);
+            let operator_data =
+                $crate::__private::arg($crate::constants::OPERATOR_DATA_RUNTIME_ARG_NAME);
+
+            $crate::__private::revert_on_error(
+                $crate::ERC777::default()
+                    .operator_send(sender, recipient, amount, data, operator_data)
+            );
+        }
+
+        #[no_mangle]
+        pub extern "C" fn operator_burn() {
+            let account = $crate::__private::arg($crate::constants::ACCOUNT_RUNTIME_ARG_NAME);
+            let amount = $crate::__private::arg($crate::constants::AMOUNT_RUNTIME_ARG_NAME);
+            let data = $crate::__private::arg($crate::constants::DATA_RUNTIME_ARG_NAME);
+            let operator_data =
+                $crate::__private::arg($crate::constants::OPERATOR_DATA_RUNTIME_ARG_NAME);
+
+            $crate::__private::revert_on_error(
+                $crate::ERC777::default().operator_burn(account, amount, data, operator_data)
+            );
+        }
+
+        #[no_mangle]
+        pub extern "C" fn batch_send() {
+            let recipients = $crate::__private::arg($crate::constants::RECIPIENTS_RUNTIME_ARG_NAME);
+            let amounts = $crate::__private::arg($crate::constants::AMOUNTS_RUNTIME_ARG_NAME);
+            let data = $crate::__private::arg($crate::constants::DATA_RUNTIME_ARG_NAME);
+
+            $crate::__private::revert_on_error(
+                $crate::ERC777::default().batch_send(recipients, amounts, data)
+            );
+        }
+
+        #[no_mangle]
+        pub extern "C" fn operator_batch_send() {
+            let holder = $crate::__private::arg($crate::constants::HOLDER_RUNTIME_ARG_NAME);
+            let recipients = $crate::__private::arg($crate::constants::RECIPIENTS_RUNTIME_ARG_NAME);
+            let amounts = $crate::__private::arg($crate::constants::AMOUNTS_RUNTIME_ARG_NAME);
+            let data = $crate::__private::arg($crate::constants::DATA_RUNTIME_ARG_NAME);
+            let operator_data =
+                $crate::__private::arg($crate::constants::OPERATOR_DATA_RUNTIME_ARG_NAME);
+
+            $crate::__private::revert_on_error(
+                $crate::ERC777::default()
+                    .operator_batch_send(holder, recipients, amounts, data, operator_data)
+            );
+        }
+
+        #[no_mangle]
+        pub extern "C" fn hold() {
+            let hold_id = $crate::__private::arg($crate::constants::HOLD_ID_RUNTIME_ARG_NAME);
+            let recipient = $crate::__private::arg($crate::constants::RECIPIENT_RUNTIME_ARG_NAME);
+            let notary = $crate::__private::arg($crate::constants::NOTARY_RUNTIME_ARG_NAME);
+            let amount = $crate::__private::arg($crate::constants::AMOUNT_RUNTIME_ARG_NAME);
+            let expiration = $crate::__private::arg($crate::constants::EXPIRATION_RUNTIME_ARG_NAME);
+
+            $crate::__private::revert_on_error(
+                $crate::ERC777::default().hold(hold_id, recipient, notary, amount, expiration)
+            );
+        }
+
+        #[no_mangle]
+        pub extern "C" fn execute_hold() {
+            let hold_id = $crate::__private::arg($crate::constants::HOLD_ID_RUNTIME_ARG_NAME);
+
+            $crate::__private::revert_on_error($crate::ERC777::default().execute_hold(hold_id));
+        }
+
+        #[no_mangle]
+        pub extern "C" fn release_hold() {
+            let hold_id = $crate::__private::arg($crate::constants::HOLD_ID_RUNTIME_ARG_NAME);
+
+            $crate::__private::revert_on_error($crate::ERC777::default().release_hold(hold_id));
+        }
+
+        #[no_mangle]
+        pub extern "C" fn balance_on_hold() {
+            let address = $crate::__private::arg($crate::constants::ADDRESS_RUNTIME_ARG_NAME);
+            let balance = $crate::ERC777::default().balance_on_hold(address);
+            $crate::__private::ret(balance);
+        }
+
+        #[no_mangle]
+        pub extern "C" fn spendable_balance_of() {
+            let address = $crate::__private::arg($crate::constants::ADDRESS_RUNTIME_ARG_NAME);
+            let balance = $crate::ERC777::default().spendable_balance_of(address);
+            $crate::__private::ret(balance);
+        }
+
+        #[no_mangle]
+        pub extern "C" fn permit() {
+            let owner_public_key =
+                $crate::__private::arg($crate::constants::OWNER_PUBLIC_KEY_RUNTIME_ARG_NAME);
+            let spender = $crate::__private::arg($crate::constants::SPENDER_RUNTIME_ARG_NAME);
+            let amount = $crate::__private::arg($crate::constants::AMOUNT_RUNTIME_ARG_NAME);
+            let deadline = $crate::__private::arg($crate::constants::DEADLINE_RUNTIME_ARG_NAME);
+            let signature = $crate::__private::arg($crate::constants::SIGNATURE_RUNTIME_ARG_NAME);
+
+            $crate::__private::revert_on_error(
+                $crate::ERC777::default()
+                    .permit(owner_public_key, spender, amount, deadline, signature)
+            );
+        }
+
+        #[no_mangle]
+        pub extern "C" fn permit_operator() {
+            let owner_public_key =
+                $crate::__private::arg($crate::constants::OWNER_PUBLIC_KEY_RUNTIME_ARG_NAME);
+            let operator = $crate::__private::arg($crate::constants::OPERATOR_RUNTIME_ARG_NAME);
+            let deadline = $crate::__private::arg($crate::constants::DEADLINE_RUNTIME_ARG_NAME);
+            let signature = $crate::__private::arg($crate::constants::SIGNATURE_RUNTIME_ARG_NAME);
+
+            $crate::__private::revert_on_error(
+                $crate::ERC777::default()
+                    .permit_operator(owner_public_key, operator, deadline, signature)
+            );
+        }
+
+        #[no_mangle]
+        pub extern "C" fn nonce_of() {
+            let owner = $crate::__private::arg($crate::constants::OWNER_RUNTIME_ARG_NAME);
+            let nonce = $crate::ERC777::default().nonce_of(owner);
+            $crate::__private::ret(nonce);
+        }
+    };
+}
+
+/// Helpers used by [`erc777_entry_points`], so that contracts invoking it don't need to import
+/// anything themselves.
+#[doc(hidden)]
+pub mod __private {
+    use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+    use casper_types::{
+        bytesrepr::{FromBytes, ToBytes},
+        CLTyped, CLValue,
+    };
+
+    use crate::Error;
+
+    /// Returns the named runtime argument `name` of the current call.
+    pub fn arg<T: CLTyped + FromBytes>(name: &str) -> T {
+        runtime::get_named_arg(name)
+    }
+
+    /// Returns `value` to the caller of the current entry point.
+    pub fn ret<T: CLTyped + ToBytes>(value: T) {
+        runtime::ret(CLValue::from_t(value).unwrap_or_revert())
+    }
+
+    /// Returns the value of `result`, or reverts with its error.
+    pub fn revert_on_error<T>(result: Result<T, Error>) -> T {
+        result.unwrap_or_revert()
+    }
+}
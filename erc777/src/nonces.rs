@@ -0,0 +1,35 @@
+//! Implementation of the per-owner nonces used by signed permits.
+use alloc::string::String;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{bytesrepr::ToBytes, URef};
+
+use crate::{constants::NONCES_KEY_NAME, detail, Address};
+
+#[inline]
+pub(crate) fn nonces_uref() -> URef {
+    detail::get_uref(NONCES_KEY_NAME)
+}
+
+/// Creates a dictionary item key for an owner.
+#[inline]
+fn make_dictionary_item_key(owner: Address) -> String {
+    let preimage = owner.to_bytes().unwrap_or_revert();
+    base64::encode(&preimage)
+}
+
+/// Reads the next unused nonce of `owner`.
+///
+/// If a given owner has never used a permit, then a 0 is returned.
+pub(crate) fn read_nonce_from(nonces_uref: URef, owner: Address) -> u64 {
+    let dictionary_item_key = make_dictionary_item_key(owner);
+    storage::dictionary_get(nonces_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Writes the next unused nonce of `owner`.
+pub(crate) fn write_nonce_to(nonces_uref: URef, owner: Address, nonce: u64) {
+    let dictionary_item_key = make_dictionary_item_key(owner);
+    storage::dictionary_put(nonces_uref, &dictionary_item_key, nonce);
+}
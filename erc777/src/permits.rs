@@ -0,0 +1,93 @@
+//! Implementation of signed off-chain approvals and operator grants.
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{bytesrepr::ToBytes, ContractHash, PublicKey, U256};
+use k256::ecdsa::signature::Verifier;
+
+use crate::{
+    constants::{PERMIT_DOMAIN_TAG, PERMIT_OPERATOR_DOMAIN_TAG},
+    error::Error,
+    Address,
+};
+
+/// Builds the digest an owner signs to approve `spender` for `amount`.
+///
+/// The preimage is `PERMIT_DOMAIN_TAG || token || owner || spender || amount || nonce || deadline`
+/// with every field serialized through `bytesrepr`, and the digest is its blake2b-256 hash.
+pub(crate) fn permit_digest(
+    token: ContractHash,
+    owner: Address,
+    spender: Address,
+    amount: U256,
+    nonce: u64,
+    deadline: u64,
+) -> [u8; 32] {
+    let mut preimage = Vec::new();
+    preimage.extend_from_slice(PERMIT_DOMAIN_TAG.as_bytes());
+    preimage.append(&mut token.to_bytes().unwrap_or_revert());
+    preimage.append(&mut owner.to_bytes().unwrap_or_revert());
+    preimage.append(&mut spender.to_bytes().unwrap_or_revert());
+    preimage.append(&mut amount.to_bytes().unwrap_or_revert());
+    preimage.append(&mut nonce.to_bytes().unwrap_or_revert());
+    preimage.append(&mut deadline.to_bytes().unwrap_or_revert());
+    runtime::blake2b(&preimage)
+}
+
+/// Builds the digest an owner signs to authorize `operator`.
+///
+/// The preimage is `PERMIT_OPERATOR_DOMAIN_TAG || token || owner || operator || nonce || deadline`.
+pub(crate) fn permit_operator_digest(
+    token: ContractHash,
+    owner: Address,
+    operator: Address,
+    nonce: u64,
+    deadline: u64,
+) -> [u8; 32] {
+    let mut preimage = Vec::new();
+    preimage.extend_from_slice(PERMIT_OPERATOR_DOMAIN_TAG.as_bytes());
+    preimage.append(&mut token.to_bytes().unwrap_or_revert());
+    preimage.append(&mut owner.to_bytes().unwrap_or_revert());
+    preimage.append(&mut operator.to_bytes().unwrap_or_revert());
+    preimage.append(&mut nonce.to_bytes().unwrap_or_revert());
+    preimage.append(&mut deadline.to_bytes().unwrap_or_revert());
+    runtime::blake2b(&preimage)
+}
+
+/// Fails with [`Error::PermitExpired`] once the block time has passed `deadline`.
+pub(crate) fn check_deadline(deadline: u64) -> Result<(), Error> {
+    let now: u64 = runtime::get_blocktime().into();
+    if now > deadline {
+        return Err(Error::PermitExpired);
+    }
+    Ok(())
+}
+
+/// Verifies a raw 64 bytes Ed25519 or secp256k1 `signature` of `digest` made by `public_key`.
+///
+/// `k256` hashes `digest` with SHA-256 before checking a secp256k1 signature, as its `Signer`
+/// does when signing.
+pub(crate) fn verify_signature(
+    public_key: &PublicKey,
+    digest: &[u8],
+    signature: &[u8],
+) -> Result<(), Error> {
+    match public_key {
+        PublicKey::Ed25519(public_key) => {
+            let signature = ed25519_dalek::Signature::try_from(signature)
+                .map_err(|_| Error::InvalidSignature)?;
+            public_key
+                .verify(digest, &signature)
+                .map_err(|_| Error::InvalidSignature)
+        }
+        PublicKey::Secp256k1(public_key) => {
+            let signature = k256::ecdsa::Signature::try_from(signature)
+                .map_err(|_| Error::InvalidSignature)?;
+            public_key
+                .verify(digest, &signature)
+                .map_err(|_| Error::InvalidSignature)
+        }
+        _ => Err(Error::InvalidSignature),
+    }
+}
@@ -6,12 +6,17 @@ compile_error!("target arch should be wasm32: compile with '--target wasm32-unkn
 
 extern crate alloc;
 
+use alloc::string::String;
+use alloc::vec::Vec;
+
 use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
 use casper_types::{CLValue, Key, bytesrepr::Bytes};
 use casper_erc1820::{
     constants::{
         ACCOUNT_RUNTIME_ARG_NAME, NEW_MANAGER_RUNTIME_ARG_NAME,
-        I_HASH_RUNTIME_ARG_NAME, IMPLEMENTER_RUNTIME_ARG_NAME
+        I_HASH_RUNTIME_ARG_NAME, IMPLEMENTER_RUNTIME_ARG_NAME, I_ID_RUNTIME_ARG_NAME,
+        I_NAME_RUNTIME_ARG_NAME, OFFSET_RUNTIME_ARG_NAME, LIMIT_RUNTIME_ARG_NAME,
+        IMPLEMENTERS_RUNTIME_ARG_NAME
     }, ERC1820,
 };
 
@@ -28,6 +33,28 @@ pub extern "C" fn set_interface_implementer() {
     ).unwrap_or_revert();
 }
 
+#[no_mangle]
+pub extern "C" fn set_interface_implementers() {
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let implementers: Vec<(Bytes, Key)> = runtime::get_named_arg(IMPLEMENTERS_RUNTIME_ARG_NAME);
+
+    ERC1820::default().set_interface_implementers(
+        account,
+        implementers
+    ).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn clear_interface_implementer() {
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let interface_hash: Bytes = runtime::get_named_arg(I_HASH_RUNTIME_ARG_NAME);
+
+    ERC1820::default().clear_interface_implementer(
+        account,
+        interface_hash
+    ).unwrap_or_revert();
+}
+
 #[no_mangle]
 pub extern "C" fn get_interface_implementer() {
     let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
@@ -63,6 +90,87 @@ pub extern "C" fn get_manager() {
     runtime::ret(CLValue::from_t(manager).unwrap_or_revert());
 }
 
+#[no_mangle]
+pub extern "C" fn get_interfaces() {
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+
+    let interfaces = ERC1820::default().get_interfaces(account);
+    runtime::ret(CLValue::from_t(interfaces).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_interface_count() {
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+
+    let count = ERC1820::default().get_interface_count(account);
+    runtime::ret(CLValue::from_t(count).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_accounts_for_implementer() {
+    let implementer: Key = runtime::get_named_arg(IMPLEMENTER_RUNTIME_ARG_NAME);
+    let offset: u64 = runtime::get_named_arg(OFFSET_RUNTIME_ARG_NAME);
+    let limit: u64 = runtime::get_named_arg(LIMIT_RUNTIME_ARG_NAME);
+
+    let accounts = ERC1820::default().get_accounts_for_implementer(implementer, offset, limit);
+    runtime::ret(CLValue::from_t(accounts).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn interface_hash() {
+    let interface_name: String = runtime::get_named_arg(I_NAME_RUNTIME_ARG_NAME);
+
+    let interface_hash = ERC1820::default().interface_hash(interface_name);
+    runtime::ret(CLValue::from_t(interface_hash).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn update_erc165_cache() {
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let interface_id: Bytes = runtime::get_named_arg(I_ID_RUNTIME_ARG_NAME);
+
+    ERC1820::default()
+        .update_erc165_cache(account, interface_id)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn implements_erc165_interface() {
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let interface_id: Bytes = runtime::get_named_arg(I_ID_RUNTIME_ARG_NAME);
+
+    let implements = ERC1820::default()
+        .implements_erc165_interface(account, interface_id)
+        .unwrap_or_revert();
+
+    runtime::ret(CLValue::from_t(implements).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn implements_erc165_interface_no_cache() {
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let interface_id: Bytes = runtime::get_named_arg(I_ID_RUNTIME_ARG_NAME);
+
+    let implements = ERC1820::default()
+        .implements_erc165_interface_no_cache(account, interface_id)
+        .unwrap_or_revert();
+
+    runtime::ret(CLValue::from_t(implements).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn declare_erc165() {
+    ERC1820::default().declare_erc165().unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn supports_interface() {
+    let interface_id: Bytes = runtime::get_named_arg(I_ID_RUNTIME_ARG_NAME);
+
+    let supports = ERC1820::default().supports_interface(interface_id);
+    runtime::ret(CLValue::from_t(supports).unwrap_or_revert());
+}
+
 #[no_mangle]
 fn call() {
     ERC1820::install().unwrap_or_revert();
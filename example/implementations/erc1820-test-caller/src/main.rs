@@ -0,0 +1,230 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::{string::{String, ToString}, vec, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes}, contracts::NamedKeys, runtime_args, ApiError, CLType, CLTyped,
+    ContractHash, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter,
+    RuntimeArgs, URef,
+};
+use casper_erc1820::constants::{
+    ACCOUNT_RUNTIME_ARG_NAME, GET_ACCOUNTS_FOR_IMPLEMENTER_ENTRY_POINT, GET_INTERFACES_ENTRY_POINT,
+    GET_INTERFACE_COUNT_ENTRY_POINT, IMPLEMENTER_RUNTIME_ARG_NAME, I_HASH_RUNTIME_ARG_NAME,
+    LIMIT_RUNTIME_ARG_NAME, NEW_MANAGER_RUNTIME_ARG_NAME, OFFSET_RUNTIME_ARG_NAME,
+    REGISTRY_RUNTIME_ARG_NAME, SET_INTERFACE_ENTRY_POINT, SET_MANAGER_ENTRY_POINT,
+};
+
+/// Name of the key the contract hash is stored under in the installer's named keys.
+const CONTRACT_KEY_NAME: &str = "erc1820_test_caller_contract";
+/// Name of the key the contract package hash is stored under in the installer's named keys.
+const PACKAGE_KEY_NAME: &str = "erc1820_test_caller_contract_package_hash";
+/// Entry point calling `set_manager` of another test caller.
+const FORWARD_SET_MANAGER_ENTRY_POINT: &str = "forward_set_manager";
+/// Name of the `contract` runtime argument.
+const CONTRACT_RUNTIME_ARG_NAME: &str = "contract";
+/// Entry point storing the result of `get_interfaces` of the registry.
+const STORE_INTERFACES_ENTRY_POINT: &str = "store_interfaces";
+/// Entry point storing the result of `get_interface_count` of the registry.
+const STORE_INTERFACE_COUNT_ENTRY_POINT: &str = "store_interface_count";
+/// Entry point storing the result of `get_accounts_for_implementer` of the registry.
+const STORE_ACCOUNTS_FOR_IMPLEMENTER_ENTRY_POINT: &str = "store_accounts_for_implementer";
+/// Name of the key the last stored result is kept under in the named keys of the contract.
+const RESULT_KEY_NAME: &str = "result";
+
+fn read_registry() -> ContractHash {
+    let registry_uref: URef = runtime::get_key(REGISTRY_RUNTIME_ARG_NAME)
+        .and_then(Key::into_uref)
+        .ok_or(ApiError::MissingKey)
+        .unwrap_or_revert();
+    storage::read(registry_uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Keeps `value` under the result key, where tests read the answers of the registry.
+fn store_result<T: CLTyped + ToBytes>(value: T) {
+    runtime::put_key(RESULT_KEY_NAME, Key::from(storage::new_uref(value)));
+}
+
+/// Calls `set_manager` of the registry, acting as this contract.
+#[no_mangle]
+pub extern "C" fn set_manager() {
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let new_manager: Key = runtime::get_named_arg(NEW_MANAGER_RUNTIME_ARG_NAME);
+
+    runtime::call_contract::<()>(
+        read_registry(),
+        SET_MANAGER_ENTRY_POINT,
+        runtime_args! {
+            ACCOUNT_RUNTIME_ARG_NAME => account,
+            NEW_MANAGER_RUNTIME_ARG_NAME => new_manager
+        },
+    );
+}
+
+/// Calls `set_interface_implementer` of the registry, acting as this contract.
+#[no_mangle]
+pub extern "C" fn set_interface_implementer() {
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let i_hash: Bytes = runtime::get_named_arg(I_HASH_RUNTIME_ARG_NAME);
+    let implementer: Key = runtime::get_named_arg(IMPLEMENTER_RUNTIME_ARG_NAME);
+
+    runtime::call_contract::<()>(
+        read_registry(),
+        SET_INTERFACE_ENTRY_POINT,
+        runtime_args! {
+            ACCOUNT_RUNTIME_ARG_NAME => account,
+            I_HASH_RUNTIME_ARG_NAME => i_hash,
+            IMPLEMENTER_RUNTIME_ARG_NAME => implementer
+        },
+    );
+}
+
+/// Calls `set_manager` of the test caller `contract`, so the registry is two contracts away.
+#[no_mangle]
+pub extern "C" fn forward_set_manager() {
+    let contract: ContractHash = runtime::get_named_arg(CONTRACT_RUNTIME_ARG_NAME);
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let new_manager: Key = runtime::get_named_arg(NEW_MANAGER_RUNTIME_ARG_NAME);
+
+    runtime::call_contract::<()>(
+        contract,
+        SET_MANAGER_ENTRY_POINT,
+        runtime_args! {
+            ACCOUNT_RUNTIME_ARG_NAME => account,
+            NEW_MANAGER_RUNTIME_ARG_NAME => new_manager
+        },
+    );
+}
+
+/// Stores the interfaces `account` has registered, as answered by the registry.
+#[no_mangle]
+pub extern "C" fn store_interfaces() {
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+
+    let interfaces: Vec<Bytes> = runtime::call_contract(
+        read_registry(),
+        GET_INTERFACES_ENTRY_POINT,
+        runtime_args! {
+            ACCOUNT_RUNTIME_ARG_NAME => account
+        },
+    );
+    store_result(interfaces);
+}
+
+/// Stores the number of interfaces `account` has registered, as answered by the registry.
+#[no_mangle]
+pub extern "C" fn store_interface_count() {
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+
+    let count: u64 = runtime::call_contract(
+        read_registry(),
+        GET_INTERFACE_COUNT_ENTRY_POINT,
+        runtime_args! {
+            ACCOUNT_RUNTIME_ARG_NAME => account
+        },
+    );
+    store_result(count);
+}
+
+/// Stores a page of the accounts `implementer` serves, as answered by the registry.
+#[no_mangle]
+pub extern "C" fn store_accounts_for_implementer() {
+    let implementer: Key = runtime::get_named_arg(IMPLEMENTER_RUNTIME_ARG_NAME);
+    let offset: u64 = runtime::get_named_arg(OFFSET_RUNTIME_ARG_NAME);
+    let limit: u64 = runtime::get_named_arg(LIMIT_RUNTIME_ARG_NAME);
+
+    let accounts: Vec<(Key, Bytes)> = runtime::call_contract(
+        read_registry(),
+        GET_ACCOUNTS_FOR_IMPLEMENTER_ENTRY_POINT,
+        runtime_args! {
+            IMPLEMENTER_RUNTIME_ARG_NAME => implementer,
+            OFFSET_RUNTIME_ARG_NAME => offset,
+            LIMIT_RUNTIME_ARG_NAME => limit
+        },
+    );
+    store_result(accounts);
+}
+
+#[no_mangle]
+fn call() {
+    let registry: ContractHash = runtime::get_named_arg(REGISTRY_RUNTIME_ARG_NAME);
+
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(SET_MANAGER_ENTRY_POINT),
+        vec![
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(NEW_MANAGER_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(SET_INTERFACE_ENTRY_POINT),
+        vec![
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(I_HASH_RUNTIME_ARG_NAME, Bytes::cl_type()),
+            Parameter::new(IMPLEMENTER_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(FORWARD_SET_MANAGER_ENTRY_POINT),
+        vec![
+            Parameter::new(CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(NEW_MANAGER_RUNTIME_ARG_NAME, Key::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(STORE_INTERFACES_ENTRY_POINT),
+        vec![Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(STORE_INTERFACE_COUNT_ENTRY_POINT),
+        vec![Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(STORE_ACCOUNTS_FOR_IMPLEMENTER_ENTRY_POINT),
+        vec![
+            Parameter::new(IMPLEMENTER_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(OFFSET_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(LIMIT_RUNTIME_ARG_NAME, u64::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(REGISTRY_RUNTIME_ARG_NAME.to_string(), Key::from(storage::new_uref(registry)));
+
+    let (contract_hash, _version) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(PACKAGE_KEY_NAME.to_string()),
+        None,
+    );
+    runtime::put_key(CONTRACT_KEY_NAME, Key::from(contract_hash));
+}
@@ -0,0 +1,96 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{CLValue, ContractHash, Key, U256, bytesrepr::Bytes};
+use casper_erc777_payment_splitter::{
+    constants::{
+        ACCOUNT_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, ERC1820_CONTRACT_RUNTIME_ARG_NAME,
+        FROM_RUNTIME_ARG_NAME, I_HASH_RUNTIME_ARG_NAME, OPERATOR_DATA_RUNTIME_ARG_NAME,
+        OPERATOR_RUNTIME_ARG_NAME, PAYEES_RUNTIME_ARG_NAME, PAYEE_RUNTIME_ARG_NAME,
+        SHARES_RUNTIME_ARG_NAME, TOKEN_RUNTIME_ARG_NAME, TO_RUNTIME_ARG_NAME,
+        USER_DATA_RUNTIME_ARG_NAME
+    },
+    PaymentSplitter,
+};
+
+#[no_mangle]
+pub extern "C" fn tokens_received() {
+    let operator: Key = runtime::get_named_arg(OPERATOR_RUNTIME_ARG_NAME);
+    let from: Key = runtime::get_named_arg(FROM_RUNTIME_ARG_NAME);
+    let to: Key = runtime::get_named_arg(TO_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let data: Bytes = runtime::get_named_arg(USER_DATA_RUNTIME_ARG_NAME);
+    let operator_data: Bytes = runtime::get_named_arg(OPERATOR_DATA_RUNTIME_ARG_NAME);
+
+    PaymentSplitter::default()
+        .tokens_received(operator, from, to, amount, data, operator_data)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn can_implement_interface_for_address() {
+    let i_hash: Bytes = runtime::get_named_arg(I_HASH_RUNTIME_ARG_NAME);
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+
+    let answer = PaymentSplitter::default().can_implement_interface_for_address(i_hash, account);
+    runtime::ret(CLValue::from_t(answer).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn register_self() {
+    PaymentSplitter::default().register_self().unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_payees() {
+    let payees: Vec<Key> = runtime::get_named_arg(PAYEES_RUNTIME_ARG_NAME);
+    let shares: Vec<u32> = runtime::get_named_arg(SHARES_RUNTIME_ARG_NAME);
+
+    PaymentSplitter::default().set_payees(payees, shares).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn get_payees() {
+    let payees = PaymentSplitter::default().get_payees();
+    runtime::ret(CLValue::from_t(payees).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn released() {
+    let token: Key = runtime::get_named_arg(TOKEN_RUNTIME_ARG_NAME);
+    let payee: Key = runtime::get_named_arg(PAYEE_RUNTIME_ARG_NAME);
+
+    let released = PaymentSplitter::default().released(token, payee);
+    runtime::ret(CLValue::from_t(released).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn add_token() {
+    let token: Key = runtime::get_named_arg(TOKEN_RUNTIME_ARG_NAME);
+
+    PaymentSplitter::default().add_token(token).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn remove_token() {
+    let token: Key = runtime::get_named_arg(TOKEN_RUNTIME_ARG_NAME);
+
+    PaymentSplitter::default().remove_token(token).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn call() {
+    let erc1820_contract: ContractHash = runtime::get_named_arg(ERC1820_CONTRACT_RUNTIME_ARG_NAME);
+    let payees: Vec<Key> = runtime::get_named_arg(PAYEES_RUNTIME_ARG_NAME);
+    let shares: Vec<u32> = runtime::get_named_arg(SHARES_RUNTIME_ARG_NAME);
+
+    PaymentSplitter::install(erc1820_contract, payees, shares).unwrap_or_revert();
+}
@@ -6,21 +6,28 @@ compile_error!("target arch should be wasm32: compile with '--target wasm32-unkn
 
 extern crate alloc;
 
+use alloc::{string::String, vec::Vec};
+
 use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
 use casper_types::{CLValue, ContractHash, Key, U256, bytesrepr::Bytes};
 use casper_erc777_recipient::{
     constants::{
         AMOUNT_RUNTIME_ARG_NAME, FROM_RUNTIME_ARG_NAME, USER_DATA_RUNTIME_ARG_NAME,
         OPERATOR_DATA_RUNTIME_ARG_NAME, OPERATOR_RUNTIME_ARG_NAME, ACCOUNT_RUNTIME_ARG_NAME,
-        ERC1820_CONTRACT_ARG_NAME, TO_RUNTIME_ARG_NAME, ERC777_CONTRACT_ARG_NAME
+        ERC1820_CONTRACT_ARG_NAME, TO_RUNTIME_ARG_NAME,
+        I_HASH_RUNTIME_ARG_NAME, TOKEN_RUNTIME_ARG_NAME, DELEGATE_RUNTIME_ARG_NAME, SENDERS_RUNTIME_ARG_NAME,
+        PREFIX_RUNTIME_ARG_NAME, INDEX_RUNTIME_ARG_NAME, OFFSET_RUNTIME_ARG_NAME, LIMIT_RUNTIME_ARG_NAME,
+        INVOICE_ID_RUNTIME_ARG_NAME, EXPIRY_RUNTIME_ARG_NAME, REJECT_OVERPAYMENT_RUNTIME_ARG_NAME,
+        REJECT_UNDERPAYMENT_RUNTIME_ARG_NAME, REJECT_EXPIRED_RUNTIME_ARG_NAME
     },
     ERC777Recipient,
 };
 
 #[no_mangle]
 pub extern "C" fn balance_of() {
-    let address = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
-    let balance = ERC777Recipient::default().balance_of(address);
+    let token: Key = runtime::get_named_arg(TOKEN_RUNTIME_ARG_NAME);
+    let address: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let balance = ERC777Recipient::default().balance_of(token, address);
     runtime::ret(CLValue::from_t(balance.unwrap_or_default()).unwrap_or_revert());
 }
 
@@ -40,6 +47,7 @@ pub extern "C" fn tokens_received() {
 
 #[no_mangle]
 pub extern "C" fn transfer() {
+    let token: Key = runtime::get_named_arg(TOKEN_RUNTIME_ARG_NAME);
     let from: Key = runtime::get_named_arg(FROM_RUNTIME_ARG_NAME);
     let to: Key = runtime::get_named_arg(TO_RUNTIME_ARG_NAME);
     let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
@@ -47,26 +55,165 @@ pub extern "C" fn transfer() {
     let operator_data: Bytes = runtime::get_named_arg(OPERATOR_DATA_RUNTIME_ARG_NAME);
 
     ERC777Recipient::default()
-        .transfer(from, to, amount, data, operator_data)
+        .transfer(token, from, to, amount, data, operator_data)
         .unwrap_or_revert();
 }
 
 #[no_mangle]
 pub extern "C" fn burn() {
+    let token: Key = runtime::get_named_arg(TOKEN_RUNTIME_ARG_NAME);
     let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
     let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
     let data: Bytes = runtime::get_named_arg(USER_DATA_RUNTIME_ARG_NAME);
     let operator_data: Bytes = runtime::get_named_arg(OPERATOR_DATA_RUNTIME_ARG_NAME);
 
     ERC777Recipient::default()
-        .burn(account, amount, data, operator_data)
+        .burn(token, account, amount, data, operator_data)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn can_implement_interface_for_address() {
+    let i_hash: Bytes = runtime::get_named_arg(I_HASH_RUNTIME_ARG_NAME);
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+
+    let answer = ERC777Recipient::default().can_implement_interface_for_address(i_hash, account);
+    runtime::ret(CLValue::from_t(answer).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn register_for() {
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+
+    ERC777Recipient::default().register_for(account).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn unregister_for() {
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+
+    ERC777Recipient::default().unregister_for(account).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn get_served_accounts() {
+    let accounts = ERC777Recipient::default().get_served_accounts();
+    runtime::ret(CLValue::from_t(accounts).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_sender_allowlist() {
+    let senders: Vec<Key> = runtime::get_named_arg(SENDERS_RUNTIME_ARG_NAME);
+
+    ERC777Recipient::default().set_sender_allowlist(senders).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_min_amount() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    ERC777Recipient::default().set_min_amount(amount).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_max_amount() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    ERC777Recipient::default().set_max_amount(amount).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_data_prefix() {
+    let prefix: Bytes = runtime::get_named_arg(PREFIX_RUNTIME_ARG_NAME);
+
+    ERC777Recipient::default().set_data_prefix(prefix).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn add_token() {
+    let token: Key = runtime::get_named_arg(TOKEN_RUNTIME_ARG_NAME);
+
+    ERC777Recipient::default().add_token(token).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn remove_token() {
+    let token: Key = runtime::get_named_arg(TOKEN_RUNTIME_ARG_NAME);
+
+    ERC777Recipient::default().remove_token(token).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn add_delegate() {
+    let delegate: Key = runtime::get_named_arg(DELEGATE_RUNTIME_ARG_NAME);
+
+    ERC777Recipient::default().add_delegate(delegate).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn remove_delegate() {
+    let delegate: Key = runtime::get_named_arg(DELEGATE_RUNTIME_ARG_NAME);
+
+    ERC777Recipient::default().remove_delegate(delegate).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn movement_count() {
+    let count = ERC777Recipient::default().movement_count();
+    runtime::ret(CLValue::from_t(count).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_movement() {
+    let index: u64 = runtime::get_named_arg(INDEX_RUNTIME_ARG_NAME);
+
+    let movement = ERC777Recipient::default().get_movement(index);
+    runtime::ret(CLValue::from_t(movement).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_movements() {
+    let offset: u64 = runtime::get_named_arg(OFFSET_RUNTIME_ARG_NAME);
+    let limit: u64 = runtime::get_named_arg(LIMIT_RUNTIME_ARG_NAME);
+
+    let movements = ERC777Recipient::default().get_movements(offset, limit);
+    runtime::ret(CLValue::from_t(movements).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn create_invoice() {
+    let invoice_id: String = runtime::get_named_arg(INVOICE_ID_RUNTIME_ARG_NAME);
+    let token: Key = runtime::get_named_arg(TOKEN_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let expiry: u64 = runtime::get_named_arg(EXPIRY_RUNTIME_ARG_NAME);
+
+    ERC777Recipient::default()
+        .create_invoice(invoice_id, token, amount, expiry)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn invoice_status() {
+    let invoice_id: String = runtime::get_named_arg(INVOICE_ID_RUNTIME_ARG_NAME);
+
+    let status = ERC777Recipient::default().invoice_status(invoice_id).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(status).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_invoice_rules() {
+    let reject_overpayment: bool = runtime::get_named_arg(REJECT_OVERPAYMENT_RUNTIME_ARG_NAME);
+    let reject_underpayment: bool = runtime::get_named_arg(REJECT_UNDERPAYMENT_RUNTIME_ARG_NAME);
+    let reject_expired: bool = runtime::get_named_arg(REJECT_EXPIRED_RUNTIME_ARG_NAME);
+
+    ERC777Recipient::default()
+        .set_invoice_rules(reject_overpayment, reject_underpayment, reject_expired)
         .unwrap_or_revert();
 }
 
 #[no_mangle]
 fn call() {
     let erc1820_contract: ContractHash = runtime::get_named_arg(ERC1820_CONTRACT_ARG_NAME);
-    let erc777_contract: ContractHash = runtime::get_named_arg(ERC777_CONTRACT_ARG_NAME);
 
-    ERC777Recipient::install(erc1820_contract, erc777_contract).unwrap_or_revert();
+    ERC777Recipient::install(erc1820_contract).unwrap_or_revert();
 }
@@ -6,12 +6,16 @@ compile_error!("target arch should be wasm32: compile with '--target wasm32-unkn
 
 extern crate alloc;
 
+use alloc::vec::Vec;
+
 use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
-use casper_types::{ContractHash, Key, U256, bytesrepr::Bytes};
+use casper_types::{CLValue, ContractHash, Key, U256, bytesrepr::Bytes};
 use casper_erc777_sender::{constants::{
     AMOUNT_RUNTIME_ARG_NAME, FROM_RUNTIME_ARG_NAME, USER_DATA_RUNTIME_ARG_NAME,
     OPERATOR_DATA_RUNTIME_ARG_NAME, OPERATOR_RUNTIME_ARG_NAME, TO_RUNTIME_ARG_NAME,
-    ERC1820_CONTRACT_ARG_NAME, ACCOUNT_RUNTIME_ARG_NAME, ERC777_CONTRACT_ARG_NAME
+    ERC1820_CONTRACT_ARG_NAME, ACCOUNT_RUNTIME_ARG_NAME,
+    I_HASH_RUNTIME_ARG_NAME, RECIPIENTS_RUNTIME_ARG_NAME, OPERATORS_RUNTIME_ARG_NAME, PREFIX_RUNTIME_ARG_NAME,
+    TOKEN_RUNTIME_ARG_NAME, DELEGATE_RUNTIME_ARG_NAME, INDEX_RUNTIME_ARG_NAME, OFFSET_RUNTIME_ARG_NAME, LIMIT_RUNTIME_ARG_NAME
 }, ERC777Sender};
 
 #[no_mangle]
@@ -30,6 +34,7 @@ pub extern "C" fn tokens_to_send() {
 
 #[no_mangle]
 pub extern "C" fn transfer() {
+    let token: Key = runtime::get_named_arg(TOKEN_RUNTIME_ARG_NAME);
     let from: Key = runtime::get_named_arg(FROM_RUNTIME_ARG_NAME);
     let to: Key = runtime::get_named_arg(TO_RUNTIME_ARG_NAME);
     let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
@@ -37,26 +42,170 @@ pub extern "C" fn transfer() {
     let operator_data: Bytes = runtime::get_named_arg(OPERATOR_DATA_RUNTIME_ARG_NAME);
 
     ERC777Sender::default()
-        .transfer(from, to, amount, data, operator_data)
+        .transfer(token, from, to, amount, data, operator_data)
         .unwrap_or_revert();
 }
 
 #[no_mangle]
 pub extern "C" fn burn() {
+    let token: Key = runtime::get_named_arg(TOKEN_RUNTIME_ARG_NAME);
     let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
     let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
     let data: Bytes = runtime::get_named_arg(USER_DATA_RUNTIME_ARG_NAME);
     let operator_data: Bytes = runtime::get_named_arg(OPERATOR_DATA_RUNTIME_ARG_NAME);
 
     ERC777Sender::default()
-        .burn(account, amount, data, operator_data)
+        .burn(token, account, amount, data, operator_data)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn can_implement_interface_for_address() {
+    let i_hash: Bytes = runtime::get_named_arg(I_HASH_RUNTIME_ARG_NAME);
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+
+    let answer = ERC777Sender::default().can_implement_interface_for_address(i_hash, account);
+    runtime::ret(CLValue::from_t(answer).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_recipient_allowlist() {
+    let recipients: Vec<Key> = runtime::get_named_arg(RECIPIENTS_RUNTIME_ARG_NAME);
+
+    ERC777Sender::default()
+        .set_recipient_allowlist(recipients)
         .unwrap_or_revert();
 }
 
+#[no_mangle]
+pub extern "C" fn set_recipient_blocklist() {
+    let recipients: Vec<Key> = runtime::get_named_arg(RECIPIENTS_RUNTIME_ARG_NAME);
+
+    ERC777Sender::default()
+        .set_recipient_blocklist(recipients)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_max_amount() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    ERC777Sender::default()
+        .set_max_amount(amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_data_prefix() {
+    let prefix: Bytes = runtime::get_named_arg(PREFIX_RUNTIME_ARG_NAME);
+
+    ERC777Sender::default()
+        .set_data_prefix(prefix)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_operator_allowlist() {
+    let operators: Vec<Key> = runtime::get_named_arg(OPERATORS_RUNTIME_ARG_NAME);
+
+    ERC777Sender::default()
+        .set_operator_allowlist(operators)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_daily_limit() {
+    let token: Key = runtime::get_named_arg(TOKEN_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    ERC777Sender::default()
+        .set_daily_limit(token, amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn remaining_allowance_today() {
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let token: Key = runtime::get_named_arg(TOKEN_RUNTIME_ARG_NAME);
+
+    let remaining = ERC777Sender::default().remaining_allowance_today(account, token);
+    runtime::ret(CLValue::from_t(remaining).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn register_for() {
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+
+    ERC777Sender::default().register_for(account).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn unregister_for() {
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+
+    ERC777Sender::default().unregister_for(account).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn get_served_accounts() {
+    let accounts = ERC777Sender::default().get_served_accounts();
+    runtime::ret(CLValue::from_t(accounts).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn add_token() {
+    let token: Key = runtime::get_named_arg(TOKEN_RUNTIME_ARG_NAME);
+
+    ERC777Sender::default().add_token(token).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn remove_token() {
+    let token: Key = runtime::get_named_arg(TOKEN_RUNTIME_ARG_NAME);
+
+    ERC777Sender::default().remove_token(token).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn add_delegate() {
+    let delegate: Key = runtime::get_named_arg(DELEGATE_RUNTIME_ARG_NAME);
+
+    ERC777Sender::default().add_delegate(delegate).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn remove_delegate() {
+    let delegate: Key = runtime::get_named_arg(DELEGATE_RUNTIME_ARG_NAME);
+
+    ERC777Sender::default().remove_delegate(delegate).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn movement_count() {
+    let count = ERC777Sender::default().movement_count();
+    runtime::ret(CLValue::from_t(count).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_movement() {
+    let index: u64 = runtime::get_named_arg(INDEX_RUNTIME_ARG_NAME);
+
+    let movement = ERC777Sender::default().get_movement(index);
+    runtime::ret(CLValue::from_t(movement).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_movements() {
+    let offset: u64 = runtime::get_named_arg(OFFSET_RUNTIME_ARG_NAME);
+    let limit: u64 = runtime::get_named_arg(LIMIT_RUNTIME_ARG_NAME);
+
+    let movements = ERC777Sender::default().get_movements(offset, limit);
+    runtime::ret(CLValue::from_t(movements).unwrap_or_revert());
+}
+
 #[no_mangle]
 fn call() {
     let erc1820_contract: ContractHash = runtime::get_named_arg(ERC1820_CONTRACT_ARG_NAME);
-    let erc777_contract: ContractHash = runtime::get_named_arg(ERC777_CONTRACT_ARG_NAME);
 
-    ERC777Sender::install(erc1820_contract, erc777_contract).unwrap_or_revert();
+    ERC777Sender::install(erc1820_contract).unwrap_or_revert();
 }
@@ -0,0 +1,82 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::{string::{String, ToString}, vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::Bytes, contracts::NamedKeys, ApiError, CLType, CLTyped, CLValue, EntryPoint,
+    EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter, URef, U256,
+};
+use casper_erc777::constants::{
+    AMOUNT_RUNTIME_ARG_NAME, FROM_RUNTIME_ARG_NAME, HASH_ERC1820_RECIPIENT,
+    OPERATOR_DATA_RUNTIME_ARG_NAME, OPERATOR_RUNTIME_ARG_NAME, TOKENS_RECEIVED_OF_EXTERNAL_ENTRY_POINT,
+    TO_RUNTIME_ARG_NAME, USER_DATA_RUNTIME_ARG_NAME,
+};
+use casper_erc1820::{
+    accept_magic, constants::I_HASH_RUNTIME_ARG_NAME,
+    entry_points::can_implement_interface_for_address as can_implement_entry_point, interface_hash,
+};
+
+/// Name of the key the contract hash is stored under in the installer's named keys.
+const CONTRACT_KEY_NAME: &str = "erc777_test_recipient_contract";
+/// Name of the key counting the calls to `tokens_received`.
+const RECEIVED_COUNT_KEY_NAME: &str = "received_count";
+
+/// Counts the movements the hook is notified of, so the tests can tell which hooks a token calls.
+#[no_mangle]
+pub extern "C" fn tokens_received() {
+    let count_uref: URef = runtime::get_key(RECEIVED_COUNT_KEY_NAME)
+        .and_then(Key::into_uref)
+        .ok_or(ApiError::MissingKey)
+        .unwrap_or_revert();
+    let count: u64 = storage::read(count_uref).unwrap_or_revert().unwrap_or_revert();
+    storage::write(count_uref, count + 1);
+}
+
+/// Accepts to be the `ERC777TokensRecipient` implementer of any account.
+#[no_mangle]
+pub extern "C" fn can_implement_interface_for_address() {
+    let i_hash: Bytes = runtime::get_named_arg(I_HASH_RUNTIME_ARG_NAME);
+
+    let answer = if i_hash.eq(&interface_hash(HASH_ERC1820_RECIPIENT)) {
+        accept_magic()
+    } else {
+        Bytes::default()
+    };
+    runtime::ret(CLValue::from_t(answer).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn call() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(TOKENS_RECEIVED_OF_EXTERNAL_ENTRY_POINT),
+        vec![
+            Parameter::new(OPERATOR_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(FROM_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(TO_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(USER_DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+            Parameter::new(OPERATOR_DATA_RUNTIME_ARG_NAME, Bytes::cl_type())
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(can_implement_entry_point());
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(RECEIVED_COUNT_KEY_NAME.to_string(), Key::from(storage::new_uref(0u64)));
+
+    let (contract_hash, _version) = storage::new_contract(entry_points, Some(named_keys), None, None);
+    runtime::put_key(CONTRACT_KEY_NAME, Key::from(contract_hash));
+}
@@ -0,0 +1,48 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{URef, U256};
+use casper_erc777::constants::{
+    AMOUNT_RUNTIME_ARG_NAME, GRANULARITY_RUNTIME_ARG_NAME, HASH_ERC1820_RUNTIME_ARG_NAME,
+    NAME_RUNTIME_ARG_NAME, SYMBOL_RUNTIME_ARG_NAME,
+};
+use casper_erc777_wrapped_cspr::{constants::PURSE_RUNTIME_ARG_NAME, WrappedCSPR};
+
+casper_erc777::erc777_entry_points!();
+
+#[no_mangle]
+pub extern "C" fn deposit() {
+    let purse: URef = runtime::get_named_arg(PURSE_RUNTIME_ARG_NAME);
+
+    WrappedCSPR::default().deposit(purse).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    WrappedCSPR::default().withdraw(amount).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn call() {
+    let name: String = runtime::get_named_arg(NAME_RUNTIME_ARG_NAME);
+    let symbol: String = runtime::get_named_arg(SYMBOL_RUNTIME_ARG_NAME);
+    let granularity = runtime::get_named_arg(GRANULARITY_RUNTIME_ARG_NAME);
+    let erc1820_hash = runtime::get_named_arg(HASH_ERC1820_RUNTIME_ARG_NAME);
+
+    let _token = WrappedCSPR::install(
+        name,
+        symbol,
+        granularity,
+        erc1820_hash
+    ).unwrap_or_revert();
+}
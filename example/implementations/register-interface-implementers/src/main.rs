@@ -0,0 +1,49 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::contract_api::runtime;
+use casper_types::{bytesrepr::Bytes, runtime_args, ApiError, ContractHash, Key, RuntimeArgs};
+use casper_erc1820::{
+    constants::{
+        ACCOUNT_RUNTIME_ARG_NAME, IMPLEMENTERS_RUNTIME_ARG_NAME, I_NAMES_RUNTIME_ARG_NAME,
+        REGISTRY_RUNTIME_ARG_NAME, SET_INTERFACES_ENTRY_POINT,
+    },
+    interface_hash,
+};
+
+/// Registers `implementers[i]` as the implementer of `interface_names[i]` for `account` in a
+/// single call to the `set_interface_implementers` entry point of the ERC1820 registry, so a
+/// wallet registers all its hooks in one deploy.
+#[no_mangle]
+fn call() {
+    let registry: ContractHash = runtime::get_named_arg(REGISTRY_RUNTIME_ARG_NAME);
+    let account: Key = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let interface_names: Vec<String> = runtime::get_named_arg(I_NAMES_RUNTIME_ARG_NAME);
+    let implementers: Vec<Key> = runtime::get_named_arg(IMPLEMENTERS_RUNTIME_ARG_NAME);
+
+    if interface_names.len() != implementers.len() {
+        runtime::revert(ApiError::InvalidArgument);
+    }
+
+    let registrations: Vec<(Bytes, Key)> = interface_names
+        .iter()
+        .map(|interface_name| interface_hash(interface_name))
+        .zip(implementers)
+        .collect();
+
+    runtime::call_contract::<()>(
+        registry,
+        SET_INTERFACES_ENTRY_POINT,
+        runtime_args! {
+            ACCOUNT_RUNTIME_ARG_NAME => account,
+            IMPLEMENTERS_RUNTIME_ARG_NAME => registrations
+        },
+    );
+}
@@ -0,0 +1,38 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use casper_contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{runtime_args, ContractHash, RuntimeArgs, U512};
+use casper_erc777_wrapped_cspr::constants::{
+    AMOUNT_RUNTIME_ARG_NAME, DEPOSIT_ENTRY_POINT_NAME, PURSE_RUNTIME_ARG_NAME,
+    WRAPPED_CSPR_CONTRACT_ARG_NAME,
+};
+
+/// Moves `amount` motes from the caller's main purse into a new purse and hands it over to the
+/// `deposit` entry point of the Wrapped CSPR contract, which mints the same amount of tokens.
+#[no_mangle]
+fn call() {
+    let wrapped_cspr_contract: ContractHash =
+        runtime::get_named_arg(WRAPPED_CSPR_CONTRACT_ARG_NAME);
+    let amount: U512 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    let purse = system::create_purse();
+    system::transfer_from_purse_to_purse(account::get_main_purse(), purse, amount, None)
+        .unwrap_or_revert();
+
+    runtime::call_contract::<()>(
+        wrapped_cspr_contract,
+        DEPOSIT_ENTRY_POINT_NAME,
+        runtime_args! {
+            PURSE_RUNTIME_ARG_NAME => purse
+        },
+    );
+}
@@ -5,9 +5,10 @@ mod test_erc1820;
 mod tests {
     use casper_types::{Key};
     use casper_types::account::AccountHash;
-    use casper_types::bytesrepr::{Bytes, ToBytes};
+    use casper_types::bytesrepr::Bytes;
 
     extern crate base64;
+    use casper_erc1820::constants::ERC165_INTERFACE_ID;
     use crate::test_erc1820::{Sender, TestERC1820};
 
     pub const HASH_ERC1820_SENDER: &str = "ERC777TokensSender";
@@ -22,14 +23,14 @@ mod tests {
 
         fixture.set_interface_implementer(
             Key::from(ali),
-            Bytes::from(tag_sender.clone().to_bytes().unwrap()),
+            TestERC1820::interface_hash(&tag_sender),
             Key::from(joe),
             Sender(ali)
         );
 
         let implementer = fixture.get_interface_implementer(
             Key::from(ali),
-            Bytes::from(tag_sender.clone().to_bytes().unwrap())
+            TestERC1820::interface_hash(&tag_sender)
         );
 
         assert_eq!(
@@ -47,14 +48,14 @@ mod tests {
 
         fixture.set_interface_implementer(
             Key::from(ali),
-            Bytes::from(tag_sender.clone().to_bytes().unwrap()),
+            TestERC1820::interface_hash(&tag_sender),
             Key::from(joe),
             Sender(ali)
         );
 
         let implementer = fixture.get_interface_implementer(
             Key::from(ali),
-            Bytes::from(tag_sender.clone().to_bytes().unwrap())
+            TestERC1820::interface_hash(&tag_sender)
         );
 
         assert_eq!(
@@ -74,14 +75,14 @@ mod tests {
 
         fixture.set_interface_implementer(
             Key::from(joe),
-            Bytes::from(tag_sender.clone().to_bytes().unwrap()),
+            TestERC1820::interface_hash(&tag_sender),
             Key::from(joe),
             Sender(ali)
         );
 
         let implementer = fixture.get_interface_implementer(
             Key::from(ali),
-            Bytes::from(tag_sender.clone().to_bytes().unwrap())
+            TestERC1820::interface_hash(&tag_sender)
         );
 
         assert_eq!(
@@ -101,14 +102,14 @@ mod tests {
 
         fixture.set_interface_implementer(
             Key::from(ali),
-            Bytes::from(tag_sender.clone().to_bytes().unwrap()),
+            TestERC1820::interface_hash(&tag_sender),
             Key::from(AccountHash::default()),
             Sender(ali)
         );
 
         let implementer = fixture.get_interface_implementer(
             Key::from(ali),
-            Bytes::from(tag_sender.clone().to_bytes().unwrap())
+            TestERC1820::interface_hash(&tag_sender)
         );
 
         assert_eq!(
@@ -118,7 +119,7 @@ mod tests {
     }
 
 
-    #[should_panic(expected = "ApiError::User(1001) [66537]")]
+    #[should_panic(expected = "ApiError::User(1005) [66541]")]
     #[test]
     fn should_not_register_an_implementer_with_same_caller_address() {
         let mut fixture = TestERC1820::install_contract();
@@ -128,14 +129,14 @@ mod tests {
 
         fixture.set_interface_implementer(
             Key::from(ali),
-            Bytes::from(tag_sender.clone().to_bytes().unwrap()),
+            TestERC1820::interface_hash(&tag_sender),
             Key::from(ali),
             Sender(ali)
         );
 
         let implementer = fixture.get_interface_implementer(
             Key::from(ali),
-            Bytes::from(tag_sender.clone().to_bytes().unwrap())
+            TestERC1820::interface_hash(&tag_sender)
         );
 
         assert_eq!(
@@ -163,7 +164,7 @@ mod tests {
         )
     }
 
-    #[should_panic(expected = "ApiError::User(999) [66535]")]
+    #[should_panic(expected = "ApiError::User(1000) [66536]")]
     #[test]
     fn should_not_set_manager() {
         let mut fixture = TestERC1820::install_contract();
@@ -183,6 +184,437 @@ mod tests {
             manager
         )
     }
+
+    #[test]
+    fn should_register_an_implementer_from_the_manager() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let bob = fixture.bob;
+        let joe = fixture.joe;
+        let tag_sender = HASH_ERC1820_SENDER.to_string();
+
+        fixture.set_manager(Key::from(ali), Key::from(bob), Sender(ali));
+        fixture.set_interface_implementer(
+            Key::from(ali),
+            TestERC1820::interface_hash(&tag_sender),
+            Key::from(joe),
+            Sender(bob)
+        );
+
+        assert_eq!(Some(Key::from(bob)), fixture.get_manager(Key::from(ali)));
+        assert_eq!(
+            Some(Key::from(joe)),
+            fixture.get_interface_implementer(
+                Key::from(ali),
+                TestERC1820::interface_hash(&tag_sender)
+            )
+        )
+    }
+
+    #[should_panic(expected = "ApiError::User(1000) [66536]")]
+    #[test]
+    fn should_not_register_an_implementer_from_a_previous_manager() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let joe = fixture.joe;
+        let tag_sender = HASH_ERC1820_SENDER.to_string();
+
+        fixture.set_manager(Key::from(ali), Key::from(joe), Sender(ali));
+        fixture.set_interface_implementer(
+            Key::from(ali),
+            TestERC1820::interface_hash(&tag_sender),
+            Key::from(joe),
+            Sender(ali)
+        );
+    }
+
+    #[test]
+    fn should_reset_manager_to_the_account() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let bob = fixture.bob;
+        let joe = fixture.joe;
+        let tag_sender = HASH_ERC1820_SENDER.to_string();
+
+        fixture.set_manager(Key::from(ali), Key::from(bob), Sender(ali));
+        fixture.set_manager(Key::from(ali), Key::from(ali), Sender(bob));
+        fixture.set_interface_implementer(
+            Key::from(ali),
+            TestERC1820::interface_hash(&tag_sender),
+            Key::from(joe),
+            Sender(ali)
+        );
+
+        assert_eq!(Some(Key::from(ali)), fixture.get_manager(Key::from(ali)));
+    }
+
+    #[test]
+    fn should_reset_a_zero_manager_to_the_account() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let joe = fixture.joe;
+        let tag_sender = HASH_ERC1820_SENDER.to_string();
+
+        fixture.set_manager(Key::from(ali), Key::Account(AccountHash::default()), Sender(ali));
+        assert_eq!(Some(Key::from(ali)), fixture.get_manager(Key::from(ali)));
+
+        fixture.set_interface_implementer(
+            Key::from(ali),
+            TestERC1820::interface_hash(&tag_sender),
+            Key::from(joe),
+            Sender(ali)
+        );
+        assert_eq!(
+            Some(Key::from(joe)),
+            fixture.get_interface_implementer(
+                Key::from(ali),
+                TestERC1820::interface_hash(&tag_sender)
+            )
+        )
+    }
+
+    #[test]
+    fn should_record_registry_events() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let bob = fixture.bob;
+        let joe = fixture.joe;
+        let i_hash = TestERC1820::interface_hash(HASH_ERC1820_SENDER);
+
+        fixture.set_manager(Key::from(ali), Key::from(bob), Sender(ali));
+        fixture.set_interface_implementer(Key::from(ali), i_hash.clone(), Key::from(joe), Sender(bob));
+
+        assert_eq!(fixture.events_count(), 2);
+
+        let manager_changed = fixture.event(0).unwrap();
+        assert_eq!(manager_changed["event_type"], "ManagerChanged");
+        assert_eq!(manager_changed["account"], Key::from(ali).to_formatted_string());
+        assert_eq!(manager_changed["new_manager"], Key::from(bob).to_formatted_string());
+
+        let implementer_set = fixture.event(1).unwrap();
+        assert_eq!(implementer_set["event_type"], "InterfaceImplementerSet");
+        assert_eq!(implementer_set["account"], Key::from(ali).to_formatted_string());
+        assert_eq!(implementer_set["interface_hash"], hex::encode(i_hash.to_vec()));
+        assert_eq!(implementer_set["implementer"], Key::from(joe).to_formatted_string());
+        assert_eq!(fixture.event(2), None);
+    }
+
+    #[test]
+    fn should_index_the_interfaces_of_an_account() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let bob = fixture.bob;
+        let joe = fixture.joe;
+        let sender_hash = TestERC1820::interface_hash(HASH_ERC1820_SENDER);
+        let recipient_hash = TestERC1820::interface_hash(HASH_ERC1820_RECIPIENT);
+
+        let (caller, _) = fixture.install_test_caller(Sender(ali));
+
+        fixture.set_interface_implementer(Key::from(ali), sender_hash.clone(), Key::from(joe), Sender(ali));
+        fixture.set_interface_implementer(Key::from(ali), recipient_hash.clone(), Key::from(joe), Sender(ali));
+        fixture.set_interface_implementer(Key::from(ali), sender_hash.clone(), Key::from(bob), Sender(ali));
+
+        assert_eq!(
+            fixture.get_interfaces(caller, Key::from(ali), Sender(ali)),
+            vec![sender_hash.clone(), recipient_hash.clone()]
+        );
+        assert_eq!(fixture.get_interface_count(caller, Key::from(ali), Sender(ali)), 2);
+        assert_eq!(fixture.get_interfaces(caller, Key::from(joe), Sender(ali)), Vec::<Bytes>::new());
+        assert_eq!(fixture.get_interface_count(caller, Key::from(joe), Sender(ali)), 0);
+
+        // The last interface takes the place of the removed one.
+        let third_hash = TestERC1820::interface_hash("ThirdInterface");
+        fixture.set_interface_implementer(Key::from(ali), third_hash.clone(), Key::from(joe), Sender(ali));
+        fixture.clear_interface_implementer(Key::from(ali), sender_hash, Sender(ali));
+
+        assert_eq!(
+            fixture.get_interfaces(caller, Key::from(ali), Sender(ali)),
+            vec![third_hash, recipient_hash]
+        );
+        assert_eq!(fixture.get_interface_count(caller, Key::from(ali), Sender(ali)), 2);
+    }
+
+    #[test]
+    fn should_index_the_accounts_of_an_implementer() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let bob = fixture.bob;
+        let joe = fixture.joe;
+        let sender_hash = TestERC1820::interface_hash(HASH_ERC1820_SENDER);
+        let recipient_hash = TestERC1820::interface_hash(HASH_ERC1820_RECIPIENT);
+        let (caller, _) = fixture.install_test_caller(Sender(ali));
+
+        fixture.set_interface_implementer(Key::from(ali), sender_hash.clone(), Key::from(joe), Sender(ali));
+        fixture.set_interface_implementer(Key::from(ali), recipient_hash.clone(), Key::from(joe), Sender(ali));
+        fixture.set_interface_implementer(Key::from(bob), sender_hash.clone(), Key::from(joe), Sender(bob));
+
+        assert_eq!(
+            fixture.get_accounts_for_implementer(caller, Key::from(joe), 0, 10, Sender(ali)),
+            vec![
+                (Key::from(ali), sender_hash.clone()),
+                (Key::from(ali), recipient_hash.clone()),
+                (Key::from(bob), sender_hash.clone())
+            ]
+        );
+
+        // The last pair takes the place of the removed one.
+        fixture.set_interface_implementer(Key::from(ali), sender_hash.clone(), Key::from(bob), Sender(ali));
+
+        assert_eq!(
+            fixture.get_accounts_for_implementer(caller, Key::from(joe), 0, 10, Sender(ali)),
+            vec![(Key::from(bob), sender_hash.clone()), (Key::from(ali), recipient_hash)]
+        );
+        assert_eq!(
+            fixture.get_accounts_for_implementer(caller, Key::from(bob), 0, 10, Sender(ali)),
+            vec![(Key::from(ali), sender_hash)]
+        );
+    }
+
+    #[test]
+    fn should_page_the_accounts_of_an_implementer() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let bob = fixture.bob;
+        let joe = fixture.joe;
+        let sender_hash = TestERC1820::interface_hash(HASH_ERC1820_SENDER);
+        let recipient_hash = TestERC1820::interface_hash(HASH_ERC1820_RECIPIENT);
+        let (caller, _) = fixture.install_test_caller(Sender(ali));
+
+        fixture.set_interface_implementer(Key::from(ali), sender_hash.clone(), Key::from(joe), Sender(ali));
+        fixture.set_interface_implementer(Key::from(ali), recipient_hash.clone(), Key::from(joe), Sender(ali));
+        fixture.set_interface_implementer(Key::from(bob), sender_hash.clone(), Key::from(joe), Sender(bob));
+
+        assert_eq!(
+            fixture.get_accounts_for_implementer(caller, Key::from(joe), 1, 1, Sender(ali)),
+            vec![(Key::from(ali), recipient_hash.clone())]
+        );
+        assert_eq!(
+            fixture.get_accounts_for_implementer(caller, Key::from(joe), 1, 5, Sender(ali)),
+            vec![(Key::from(ali), recipient_hash), (Key::from(bob), sender_hash)]
+        );
+        assert_eq!(
+            fixture.get_accounts_for_implementer(caller, Key::from(joe), 3, 2, Sender(ali)),
+            Vec::<(Key, Bytes)>::new()
+        );
+        assert_eq!(
+            fixture.get_accounts_for_implementer(caller, Key::from(joe), 0, 0, Sender(ali)),
+            Vec::<(Key, Bytes)>::new()
+        );
+    }
+
+    #[test]
+    fn should_clear_an_implementer() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let joe = fixture.joe;
+        let sender_hash = TestERC1820::interface_hash(HASH_ERC1820_SENDER);
+        let (caller, _) = fixture.install_test_caller(Sender(ali));
+
+        fixture.set_interface_implementer(Key::from(ali), sender_hash.clone(), Key::from(joe), Sender(ali));
+        fixture.clear_interface_implementer(Key::from(ali), sender_hash.clone(), Sender(ali));
+
+        assert_eq!(
+            fixture.get_interface_implementer(Key::from(ali), sender_hash),
+            Some(Key::from(AccountHash::default()))
+        );
+        assert_eq!(fixture.get_interfaces(caller, Key::from(ali), Sender(ali)), Vec::<Bytes>::new());
+        assert_eq!(fixture.get_interface_count(caller, Key::from(ali), Sender(ali)), 0);
+        assert_eq!(
+            fixture.get_accounts_for_implementer(caller, Key::from(joe), 0, 10, Sender(ali)),
+            Vec::<(Key, Bytes)>::new()
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(1000) [66536]")]
+    #[test]
+    fn should_not_clear_an_implementer_of_another_account() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let joe = fixture.joe;
+        let sender_hash = TestERC1820::interface_hash(HASH_ERC1820_SENDER);
+
+        fixture.set_interface_implementer(Key::from(ali), sender_hash.clone(), Key::from(joe), Sender(ali));
+        fixture.clear_interface_implementer(Key::from(ali), sender_hash, Sender(joe));
+    }
+
+    #[test]
+    fn should_register_implementers_in_a_batch() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let bob = fixture.bob;
+        let joe = fixture.joe;
+        let sender_hash = TestERC1820::interface_hash(HASH_ERC1820_SENDER);
+        let recipient_hash = TestERC1820::interface_hash(HASH_ERC1820_RECIPIENT);
+
+        fixture.set_interface_implementers(
+            Key::from(ali),
+            vec![(sender_hash.clone(), Key::from(joe)), (recipient_hash.clone(), Key::from(bob))],
+            Sender(ali)
+        );
+
+        assert_eq!(fixture.get_interface_implementer(Key::from(ali), sender_hash), Some(Key::from(joe)));
+        assert_eq!(fixture.get_interface_implementer(Key::from(ali), recipient_hash), Some(Key::from(bob)));
+    }
+
+    #[should_panic(expected = "ApiError::User(1001) [66537]")]
+    #[test]
+    fn should_not_register_a_batch_with_an_invalid_implementer() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let joe = fixture.joe;
+
+        fixture.set_interface_implementers(
+            Key::from(ali),
+            vec![
+                (TestERC1820::interface_hash(HASH_ERC1820_SENDER), Key::from(joe)),
+                (TestERC1820::interface_hash(HASH_ERC1820_RECIPIENT), Key::from(AccountHash::default()))
+            ],
+            Sender(ali)
+        );
+    }
+
+    #[test]
+    fn should_cache_erc165_interfaces_of_a_contract() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let registry = fixture.registry_contract();
+        let erc165_id = Bytes::from(ERC165_INTERFACE_ID.to_vec());
+        let other_id = Bytes::from(vec![1u8, 2, 3, 4]);
+
+        fixture.update_erc165_cache(registry, erc165_id.clone(), Sender(ali));
+        fixture.update_erc165_cache(registry, other_id.clone(), Sender(ali));
+
+        assert_eq!(fixture.erc165_cache(registry, erc165_id), Some(true));
+        assert_eq!(fixture.erc165_cache(registry, other_id), Some(false));
+    }
+
+    #[test]
+    fn should_cache_that_an_account_implements_no_erc165_interface() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let erc165_id = Bytes::from(ERC165_INTERFACE_ID.to_vec());
+
+        fixture.update_erc165_cache(Key::from(ali), erc165_id.clone(), Sender(ali));
+
+        assert_eq!(fixture.erc165_cache(Key::from(ali), erc165_id), Some(false));
+    }
+
+    #[test]
+    fn should_not_detect_erc165_interfaces_of_an_undeclared_contract() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let (caller, _) = fixture.install_test_caller(Sender(ali));
+        let erc165_id = Bytes::from(ERC165_INTERFACE_ID.to_vec());
+
+        // The test caller has no `supports_interface` entry point, asking it would revert.
+        fixture.update_erc165_cache(Key::from(caller), erc165_id.clone(), Sender(ali));
+
+        assert_eq!(fixture.erc165_cache(Key::from(caller), erc165_id), Some(false));
+    }
+
+    #[should_panic(expected = "ApiError::User(1007) [66543]")]
+    #[test]
+    fn should_not_declare_erc165_support_from_an_account() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+
+        fixture.declare_erc165(Sender(ali));
+    }
+
+    #[should_panic(expected = "ApiError::User(1003) [66539]")]
+    #[test]
+    fn should_not_register_an_implementer_for_an_erc165_interface() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let joe = fixture.joe;
+
+        fixture.set_interface_implementer(
+            Key::from(ali),
+            Bytes::from(ERC165_INTERFACE_ID.to_vec()),
+            Key::from(joe),
+            Sender(ali)
+        );
+    }
+
+    #[test]
+    fn should_register_an_implementer_of_the_calling_contract() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let joe = fixture.joe;
+        let (caller, caller_package) = fixture.install_test_caller(Sender(ali));
+
+        fixture.set_interface_implementer_from(
+            caller,
+            caller_package,
+            TestERC1820::interface_hash(HASH_ERC1820_RECIPIENT),
+            Key::from(joe),
+            Sender(ali)
+        );
+
+        assert_eq!(
+            Some(Key::from(joe)),
+            fixture.get_interface_implementer(caller_package, TestERC1820::interface_hash(HASH_ERC1820_RECIPIENT))
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(1000) [66536]")]
+    #[test]
+    fn should_not_register_an_implementer_of_another_contract() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let bob = fixture.bob;
+        let joe = fixture.joe;
+        let (caller, _) = fixture.install_test_caller(Sender(ali));
+        let (_, other_package) = fixture.install_test_caller(Sender(bob));
+
+        fixture.set_interface_implementer_from(
+            caller,
+            other_package,
+            TestERC1820::interface_hash(HASH_ERC1820_RECIPIENT),
+            Key::from(joe),
+            Sender(ali)
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(1000) [66536]")]
+    #[test]
+    fn should_not_set_the_manager_of_the_calling_account_from_a_contract() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let bob = fixture.bob;
+        let (caller, caller_package) = fixture.install_test_caller(Sender(bob));
+
+        // ali calls bob's contract, which tries to make itself the manager of ali.
+        fixture.set_manager_from(caller, Key::from(ali), caller_package, Sender(ali));
+    }
+
+    #[should_panic(expected = "ApiError::User(1000) [66536]")]
+    #[test]
+    fn should_not_set_the_manager_of_the_outer_contract_from_a_nested_call() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let bob = fixture.bob;
+        let joe = fixture.joe;
+        let (outer, outer_package) = fixture.install_test_caller(Sender(ali));
+        let (inner, _) = fixture.install_test_caller(Sender(bob));
+
+        // The registry sees the inner contract, which is not the manager of the outer one.
+        fixture.forward_set_manager_from(outer, inner, outer_package, Key::from(joe), Sender(ali));
+    }
+
+    #[test]
+    fn should_set_the_manager_of_the_inner_contract_from_a_nested_call() {
+        let mut fixture = TestERC1820::install_contract();
+        let ali = fixture.ali;
+        let bob = fixture.bob;
+        let joe = fixture.joe;
+        let (outer, _) = fixture.install_test_caller(Sender(ali));
+        let (inner, inner_package) = fixture.install_test_caller(Sender(bob));
+
+        fixture.forward_set_manager_from(outer, inner, inner_package, Key::from(joe), Sender(ali));
+
+        assert_eq!(Some(Key::from(joe)), fixture.get_manager(inner_package));
+    }
 }
 
 fn main() {
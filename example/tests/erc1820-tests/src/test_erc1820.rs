@@ -5,13 +5,22 @@ use blake2::{
 use casper_engine_test_support::{Code, SessionBuilder, TestContext, TestContextBuilder};
 use casper_types::{
     account::AccountHash,
-    bytesrepr::{ToBytes},
-    runtime_args, AsymmetricType, ContractHash, Key, PublicKey, RuntimeArgs, U512,
+    bytesrepr::{FromBytes, ToBytes},
+    runtime_args, AsymmetricType, CLTyped, ContractHash, Key, PublicKey, RuntimeArgs, U512,
 };
 use casper_types::bytesrepr::Bytes;
+use std::collections::BTreeMap;
 
 const CONTRACT_ERC1820_REGISTRY: &str = "erc1820_registry.wasm";
 const CONTRACT_KEY_NAME: &str = "erc1820_registry";
+const TEST_CALLER_CONTRACT_WASM: &str = "erc1820_test_caller.wasm";
+const TEST_CALLER_CONTRACT_NAME: &str = "erc1820_test_caller_contract";
+const TEST_CALLER_PACKAGE_NAME: &str = "erc1820_test_caller_contract_package_hash";
+const FORWARD_SET_MANAGER_ENTRY_POINT: &str = "forward_set_manager";
+const STORE_INTERFACES_ENTRY_POINT: &str = "store_interfaces";
+const STORE_INTERFACE_COUNT_ENTRY_POINT: &str = "store_interface_count";
+const STORE_ACCOUNTS_FOR_IMPLEMENTER_ENTRY_POINT: &str = "store_accounts_for_implementer";
+const TEST_CALLER_RESULT_KEY_NAME: &str = "result";
 
 fn blake2b256(item_key_string: &[u8]) -> Box<[u8]> {
     let mut hasher = VarBlake2b::new(32).unwrap();
@@ -59,6 +68,11 @@ impl TestERC1820 {
         }
     }
 
+    /// Canonical hash of an interface name, as computed by `casper_erc1820::interface_hash`.
+    pub fn interface_hash(interface_name: &str) -> Bytes {
+        Bytes::from(blake2b256(interface_name.as_bytes()).to_vec())
+    }
+
     fn contract_hash(&self) -> ContractHash {
         self.context
             .get_account(self.ali)
@@ -82,6 +96,88 @@ impl TestERC1820 {
         self.context.run(session);
     }
 
+    /// Installs the test caller contract from `sender`, which calls the registry as itself.
+    ///
+    /// Returns the contract hash and the key of its contract package.
+    pub fn install_test_caller(&mut self, sender: Sender) -> (ContractHash, Key) {
+        let Sender(address) = sender;
+        let session_code = Code::from(TEST_CALLER_CONTRACT_WASM);
+        let session_args = runtime_args! {
+            casper_erc1820::constants::REGISTRY_RUNTIME_ARG_NAME => self.contract_hash()
+        };
+        let session = SessionBuilder::new(session_code, session_args)
+            .with_address(address)
+            .with_authorization_keys(&[address])
+            .build();
+        self.context.run(session);
+
+        let named_keys = self.context.get_account(address).unwrap().named_keys().clone();
+        let contract_hash: ContractHash = named_keys
+            .get(TEST_CALLER_CONTRACT_NAME)
+            .unwrap()
+            .normalize()
+            .into_hash()
+            .unwrap()
+            .into();
+        let package_hash = named_keys
+            .get(TEST_CALLER_PACKAGE_NAME)
+            .unwrap()
+            .into_hash()
+            .unwrap();
+        (contract_hash, Key::Hash(package_hash))
+    }
+
+    fn call_test_caller(&mut self, caller: ContractHash, method: &str, args: RuntimeArgs, sender: Sender) {
+        let Sender(address) = sender;
+        let code = Code::Hash(caller.value(), method.to_string());
+        let session = SessionBuilder::new(code, args)
+            .with_address(address)
+            .with_authorization_keys(&[address])
+            .build();
+        self.context.run(session);
+    }
+
+    /// Calls `set_interface_implementer` of the registry through the test caller `caller`.
+    pub fn set_interface_implementer_from(&mut self, caller: ContractHash, account: Key, i_hash: Bytes, implementer: Key, sender: Sender) {
+        self.call_test_caller(
+            caller,
+            casper_erc1820::constants::SET_INTERFACE_ENTRY_POINT,
+            runtime_args! {
+                casper_erc1820::constants::ACCOUNT_RUNTIME_ARG_NAME => account,
+                casper_erc1820::constants::I_HASH_RUNTIME_ARG_NAME => i_hash,
+                casper_erc1820::constants::IMPLEMENTER_RUNTIME_ARG_NAME => implementer
+            },
+            sender
+        );
+    }
+
+    /// Calls `set_manager` of the registry through the test caller `caller`.
+    pub fn set_manager_from(&mut self, caller: ContractHash, account: Key, new_manager: Key, sender: Sender) {
+        self.call_test_caller(
+            caller,
+            casper_erc1820::constants::SET_MANAGER_ENTRY_POINT,
+            runtime_args! {
+                casper_erc1820::constants::ACCOUNT_RUNTIME_ARG_NAME => account,
+                casper_erc1820::constants::NEW_MANAGER_RUNTIME_ARG_NAME => new_manager
+            },
+            sender
+        );
+    }
+
+    /// Calls `set_manager` of the registry through `caller`, which forwards the call to the test caller `callee`.
+    pub fn forward_set_manager_from(&mut self, caller: ContractHash, callee: ContractHash, account: Key, new_manager: Key, sender: Sender) {
+        self.call_test_caller(
+            caller,
+            FORWARD_SET_MANAGER_ENTRY_POINT,
+            runtime_args! {
+                "contract" => callee,
+                casper_erc1820::constants::ACCOUNT_RUNTIME_ARG_NAME => account,
+                casper_erc1820::constants::NEW_MANAGER_RUNTIME_ARG_NAME => new_manager
+            },
+            sender
+        );
+    }
+
     pub fn set_interface_implementer(&mut self, account: Key, i_hash: Bytes, implementer: Key, sender: Sender) {
         self.call(
             sender,
@@ -94,6 +190,28 @@ impl TestERC1820 {
         );
     }
 
+    pub fn set_interface_implementers(&mut self, account: Key, implementers: Vec<(Bytes, Key)>, sender: Sender) {
+        self.call(
+            sender,
+            casper_erc1820::constants::SET_INTERFACES_ENTRY_POINT,
+            runtime_args! {
+                casper_erc1820::constants::ACCOUNT_RUNTIME_ARG_NAME => account,
+                casper_erc1820::constants::IMPLEMENTERS_RUNTIME_ARG_NAME => implementers
+            },
+        );
+    }
+
+    pub fn clear_interface_implementer(&mut self, account: Key, i_hash: Bytes, sender: Sender) {
+        self.call(
+            sender,
+            casper_erc1820::constants::CLEAR_INTERFACE_ENTRY_POINT,
+            runtime_args! {
+                casper_erc1820::constants::ACCOUNT_RUNTIME_ARG_NAME => account,
+                casper_erc1820::constants::I_HASH_RUNTIME_ARG_NAME => i_hash
+            },
+        );
+    }
+
     pub fn set_manager(&mut self, account: Key, new_manager: Key, sender: Sender) {
         self.call(
             sender,
@@ -105,6 +223,72 @@ impl TestERC1820 {
         );
     }
 
+    pub fn update_erc165_cache(&mut self, account: Key, interface_id: Bytes, sender: Sender) {
+        self.call(
+            sender,
+            casper_erc1820::constants::UPDATE_ERC165_CACHE,
+            runtime_args! {
+                casper_erc1820::constants::ACCOUNT_RUNTIME_ARG_NAME => account,
+                casper_erc1820::constants::I_ID_RUNTIME_ARG_NAME => interface_id
+            },
+        );
+    }
+
+    /// Key of the registry contract, which implements ERC165 itself.
+    pub fn registry_contract(&self) -> Key {
+        Key::from(self.contract_hash())
+    }
+
+    pub fn declare_erc165(&mut self, sender: Sender) {
+        self.call(
+            sender,
+            casper_erc1820::constants::DECLARE_ERC165_ENTRY_POINT,
+            runtime_args! {},
+        );
+    }
+
+    pub fn erc165_cache(&self, account: Key, interface_id: Bytes) -> Option<bool> {
+        let mut preimage = Vec::new();
+        preimage.append(&mut account.to_bytes().unwrap());
+        preimage.append(&mut interface_id.to_vec());
+        let item_key = hex::encode(&blake2b256(&preimage));
+
+        let key = Key::Hash(self.contract_hash().value());
+        let value = self
+            .context
+            .query_dictionary_item(
+                key,
+                Some(casper_erc1820::constants::ERC165_CACHE_REGISTRY_KEY_NAME.to_string()),
+                item_key
+            ).ok()?;
+
+        Some(value.into_t::<bool>().unwrap())
+    }
+
+    pub fn events_count(&self) -> u64 {
+        self.context
+            .query(
+                self.ali,
+                &[CONTRACT_KEY_NAME.to_string(), casper_erc1820::constants::EVENTS_COUNT_KEY_NAME.to_string()]
+            )
+            .unwrap()
+            .into_t()
+            .unwrap()
+    }
+
+    pub fn event(&self, index: u64) -> Option<BTreeMap<String, String>> {
+        let key = Key::Hash(self.contract_hash().value());
+        let value = self
+            .context
+            .query_dictionary_item(
+                key,
+                Some(casper_erc1820::constants::EVENTS_KEY_NAME.to_string()),
+                index.to_string()
+            ).ok()?;
+
+        Some(value.into_t::<BTreeMap<String, String>>().unwrap())
+    }
+
     pub fn get_interface_implementer(&self, account: Key, tag: Bytes) -> Option<Key> {
         let mut preimage = Vec::new();
         preimage.append(&mut account.to_bytes().unwrap());
@@ -124,6 +308,66 @@ impl TestERC1820 {
         Some(value.into_t::<Key>().unwrap())
     }
 
+    /// Reads the result the test caller installed by `sender` stored last.
+    fn test_caller_result<T: CLTyped + FromBytes>(&self, sender: Sender) -> T {
+        let Sender(address) = sender;
+        self.context
+            .query(address, &[TEST_CALLER_CONTRACT_NAME.to_string(), TEST_CALLER_RESULT_KEY_NAME.to_string()])
+            .unwrap()
+            .into_t()
+            .unwrap()
+    }
+
+    /// Calls `get_interfaces` of the registry through the test caller `caller` installed by `sender`.
+    pub fn get_interfaces(&mut self, caller: ContractHash, account: Key, sender: Sender) -> Vec<Bytes> {
+        self.call_test_caller(
+            caller,
+            STORE_INTERFACES_ENTRY_POINT,
+            runtime_args! {
+                casper_erc1820::constants::ACCOUNT_RUNTIME_ARG_NAME => account
+            },
+            sender
+        );
+        self.test_caller_result(sender)
+    }
+
+    /// Calls `get_interface_count` of the registry through the test caller `caller` installed by
+    /// `sender`.
+    pub fn get_interface_count(&mut self, caller: ContractHash, account: Key, sender: Sender) -> u64 {
+        self.call_test_caller(
+            caller,
+            STORE_INTERFACE_COUNT_ENTRY_POINT,
+            runtime_args! {
+                casper_erc1820::constants::ACCOUNT_RUNTIME_ARG_NAME => account
+            },
+            sender
+        );
+        self.test_caller_result(sender)
+    }
+
+    /// Calls `get_accounts_for_implementer` of the registry through the test caller `caller`
+    /// installed by `sender`.
+    pub fn get_accounts_for_implementer(
+        &mut self,
+        caller: ContractHash,
+        implementer: Key,
+        offset: u64,
+        limit: u64,
+        sender: Sender
+    ) -> Vec<(Key, Bytes)> {
+        self.call_test_caller(
+            caller,
+            STORE_ACCOUNTS_FOR_IMPLEMENTER_ENTRY_POINT,
+            runtime_args! {
+                casper_erc1820::constants::IMPLEMENTER_RUNTIME_ARG_NAME => implementer,
+                casper_erc1820::constants::OFFSET_RUNTIME_ARG_NAME => offset,
+                casper_erc1820::constants::LIMIT_RUNTIME_ARG_NAME => limit
+            },
+            sender
+        );
+        self.test_caller_result(sender)
+    }
+
     pub fn get_manager(&self, account: Key) -> Option<Key> {
         let key_bytes = account.to_bytes().unwrap();
         let hash = blake2b256(&key_bytes);
@@ -0,0 +1,206 @@
+#[cfg(test)]
+mod test_fixture;
+
+#[cfg(test)]
+mod splitter_tests {
+    use casper_types::{bytesrepr::Bytes, Key, U256};
+
+    use crate::test_fixture::{Sender, TestFixture};
+
+    fn add_contexts(fixture: &mut TestFixture, granularity: U256) {
+        let payees = vec![Key::from(fixture.bob), Key::from(fixture.joe)];
+        fixture.add_splitter_contexts(granularity, payees, vec![7500, 2500]);
+    }
+
+    #[test]
+    fn should_split_the_received_tokens_among_the_payees() {
+        let mut fixture = TestFixture::install_contract();
+        add_contexts(&mut fixture, U256::one());
+
+        let bob = Key::from(fixture.bob);
+        let joe = Key::from(fixture.joe);
+        let splitter = fixture.recipient_package();
+
+        fixture.send(splitter, U256::from(1000), Bytes::default(), Sender(fixture.ali));
+
+        assert_eq!(fixture.balance_of(bob), Some(U256::from(750)));
+        assert_eq!(fixture.balance_of(joe), Some(U256::from(250)));
+        assert_eq!(fixture.balance_of(splitter), Some(U256::zero()));
+        assert_eq!(fixture.released(bob), U256::from(750));
+        assert_eq!(fixture.released(joe), U256::from(250));
+    }
+
+    #[test]
+    fn should_give_the_remainder_to_the_first_payee_in_multiples_of_the_granularity() {
+        let mut fixture = TestFixture::install_contract();
+        add_contexts(&mut fixture, U256::from(10));
+
+        let bob = Key::from(fixture.bob);
+        let joe = Key::from(fixture.joe);
+        let splitter = fixture.recipient_package();
+
+        // 67.5 and 22.5 are rounded down to 60 and 20, the 10 left go to bob.
+        fixture.send(splitter, U256::from(90), Bytes::default(), Sender(fixture.ali));
+
+        assert_eq!(fixture.balance_of(bob), Some(U256::from(70)));
+        assert_eq!(fixture.balance_of(joe), Some(U256::from(20)));
+        assert_eq!(fixture.balance_of(splitter), Some(U256::zero()));
+    }
+
+    #[test]
+    fn should_split_the_largest_amount_without_overflowing() {
+        let mut fixture = TestFixture::install_contract();
+        let bob = Key::from(fixture.bob);
+        let joe = Key::from(fixture.joe);
+        let payees = vec![bob, joe];
+        fixture.add_splitter_contexts_with_supply(U256::one(), U256::MAX, payees, vec![7500, 2500]);
+
+        let splitter = fixture.recipient_package();
+        fixture.send(splitter, U256::MAX, Bytes::default(), Sender(fixture.ali));
+
+        assert_eq!(fixture.balance_of(joe), Some(U256::MAX / 4));
+        assert_eq!(fixture.balance_of(bob), Some(U256::MAX - U256::MAX / 4));
+        assert_eq!(fixture.balance_of(splitter), Some(U256::zero()));
+    }
+
+    #[test]
+    fn should_split_with_the_new_payees() {
+        let mut fixture = TestFixture::install_contract();
+        add_contexts(&mut fixture, U256::one());
+
+        let bob = Key::from(fixture.bob);
+        let joe = Key::from(fixture.joe);
+        let splitter = fixture.recipient_package();
+
+        fixture.send(splitter, U256::from(100), Bytes::default(), Sender(fixture.ali));
+        fixture.set_payees(vec![joe], vec![10000], Sender(fixture.ali));
+        assert_eq!(fixture.payees(), vec![(joe, 10000)]);
+
+        fixture.send(splitter, U256::from(100), Bytes::default(), Sender(fixture.ali));
+
+        assert_eq!(fixture.balance_of(bob), Some(U256::from(75)));
+        assert_eq!(fixture.balance_of(joe), Some(U256::from(125)));
+        assert_eq!(fixture.released(bob), U256::from(75));
+        assert_eq!(fixture.released(joe), U256::from(125));
+    }
+
+    #[should_panic(expected = "ApiError::User(1501) [67037]")]
+    #[test]
+    fn should_not_set_the_payees_from_another_account() {
+        let mut fixture = TestFixture::install_contract();
+        add_contexts(&mut fixture, U256::one());
+
+        let bob = Key::from(fixture.bob);
+        fixture.set_payees(vec![bob], vec![10000], Sender(fixture.bob));
+    }
+
+    #[should_panic(expected = "ApiError::User(1505) [67041]")]
+    #[test]
+    fn should_not_set_shares_which_do_not_add_up_to_10000() {
+        let mut fixture = TestFixture::install_contract();
+        add_contexts(&mut fixture, U256::one());
+
+        let bob = Key::from(fixture.bob);
+        let joe = Key::from(fixture.joe);
+        fixture.set_payees(vec![bob, joe], vec![5000, 4000], Sender(fixture.ali));
+    }
+
+    #[should_panic(expected = "ApiError::User(1502) [67038]")]
+    #[test]
+    fn should_not_split_a_token_which_is_not_registered() {
+        let mut fixture = TestFixture::install_contract();
+        add_contexts(&mut fixture, U256::one());
+
+        let token = fixture.token_package();
+        let splitter = fixture.recipient_package();
+
+        fixture.remove_token(token, Sender(fixture.ali));
+        fixture.send(splitter, U256::from(100), Bytes::default(), Sender(fixture.ali));
+    }
+}
+
+#[cfg(test)]
+mod vault_tests {
+    use casper_types::{bytesrepr::Bytes, Key, U256};
+
+    use crate::test_fixture::{Sender, TestFixture};
+
+    /// Gives 100 tokens to `bob` and `joe`, which deposit 60 and 40 of them in the vault.
+    fn deposit(fixture: &mut TestFixture) {
+        let bob = Key::from(fixture.bob);
+        let joe = Key::from(fixture.joe);
+        let vault = fixture.recipient_package();
+
+        fixture.send(bob, U256::from(100), Bytes::default(), Sender(fixture.ali));
+        fixture.send(joe, U256::from(100), Bytes::default(), Sender(fixture.ali));
+        fixture.send(vault, U256::from(60), Bytes::default(), Sender(fixture.bob));
+        fixture.send(vault, U256::from(40), Bytes::default(), Sender(fixture.joe));
+    }
+
+    #[test]
+    fn should_credit_every_depositor() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_vault_contexts();
+        deposit(&mut fixture);
+
+        assert_eq!(fixture.deposited_balance(Key::from(fixture.bob)), U256::from(60));
+        assert_eq!(fixture.deposited_balance(Key::from(fixture.joe)), U256::from(40));
+        assert_eq!(fixture.deposited_balance(Key::from(fixture.ali)), U256::zero());
+        assert_eq!(fixture.balance_of(fixture.recipient_package()), Some(U256::from(100)));
+    }
+
+    #[test]
+    fn should_withdraw_the_deposited_tokens() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_vault_contexts();
+        deposit(&mut fixture);
+
+        fixture.withdraw(U256::from(25), Sender(fixture.bob));
+
+        assert_eq!(fixture.deposited_balance(Key::from(fixture.bob)), U256::from(35));
+        assert_eq!(fixture.deposited_balance(Key::from(fixture.joe)), U256::from(40));
+        assert_eq!(fixture.balance_of(Key::from(fixture.bob)), Some(U256::from(65)));
+        assert_eq!(fixture.balance_of(fixture.recipient_package()), Some(U256::from(75)));
+    }
+
+    #[should_panic(expected = "ApiError::User(1605) [67141]")]
+    #[test]
+    fn should_not_withdraw_the_tokens_of_another_depositor() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_vault_contexts();
+        deposit(&mut fixture);
+
+        fixture.withdraw(U256::from(50), Sender(fixture.joe));
+    }
+
+    #[should_panic(expected = "ApiError::User(1602) [67138]")]
+    #[test]
+    fn should_not_deposit_a_token_which_is_not_registered() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_vault_contexts();
+
+        let token = fixture.token_package();
+        let vault = fixture.recipient_package();
+
+        fixture.remove_token(token, Sender(fixture.ali));
+        fixture.send(vault, U256::from(10), Bytes::default(), Sender(fixture.ali));
+    }
+
+    #[test]
+    fn should_withdraw_a_token_which_is_no_longer_registered() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_vault_contexts();
+        deposit(&mut fixture);
+
+        let token = fixture.token_package();
+        fixture.remove_token(token, Sender(fixture.ali));
+        fixture.withdraw(U256::from(40), Sender(fixture.joe));
+
+        assert_eq!(fixture.deposited_balance(Key::from(fixture.joe)), U256::zero());
+        assert_eq!(fixture.balance_of(Key::from(fixture.joe)), Some(U256::from(100)));
+    }
+}
+
+fn main() {
+    panic!("Execute \"cargo test\" to test the contract, not \"cargo run\".");
+}
@@ -0,0 +1,319 @@
+use blake2::{
+    digest::{Update, VariableOutput},
+    VarBlake2b,
+};
+
+use casper_engine_test_support::{Code, SessionBuilder, TestContext, TestContextBuilder};
+use casper_types::{
+    account::AccountHash, ContractHash, bytesrepr::{Bytes, FromBytes, ToBytes}, runtime_args,
+    AsymmetricType, CLTyped, Key, PublicKey, RuntimeArgs, U512, U256, HashAddr
+};
+
+const ERC1820_CONTRACT_WASM: &str = "erc1820_registry.wasm";
+const ERC777_CONTRACT_WASM: &str = "erc777_token.wasm";
+const PAYMENT_SPLITTER_CONTRACT_WASM: &str = "erc777_payment_splitter.wasm";
+const VAULT_CONTRACT_WASM: &str = "erc777_vault.wasm";
+const ERC1820_CONTRACT_NAME: &str = "erc1820_registry";
+
+fn blake2b256(item_key_string: &[u8]) -> Box<[u8]> {
+    let mut hasher = VarBlake2b::new(32).unwrap();
+    hasher.update(item_key_string);
+    hasher.finalize_boxed()
+}
+
+#[derive(Clone, Copy)]
+pub struct Sender(pub AccountHash);
+
+pub struct TestFixture {
+    pub context: TestContext,
+    pub ali: AccountHash,
+    pub bob: AccountHash,
+    pub joe: AccountHash,
+    /// Name of the installed recipient contract, the splitter or the vault.
+    recipient_name: &'static str,
+}
+
+impl TestFixture {
+    const TOKEN_TOTAL_SUPPLY_AS_U64: u64 = 10000;
+
+    pub fn install_contract() -> TestFixture {
+        let ali = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
+        let bob = PublicKey::ed25519_from_bytes([6u8; 32]).unwrap();
+        let joe = PublicKey::ed25519_from_bytes([9u8; 32]).unwrap();
+
+        let context = TestContextBuilder::new()
+            .with_public_key(ali.clone(), U512::from(500_000_000_000_000_000u64))
+            .with_public_key(bob.clone(), U512::from(500_000_000_000_000_000u64))
+            .with_public_key(joe.clone(), U512::from(500_000_000_000_000_000u64))
+            .build();
+
+        TestFixture {
+            context,
+            ali: ali.to_account_hash(),
+            bob: bob.to_account_hash(),
+            joe: joe.to_account_hash(),
+            recipient_name: "",
+        }
+    }
+
+    fn install(&mut self, session_code: Code, session_args: RuntimeArgs) {
+        let session = SessionBuilder::new(session_code, session_args)
+            .with_address(self.ali)
+            .with_authorization_keys(&[self.ali])
+            .build();
+
+        self.context.run(session);
+    }
+
+    /// Installs the registry, a token owned by `ali` and a splitter paying `payees` by `shares`,
+    /// with the token registered.
+    pub fn add_splitter_contexts(&mut self, granularity: U256, payees: Vec<Key>, shares: Vec<u32>) {
+        let total_supply = TestFixture::token_total_supply();
+        self.add_splitter_contexts_with_supply(granularity, total_supply, payees, shares);
+    }
+
+    /// Same as [`TestFixture::add_splitter_contexts`], with a token of `total_supply`.
+    pub fn add_splitter_contexts_with_supply(
+        &mut self,
+        granularity: U256,
+        total_supply: U256,
+        payees: Vec<Key>,
+        shares: Vec<u32>
+    ) {
+        let erc1820_hash = self.add_registry_and_token(granularity, total_supply);
+
+        self.install(
+            Code::from(PAYMENT_SPLITTER_CONTRACT_WASM),
+            runtime_args! {
+                casper_erc777_payment_splitter::constants::ERC1820_CONTRACT_RUNTIME_ARG_NAME => erc1820_hash,
+                casper_erc777_payment_splitter::constants::PAYEES_RUNTIME_ARG_NAME => payees,
+                casper_erc777_payment_splitter::constants::SHARES_RUNTIME_ARG_NAME => shares
+            },
+        );
+        self.recipient_name = casper_erc777_payment_splitter::constants::PAYMENT_SPLITTER_CONTRACT_NAME;
+
+        let token = self.token_package();
+        self.add_token(token, Sender(self.ali));
+    }
+
+    /// Installs the registry, a token owned by `ali` and the vault, with the token registered.
+    pub fn add_vault_contexts(&mut self) {
+        let erc1820_hash = self.add_registry_and_token(U256::one(), TestFixture::token_total_supply());
+
+        self.install(
+            Code::from(VAULT_CONTRACT_WASM),
+            runtime_args! {
+                casper_erc777_vault::constants::ERC1820_CONTRACT_RUNTIME_ARG_NAME => erc1820_hash
+            },
+        );
+        self.recipient_name = casper_erc777_vault::constants::VAULT_CONTRACT_NAME;
+
+        let token = self.token_package();
+        self.add_token(token, Sender(self.ali));
+    }
+
+    /// Installs the registry and a token owned by `ali`, and returns the hash of the registry.
+    fn add_registry_and_token(&mut self, granularity: U256, total_supply: U256) -> ContractHash {
+        self.install(Code::from(ERC1820_CONTRACT_WASM), runtime_args! {});
+        let erc1820_hash = self.contract_hash(ERC1820_CONTRACT_NAME);
+
+        self.install(
+            Code::from(ERC777_CONTRACT_WASM),
+            runtime_args! {
+                casper_erc777::constants::NAME_RUNTIME_ARG_NAME => "Test ERC777",
+                casper_erc777::constants::SYMBOL_RUNTIME_ARG_NAME => "TERC",
+                casper_erc777::constants::GRANULARITY_RUNTIME_ARG_NAME => granularity,
+                casper_erc777::constants::TOTAL_SUPPLY_RUNTIME_ARG_NAME => total_supply,
+                casper_erc777::constants::HASH_ERC1820_RUNTIME_ARG_NAME => erc1820_hash
+            },
+        );
+        erc1820_hash
+    }
+
+    pub fn token_total_supply() -> U256 {
+        Self::TOKEN_TOTAL_SUPPLY_AS_U64.into()
+    }
+
+    fn named_key(&self, name: &str) -> Key {
+        *self
+            .context
+            .get_account(self.ali)
+            .unwrap()
+            .named_keys()
+            .get(name)
+            .unwrap()
+    }
+
+    fn contract_hash(&self, name: &str) -> ContractHash {
+        self.named_key(name)
+            .normalize()
+            .into_hash()
+            .unwrap()
+            .into()
+    }
+
+    fn package(&self, contract_name: &str, suffix: &str) -> Key {
+        let mut name = contract_name.to_string();
+        name.push_str(suffix);
+        Key::Hash(self.named_key(&name).into_hash().unwrap())
+    }
+
+    pub fn contract_hash_token(&self) -> ContractHash {
+        self.contract_hash(casper_erc777::constants::ERC20_TOKEN_CONTRACT_NAME)
+    }
+
+    /// Hash of the installed recipient contract, the splitter or the vault.
+    pub fn contract_hash_recipient(&self) -> ContractHash {
+        self.contract_hash(self.recipient_name)
+    }
+
+    /// Key of the token contract package.
+    pub fn token_package(&self) -> Key {
+        self.package(
+            casper_erc777::constants::ERC20_TOKEN_CONTRACT_NAME,
+            casper_erc777::constants::PACKAGE_HASH_KEY_NAME_SUFFIX
+        )
+    }
+
+    /// Key of the recipient contract package, which receives the tokens to split or to deposit.
+    pub fn recipient_package(&self) -> Key {
+        self.package(
+            self.recipient_name,
+            casper_erc777_package_recipient::constants::PACKAGE_HASH_KEY_NAME_SUFFIX
+        )
+    }
+
+    fn call(&mut self, sender: Sender, hash_addr: HashAddr, method: &str, args: RuntimeArgs) {
+        let Sender(address) = sender;
+        let code = Code::Hash(hash_addr, method.to_string());
+        let session = SessionBuilder::new(code, args)
+            .with_address(address)
+            .with_authorization_keys(&[address])
+            .build();
+        self.context.run(session);
+    }
+
+    fn call_recipient(&mut self, method: &str, args: RuntimeArgs, sender: Sender) {
+        self.call(sender, self.contract_hash_recipient().value(), method, args);
+    }
+
+    fn query_recipient<T: CLTyped + FromBytes>(&self, name: &str) -> Option<T> {
+        let path = [self.recipient_name.to_string(), name.to_string()];
+        match self.context.query(self.ali, &path) {
+            Err(_) => None,
+            Ok(maybe_value) => {
+                let value = maybe_value
+                    .into_t()
+                    .unwrap_or_else(|_| panic!("{} is not expected type.", name));
+                Some(value)
+            }
+        }
+    }
+
+    pub fn balance_of(&self, account: Key) -> Option<U256> {
+        let item_key = base64::encode(&account.to_bytes().unwrap());
+
+        let key = Key::Hash(self.contract_hash_token().value());
+        let value = self
+            .context
+            .query_dictionary_item(key, Some(casper_erc777::constants::BALANCES_KEY_NAME.to_string()), item_key)
+            .ok()?;
+
+        Some(value.into_t::<U256>().unwrap_or_default())
+    }
+
+    pub fn send(&mut self, recipient: Key, amount: U256, data: Bytes, sender: Sender) {
+        self.call(
+            sender,
+            self.contract_hash_token().value(),
+            casper_erc777::constants::SEND_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777::constants::RECIPIENT_RUNTIME_ARG_NAME => recipient,
+                casper_erc777::constants::AMOUNT_RUNTIME_ARG_NAME => amount,
+                casper_erc777::constants::DATA_RUNTIME_ARG_NAME => data
+            },
+        );
+    }
+
+    pub fn set_payees(&mut self, payees: Vec<Key>, shares: Vec<u32>, sender: Sender) {
+        self.call_recipient(
+            casper_erc777_payment_splitter::constants::SET_PAYEES_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777_payment_splitter::constants::PAYEES_RUNTIME_ARG_NAME => payees,
+                casper_erc777_payment_splitter::constants::SHARES_RUNTIME_ARG_NAME => shares
+            },
+            sender
+        );
+    }
+
+    pub fn add_token(&mut self, token: Key, sender: Sender) {
+        self.call_recipient(
+            casper_erc777_package_recipient::constants::ADD_TOKEN_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777_package_recipient::constants::TOKEN_RUNTIME_ARG_NAME => token
+            },
+            sender
+        );
+    }
+
+    pub fn remove_token(&mut self, token: Key, sender: Sender) {
+        self.call_recipient(
+            casper_erc777_package_recipient::constants::REMOVE_TOKEN_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777_package_recipient::constants::TOKEN_RUNTIME_ARG_NAME => token
+            },
+            sender
+        );
+    }
+
+    pub fn payees(&self) -> Vec<(Key, u32)> {
+        self.query_recipient(casper_erc777_payment_splitter::constants::PAYEES_KEY_NAME)
+            .unwrap_or_default()
+    }
+
+    pub fn released(&self, payee: Key) -> U256 {
+        let mut preimage = self.token_package().to_bytes().unwrap();
+        preimage.append(&mut payee.to_bytes().unwrap());
+        let item_key = hex::encode(&blake2b256(&preimage));
+
+        let key = Key::Hash(self.contract_hash_recipient().value());
+        self.context
+            .query_dictionary_item(
+                key,
+                Some(casper_erc777_payment_splitter::constants::RELEASED_REGISTRY_KEY_NAME.to_string()),
+                item_key
+            )
+            .ok()
+            .map(|value| value.into_t::<U256>().unwrap())
+            .unwrap_or_default()
+    }
+
+    pub fn withdraw(&mut self, amount: U256, sender: Sender) {
+        let token = self.token_package();
+        self.call_recipient(
+            casper_erc777_vault::constants::WITHDRAW_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777_vault::constants::TOKEN_RUNTIME_ARG_NAME => token,
+                casper_erc777_vault::constants::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+            sender
+        );
+    }
+
+    pub fn deposited_balance(&self, depositor: Key) -> U256 {
+        let mut preimage = depositor.to_bytes().unwrap();
+        preimage.append(&mut self.token_package().to_bytes().unwrap());
+        let item_key = hex::encode(&blake2b256(&preimage));
+
+        let key = Key::Hash(self.contract_hash_recipient().value());
+        self.context
+            .query_dictionary_item(
+                key,
+                Some(casper_erc777_vault::constants::DEPOSITS_REGISTRY_KEY_NAME.to_string()),
+                item_key
+            )
+            .ok()
+            .map(|value| value.into_t::<U256>().unwrap())
+            .unwrap_or_default()
+    }
+}
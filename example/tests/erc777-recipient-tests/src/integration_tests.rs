@@ -7,6 +7,7 @@ mod test_fixture;
 mod tests {
     use casper_types::{Key, U256};
     use casper_types::bytesrepr::Bytes;
+    use casper_erc777_recipient::InvoiceStatus;
 
     extern crate base64;
     use crate::test_fixture::{Sender, TestFixture};
@@ -284,6 +285,8 @@ mod tests {
             Some(base64::encode(expected_operator))
         );
 
+        fixture.add_delegate(Key::from(owner), Sender(operator));
+
         println!("transfer_from_erc777_recipient");
         fixture.transfer_from_erc777_recipient(
             Key::from(owner),
@@ -339,6 +342,8 @@ mod tests {
         );
 
         println!("burn_from_erc777_recipient");
+        fixture.add_delegate(Key::from(operator), Sender(owner));
+
         fixture.burn_from_erc777_recipient(
             Key::from(owner),
             U256::one(),
@@ -424,6 +429,465 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_record_the_movements_received_by_the_owner() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let holder = fixture.bob;
+
+        fixture.send(Key::from(holder), U256::from(10), Bytes::default(), Sender(owner));
+        assert_eq!(fixture.movement_count(), 0);
+
+        fixture.send(Key::from(owner), U256::from(4), Bytes::from(vec![7u8]), Sender(holder));
+        assert_eq!(fixture.movement_count(), 1);
+
+        let movement = fixture.movement(0).unwrap();
+        assert_eq!(movement.get("sequence").unwrap(), "0");
+        assert_eq!(movement.get("from").unwrap(), &Key::from(holder).to_formatted_string());
+        assert_eq!(movement.get("to").unwrap(), &Key::from(owner).to_formatted_string());
+        assert_eq!(movement.get("amount").unwrap(), "4");
+        assert_eq!(movement.get("data").unwrap(), "07");
+        assert!(fixture.movement(1).is_none());
+    }
+
+    #[test]
+    fn should_record_the_operator_of_the_movements_received_from_an_operator() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let holder = fixture.bob;
+        let operator = fixture.joe;
+
+        fixture.send(Key::from(holder), U256::from(10), Bytes::default(), Sender(owner));
+        fixture.authorize_operator(Key::from(operator), Sender(holder));
+        fixture.operator_send(
+            Key::from(holder),
+            Key::from(owner),
+            U256::from(4),
+            Bytes::default(),
+            Bytes::default(),
+            Sender(operator)
+        );
+
+        let movement = fixture.movement(0).unwrap();
+        assert_eq!(movement.get("operator").unwrap(), &Key::from(operator).to_formatted_string());
+        assert_eq!(movement.get("from").unwrap(), &Key::from(holder).to_formatted_string());
+        assert_eq!(movement.get("to").unwrap(), &Key::from(owner).to_formatted_string());
+    }
+
+    #[should_panic(expected = "ApiError::User(1401) [66937]")]
+    #[test]
+    fn should_not_receive_a_token_which_is_not_registered() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let holder = fixture.bob;
+        let token = fixture.token_package();
+
+        fixture.send(Key::from(holder), U256::from(10), Bytes::default(), Sender(owner));
+        fixture.remove_token(token, Sender(owner));
+        fixture.send(Key::from(owner), U256::from(4), Bytes::default(), Sender(holder));
+    }
+
+    #[should_panic(expected = "ApiError::User(1400) [66936]")]
+    #[test]
+    fn should_not_register_a_token_from_another_account() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let token = fixture.token_package();
+        fixture.add_token(token, Sender(fixture.bob));
+    }
+
+    #[should_panic(expected = "ApiError::User(1400) [66936]")]
+    #[test]
+    fn should_not_transfer_from_an_account_which_is_not_a_delegate() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let operator = fixture.bob;
+
+        fixture.authorize_operator(Key::from(operator), Sender(owner));
+        fixture.transfer_from_erc777_recipient(
+            Key::from(owner),
+            Key::from(operator),
+            U256::one(),
+            Bytes::default(),
+            Bytes::default(),
+            Sender(operator)
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(1400) [66936]")]
+    #[test]
+    fn should_not_burn_from_a_removed_delegate() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let operator = fixture.bob;
+
+        fixture.authorize_operator(Key::from(operator), Sender(owner));
+        fixture.add_delegate(Key::from(operator), Sender(owner));
+        fixture.remove_delegate(Key::from(operator), Sender(owner));
+        fixture.burn_from_erc777_recipient(
+            Key::from(owner),
+            U256::one(),
+            Bytes::default(),
+            Bytes::default(),
+            Sender(operator)
+        );
+    }
+
+    #[test]
+    fn should_serve_an_account_which_registers_the_hook() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let holder = fixture.bob;
+
+        fixture.register_for(Key::from(holder), Sender(holder));
+        let hook = Key::from(fixture.contract_hash_erc777_recipient());
+        fixture.set_interface_implementer(
+            Key::from(holder),
+            casper_erc777_recipient::constants::HASH_ERC1820_RECIPIENT,
+            hook,
+            Sender(holder)
+        );
+        assert_eq!(fixture.served_accounts(), vec![Key::from(owner), Key::from(holder)]);
+
+        fixture.send(Key::from(holder), U256::from(10), Bytes::default(), Sender(owner));
+        assert_eq!(fixture.movement_count(), 1);
+
+        let movement = fixture.movement(0).unwrap();
+        assert_eq!(movement.get("to").unwrap(), &Key::from(holder).to_formatted_string());
+        assert_eq!(movement.get("amount").unwrap(), "10");
+    }
+
+    #[test]
+    fn should_stop_serving_an_account_which_unregisters_the_hook() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let holder = fixture.bob;
+
+        fixture.register_for(Key::from(holder), Sender(holder));
+        let hook = Key::from(fixture.contract_hash_erc777_recipient());
+        fixture.set_interface_implementer(
+            Key::from(holder),
+            casper_erc777_recipient::constants::HASH_ERC1820_RECIPIENT,
+            hook,
+            Sender(holder)
+        );
+        fixture.unregister_for(Key::from(holder), Sender(holder));
+        fixture.clear_interface_implementer(
+            Key::from(holder),
+            casper_erc777_recipient::constants::HASH_ERC1820_RECIPIENT,
+            Sender(holder)
+        );
+        assert_eq!(fixture.served_accounts(), vec![Key::from(owner)]);
+
+        fixture.send(Key::from(holder), U256::from(10), Bytes::default(), Sender(owner));
+        assert_eq!(fixture.movement_count(), 0);
+    }
+
+    #[should_panic(expected = "ApiError::User(1402) [66938]")]
+    #[test]
+    fn should_not_unregister_the_hook_for_an_account_which_is_not_served() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        fixture.unregister_for(Key::from(fixture.bob), Sender(fixture.bob));
+    }
+
+    #[should_panic(expected = "ApiError::User(1403) [66939]")]
+    #[test]
+    fn should_not_receive_from_a_sender_out_of_the_allowlist() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let holder = fixture.bob;
+
+        fixture.send(Key::from(holder), U256::from(100), Bytes::default(), Sender(owner));
+        fixture.set_sender_allowlist(vec![Key::from(fixture.joe)], Sender(owner));
+        fixture.send(Key::from(owner), U256::from(10), Bytes::default(), Sender(holder));
+    }
+
+    #[should_panic(expected = "ApiError::User(1404) [66940]")]
+    #[test]
+    fn should_not_receive_less_than_the_minimum_amount() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let holder = fixture.bob;
+
+        fixture.send(Key::from(holder), U256::from(100), Bytes::default(), Sender(owner));
+        fixture.set_min_amount(U256::from(10), Sender(owner));
+        fixture.send(Key::from(owner), U256::from(9), Bytes::default(), Sender(holder));
+    }
+
+    #[should_panic(expected = "ApiError::User(1405) [66941]")]
+    #[test]
+    fn should_not_receive_more_than_the_maximum_amount() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let holder = fixture.bob;
+
+        fixture.send(Key::from(holder), U256::from(100), Bytes::default(), Sender(owner));
+        fixture.set_max_amount(U256::from(10), Sender(owner));
+        fixture.send(Key::from(owner), U256::from(11), Bytes::default(), Sender(holder));
+    }
+
+    #[should_panic(expected = "ApiError::User(1406) [66942]")]
+    #[test]
+    fn should_not_receive_without_the_data_prefix() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let holder = fixture.bob;
+
+        fixture.send(Key::from(holder), U256::from(100), Bytes::default(), Sender(owner));
+        fixture.set_data_prefix(Bytes::from(vec![1u8, 2]), Sender(owner));
+        fixture.send(Key::from(owner), U256::from(10), Bytes::from(vec![2u8, 1]), Sender(holder));
+    }
+
+    #[test]
+    fn should_receive_within_the_receive_policies() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let holder = fixture.bob;
+
+        fixture.send(Key::from(holder), U256::from(100), Bytes::default(), Sender(owner));
+        fixture.set_sender_allowlist(vec![Key::from(holder)], Sender(owner));
+        fixture.set_min_amount(U256::from(5), Sender(owner));
+        fixture.set_max_amount(U256::from(10), Sender(owner));
+        fixture.set_data_prefix(Bytes::from(vec![1u8]), Sender(owner));
+
+        fixture.send(Key::from(owner), U256::from(10), Bytes::from(vec![1u8, 2]), Sender(holder));
+        assert_eq!(fixture.balance_of(Key::from(holder)), Some(U256::from(90)));
+    }
+
+    #[should_panic(expected = "ApiError::User(1402) [66938]")]
+    #[test]
+    fn should_not_set_receive_policies_from_an_account_which_is_not_served() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        fixture.set_min_amount(U256::from(10), Sender(fixture.bob));
+    }
+    #[test]
+    fn should_mark_an_invoice_paid() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let payer = fixture.bob;
+        let token = fixture.token_package();
+
+        fixture.send(Key::from(payer), U256::from(100), Bytes::default(), Sender(owner));
+        fixture.create_invoice("inv-1", token, U256::from(40), 0, Sender(owner));
+        assert_eq!(fixture.invoice("inv-1").unwrap().status, InvoiceStatus::Open);
+
+        fixture.send(Key::from(owner), U256::from(40), Bytes::from(b"invoice:inv-1".to_vec()), Sender(payer));
+
+        let invoice = fixture.invoice("inv-1").unwrap();
+        assert_eq!(invoice.status, InvoiceStatus::Paid);
+        assert_eq!(invoice.paid, U256::from(40));
+        assert_eq!(fixture.balance_of(Key::from(payer)), Some(U256::from(60)));
+    }
+
+    #[should_panic(expected = "ApiError::User(1409) [66945]")]
+    #[test]
+    fn should_not_pay_an_invoice_twice() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let payer = fixture.bob;
+        let token = fixture.token_package();
+
+        fixture.send(Key::from(payer), U256::from(100), Bytes::default(), Sender(owner));
+        fixture.create_invoice("inv-1", token, U256::from(40), 0, Sender(owner));
+        fixture.send(Key::from(owner), U256::from(40), Bytes::from(b"invoice:inv-1".to_vec()), Sender(payer));
+        fixture.send(Key::from(owner), U256::from(40), Bytes::from(b"invoice:inv-1".to_vec()), Sender(payer));
+    }
+
+    #[should_panic(expected = "ApiError::User(1413) [66949]")]
+    #[test]
+    fn should_not_underpay_an_invoice() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let payer = fixture.bob;
+        let token = fixture.token_package();
+
+        fixture.send(Key::from(payer), U256::from(100), Bytes::default(), Sender(owner));
+        fixture.create_invoice("inv-1", token, U256::from(40), 0, Sender(owner));
+        fixture.send(Key::from(owner), U256::from(30), Bytes::from(b"invoice:inv-1".to_vec()), Sender(payer));
+    }
+
+    #[should_panic(expected = "ApiError::User(1412) [66948]")]
+    #[test]
+    fn should_not_overpay_an_invoice() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let payer = fixture.bob;
+        let token = fixture.token_package();
+
+        fixture.send(Key::from(payer), U256::from(100), Bytes::default(), Sender(owner));
+        fixture.create_invoice("inv-1", token, U256::from(40), 0, Sender(owner));
+        fixture.send(Key::from(owner), U256::from(50), Bytes::from(b"invoice:inv-1".to_vec()), Sender(payer));
+    }
+
+    #[test]
+    fn should_pay_an_invoice_in_parts_when_underpayments_are_allowed() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let payer = fixture.bob;
+        let token = fixture.token_package();
+
+        fixture.send(Key::from(payer), U256::from(100), Bytes::default(), Sender(owner));
+        fixture.create_invoice("inv-1", token, U256::from(40), 0, Sender(owner));
+        fixture.set_invoice_rules(true, false, true, Sender(owner));
+
+        fixture.send(Key::from(owner), U256::from(30), Bytes::from(b"invoice:inv-1".to_vec()), Sender(payer));
+        let invoice = fixture.invoice("inv-1").unwrap();
+        assert_eq!(invoice.status, InvoiceStatus::Open);
+        assert_eq!(invoice.paid, U256::from(30));
+
+        fixture.send(Key::from(owner), U256::from(10), Bytes::from(b"invoice:inv-1".to_vec()), Sender(payer));
+        assert_eq!(fixture.invoice("inv-1").unwrap().status, InvoiceStatus::Paid);
+    }
+
+    #[should_panic(expected = "ApiError::User(1408) [66944]")]
+    #[test]
+    fn should_not_pay_an_unknown_invoice() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let payer = fixture.bob;
+
+        fixture.send(Key::from(payer), U256::from(100), Bytes::default(), Sender(owner));
+        fixture.send(Key::from(owner), U256::from(40), Bytes::from(b"invoice:inv-2".to_vec()), Sender(payer));
+    }
+
+    #[should_panic(expected = "ApiError::User(1414) [66950]")]
+    #[test]
+    fn should_not_pay_an_invoice_with_an_invalid_id() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let payer = fixture.bob;
+
+        let mut data = b"invoice:".to_vec();
+        data.extend_from_slice(&[0xff, 0xfe]);
+        fixture.send(Key::from(payer), U256::from(100), Bytes::default(), Sender(owner));
+        fixture.send(Key::from(owner), U256::from(40), Bytes::from(data), Sender(payer));
+    }
+
+    #[test]
+    fn should_not_pay_an_invoice_with_a_movement_to_another_served_account() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let owner = fixture.ali;
+        let holder = fixture.bob;
+        let token = fixture.token_package();
+
+        fixture.register_for(Key::from(holder), Sender(holder));
+        let hook = Key::from(fixture.contract_hash_erc777_recipient());
+        fixture.set_interface_implementer(
+            Key::from(holder),
+            casper_erc777_recipient::constants::HASH_ERC1820_RECIPIENT,
+            hook,
+            Sender(holder)
+        );
+        fixture.create_invoice("inv-1", token, U256::from(40), 0, Sender(owner));
+
+        fixture.send(Key::from(holder), U256::from(40), Bytes::from(b"invoice:inv-1".to_vec()), Sender(owner));
+
+        assert_eq!(fixture.invoice("inv-1").unwrap().status, InvoiceStatus::Open);
+        assert_eq!(fixture.balance_of(Key::from(holder)), Some(U256::from(40)));
+    }
+
+    #[should_panic(expected = "ApiError::User(1400) [66936]")]
+    #[test]
+    fn should_not_create_an_invoice_from_another_account() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_recipient_context();
+
+        let token = fixture.token_package();
+        fixture.create_invoice("inv-1", token, U256::from(40), 0, Sender(fixture.bob));
+    }
 }
 
 fn main() {
@@ -2,6 +2,8 @@ use blake2::{
     digest::{Update, VariableOutput},
     VarBlake2b,
 };
+use std::collections::BTreeMap;
+
 use casper_engine_test_support::{Code, SessionBuilder, TestContext, TestContextBuilder};
 use casper_types::{
     account::AccountHash, bytesrepr::{FromBytes, ToBytes},
@@ -96,11 +98,9 @@ impl TestFixture {
         println!("Install of erc777 recipient");
         //----- ERC777 RECIPIENT
         let erc1820_contract_hash = self.contract_hash_erc1820();
-        let erc777_contract_hash = self.contract_hash_erc20();
         let session_code = Code::from(ERC777_RECIPIENT_CONTRACT_WASM);
         let session_args = runtime_args! {
-            casper_erc777_recipient::constants::ERC1820_CONTRACT_ARG_NAME => erc1820_contract_hash,
-            casper_erc777_recipient::constants::ERC777_CONTRACT_ARG_NAME => erc777_contract_hash
+            casper_erc777_recipient::constants::ERC1820_CONTRACT_ARG_NAME => erc1820_contract_hash
         };
 
         let session = SessionBuilder::new(session_code, session_args)
@@ -109,6 +109,9 @@ impl TestFixture {
             .build();
 
         self.context.run(session);
+
+        let token = self.token_package();
+        self.add_token(token, Sender(self.ali));
     }
 
     pub fn contract_hash_erc1820(&self) -> ContractHash {
@@ -160,6 +163,34 @@ impl TestFixture {
         self.context.run(session);
     }
 
+    //---- ERC1820 Calls
+    pub fn set_interface_implementer(&mut self, account: Key, interface_name: &str, implementer: Key, sender: Sender) {
+        let i_hash = Bytes::from(blake2b256(interface_name.as_bytes()).to_vec());
+        self.call(
+            sender,
+            self.contract_hash_erc1820().value(),
+            casper_erc1820::constants::SET_INTERFACE_ENTRY_POINT,
+            runtime_args! {
+                casper_erc1820::constants::ACCOUNT_RUNTIME_ARG_NAME => account,
+                casper_erc1820::constants::I_HASH_RUNTIME_ARG_NAME => i_hash,
+                casper_erc1820::constants::IMPLEMENTER_RUNTIME_ARG_NAME => implementer
+            },
+        );
+    }
+
+    pub fn clear_interface_implementer(&mut self, account: Key, interface_name: &str, sender: Sender) {
+        let i_hash = Bytes::from(blake2b256(interface_name.as_bytes()).to_vec());
+        self.call(
+            sender,
+            self.contract_hash_erc1820().value(),
+            casper_erc1820::constants::CLEAR_INTERFACE_ENTRY_POINT,
+            runtime_args! {
+                casper_erc1820::constants::ACCOUNT_RUNTIME_ARG_NAME => account,
+                casper_erc1820::constants::I_HASH_RUNTIME_ARG_NAME => i_hash
+            },
+        );
+    }
+
     //---- ERC777 Calls
     fn query_contract_erc20<T: CLTyped + FromBytes>(&self, name: &str) -> Option<T> {
         match self
@@ -333,6 +364,7 @@ impl TestFixture {
             self.contract_hash_erc777_recipient().value(),
             casper_erc777_recipient::constants::TRANSFER_ENTRY_POINT,
             runtime_args! {
+                casper_erc777_recipient::constants::TOKEN_RUNTIME_ARG_NAME => self.token_package(),
                 casper_erc777_recipient::constants::FROM_RUNTIME_ARG_NAME => from,
                 casper_erc777_recipient::constants::TO_RUNTIME_ARG_NAME => to,
                 casper_erc777_recipient::constants::AMOUNT_RUNTIME_ARG_NAME => amount,
@@ -355,6 +387,7 @@ impl TestFixture {
             self.contract_hash_erc777_recipient().value(),
             casper_erc777_recipient::constants::BURN_ENTRY_POINT,
             runtime_args! {
+                casper_erc777_recipient::constants::TOKEN_RUNTIME_ARG_NAME => self.token_package(),
                 casper_erc777_recipient::constants::ACCOUNT_RUNTIME_ARG_NAME => account,
                 casper_erc777_recipient::constants::AMOUNT_RUNTIME_ARG_NAME => amount,
                 casper_erc777_recipient::constants::USER_DATA_RUNTIME_ARG_NAME => user_data,
@@ -362,4 +395,230 @@ impl TestFixture {
             },
         );
     }
+
+    //------ ERC777 Recipient Tokens
+    /// Key of the token contract package, which is how the recipient contract identifies the token.
+    pub fn token_package(&self) -> Key {
+        let package_hash = self.context
+            .get_account(self.ali)
+            .unwrap()
+            .named_keys()
+            .get(&format!("{}{}", ERC20_CONTRACT_NAME, casper_erc777::constants::PACKAGE_HASH_KEY_NAME_SUFFIX))
+            .unwrap()
+            .into_hash()
+            .unwrap();
+        Key::Hash(package_hash)
+    }
+
+    fn call_erc777_recipient(&mut self, method: &str, args: RuntimeArgs, sender: Sender) {
+        self.call(sender, self.contract_hash_erc777_recipient().value(), method, args);
+    }
+
+    pub fn register_for(&mut self, account: Key, sender: Sender) {
+        self.call_erc777_recipient(
+            casper_erc777_recipient::constants::REGISTER_FOR_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_recipient::constants::ACCOUNT_RUNTIME_ARG_NAME => account
+            },
+            sender
+        );
+    }
+
+    pub fn unregister_for(&mut self, account: Key, sender: Sender) {
+        self.call_erc777_recipient(
+            casper_erc777_recipient::constants::UNREGISTER_FOR_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_recipient::constants::ACCOUNT_RUNTIME_ARG_NAME => account
+            },
+            sender
+        );
+    }
+
+    pub fn served_accounts(&self) -> Vec<Key> {
+        // The served accounts are an indexed list, a count then one item per index.
+        let count: u64 = self.served_accounts_item(0u8, &[]).unwrap_or_default();
+        (0..count)
+            .map(|index| self.served_accounts_item(1u8, &index.to_le_bytes()).unwrap())
+            .collect()
+    }
+
+    fn served_accounts_item<T: CLTyped + FromBytes>(&self, tag: u8, suffix: &[u8]) -> Option<T> {
+        let mut preimage = vec![tag];
+        preimage.append(&mut casper_erc777_recipient::constants::SERVED_ACCOUNTS_LIST_OWNER.to_bytes().unwrap());
+        preimage.extend_from_slice(suffix);
+        let item_key = hex::encode(&blake2b256(&preimage));
+
+        let key = Key::Hash(self.contract_hash_erc777_recipient().value());
+        let value = self
+            .context
+            .query_dictionary_item(
+                key,
+                Some(casper_erc777_recipient::constants::SERVED_ACCOUNTS_KEY_NAME.to_string()),
+                item_key
+            ).ok()?;
+
+        Some(value.into_t::<T>().unwrap())
+    }
+
+    pub fn add_token(&mut self, token: Key, sender: Sender) {
+        self.call_erc777_recipient(
+            casper_erc777_recipient::constants::ADD_TOKEN_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_recipient::constants::TOKEN_RUNTIME_ARG_NAME => token
+            },
+            sender
+        );
+    }
+
+    pub fn remove_token(&mut self, token: Key, sender: Sender) {
+        self.call_erc777_recipient(
+            casper_erc777_recipient::constants::REMOVE_TOKEN_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_recipient::constants::TOKEN_RUNTIME_ARG_NAME => token
+            },
+            sender
+        );
+    }
+
+    pub fn add_delegate(&mut self, delegate: Key, sender: Sender) {
+        self.call_erc777_recipient(
+            casper_erc777_recipient::constants::ADD_DELEGATE_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_recipient::constants::DELEGATE_RUNTIME_ARG_NAME => delegate
+            },
+            sender
+        );
+    }
+
+    pub fn remove_delegate(&mut self, delegate: Key, sender: Sender) {
+        self.call_erc777_recipient(
+            casper_erc777_recipient::constants::REMOVE_DELEGATE_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_recipient::constants::DELEGATE_RUNTIME_ARG_NAME => delegate
+            },
+            sender
+        );
+    }
+
+    //------ ERC777 Recipient Receive Policies
+    pub fn set_sender_allowlist(&mut self, senders: Vec<Key>, sender: Sender) {
+        self.call_erc777_recipient(
+            casper_erc777_recipient::constants::SET_SENDER_ALLOWLIST_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_recipient::constants::SENDERS_RUNTIME_ARG_NAME => senders
+            },
+            sender
+        );
+    }
+
+    pub fn set_min_amount(&mut self, amount: U256, sender: Sender) {
+        self.call_erc777_recipient(
+            casper_erc777_recipient::constants::SET_MIN_AMOUNT_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_recipient::constants::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+            sender
+        );
+    }
+
+    pub fn set_max_amount(&mut self, amount: U256, sender: Sender) {
+        self.call_erc777_recipient(
+            casper_erc777_recipient::constants::SET_MAX_AMOUNT_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_recipient::constants::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+            sender
+        );
+    }
+
+    pub fn set_data_prefix(&mut self, prefix: Bytes, sender: Sender) {
+        self.call_erc777_recipient(
+            casper_erc777_recipient::constants::SET_DATA_PREFIX_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_recipient::constants::PREFIX_RUNTIME_ARG_NAME => prefix
+            },
+            sender
+        );
+    }
+
+    //------ ERC777 Recipient Invoices
+    pub fn create_invoice(
+        &mut self,
+        invoice_id: &str,
+        token: Key,
+        amount: U256,
+        expiry: u64,
+        sender: Sender
+    ) {
+        self.call_erc777_recipient(
+            casper_erc777_recipient::constants::CREATE_INVOICE_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_recipient::constants::INVOICE_ID_RUNTIME_ARG_NAME => invoice_id.to_string(),
+                casper_erc777_recipient::constants::TOKEN_RUNTIME_ARG_NAME => token,
+                casper_erc777_recipient::constants::AMOUNT_RUNTIME_ARG_NAME => amount,
+                casper_erc777_recipient::constants::EXPIRY_RUNTIME_ARG_NAME => expiry
+            },
+            sender
+        );
+    }
+
+    pub fn set_invoice_rules(
+        &mut self,
+        reject_overpayment: bool,
+        reject_underpayment: bool,
+        reject_expired: bool,
+        sender: Sender
+    ) {
+        self.call_erc777_recipient(
+            casper_erc777_recipient::constants::SET_INVOICE_RULES_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_recipient::constants::REJECT_OVERPAYMENT_RUNTIME_ARG_NAME => reject_overpayment,
+                casper_erc777_recipient::constants::REJECT_UNDERPAYMENT_RUNTIME_ARG_NAME => reject_underpayment,
+                casper_erc777_recipient::constants::REJECT_EXPIRED_RUNTIME_ARG_NAME => reject_expired
+            },
+            sender
+        );
+    }
+
+    pub fn invoice(&self, invoice_id: &str) -> Option<casper_erc777_recipient::Invoice> {
+        let key = Key::Hash(self.contract_hash_erc777_recipient().value());
+        let item_key = hex::encode(blake2b256(invoice_id.as_bytes()));
+        let value = self
+            .context
+            .query_dictionary_item(
+                key,
+                Some(casper_erc777_recipient::constants::INVOICES_REGISTRY_KEY_NAME.to_string()),
+                item_key
+            ).ok()?;
+
+        Some(value.into_t::<casper_erc777_recipient::Invoice>().unwrap())
+    }
+
+    //------ ERC777 Recipient Movements
+    pub fn movement_count(&self) -> u64 {
+        self.context
+            .query(
+                self.ali,
+                &[
+                    ERC777_RECIPIENT_CONTRACT_NAME.to_string(),
+                    casper_erc777_recipient::constants::MOVEMENT_COUNT_KEY_NAME.to_string()
+                ]
+            )
+            .unwrap()
+            .into_t::<u64>()
+            .unwrap()
+    }
+
+    pub fn movement(&self, index: u64) -> Option<BTreeMap<String, String>> {
+        let key = Key::Hash(self.contract_hash_erc777_recipient().value());
+        let value = self
+            .context
+            .query_dictionary_item(
+                key,
+                Some(casper_erc777_recipient::constants::BALANCES_REGISTRY_KEY_NAME.to_string()),
+                index.to_string()
+            ).ok()?;
+
+        Some(value.into_t::<BTreeMap<String, String>>().unwrap())
+    }
 }
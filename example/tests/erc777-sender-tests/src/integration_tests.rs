@@ -230,6 +230,8 @@ mod tests {
             Some(base64::encode(expected_operator))
         );
 
+        fixture.add_delegate(Key::from(operator), Sender(owner));
+
         fixture.transfer_from_erc777_sender(
             Key::from(owner),
             Key::from(recipient),
@@ -277,6 +279,8 @@ mod tests {
             Some(base64::encode(expected_operator))
         );
 
+        fixture.add_delegate(Key::from(operator), Sender(owner));
+
         fixture.burn_from_erc777_sender(
             Key::from(owner),
             U256::one(),
@@ -361,6 +365,506 @@ mod tests {
         );
     }
 
+    #[should_panic(expected = "ApiError::User(1004) [66540]")]
+    #[test]
+    fn should_not_register_the_sender_contract_for_another_account() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let bob = fixture.bob;
+        let sender_contract = Key::from(fixture.contract_hash_erc777_sender());
+
+        fixture.set_interface_implementer(
+            Key::from(bob),
+            casper_erc777_sender::constants::HASH_ERC1820_SENDER,
+            sender_contract,
+            Sender(bob)
+        );
+    }
+
+    #[test]
+    fn should_send_within_the_send_policies() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        let recipient = fixture.bob;
+
+        fixture.set_recipient_allowlist(vec![Key::from(recipient)], Sender(owner));
+        fixture.set_max_amount(U256::from(10), Sender(owner));
+        fixture.set_data_prefix(Bytes::from(vec![1u8, 2]), Sender(owner));
+
+        fixture.send(Key::from(recipient), U256::from(10), Bytes::from(vec![1u8, 2, 3]), Sender(owner));
+
+        assert_eq!(fixture.balance_of(Key::from(recipient)), Some(U256::from(10)));
+    }
+
+    #[should_panic(expected = "ApiError::User(1308) [66844]")]
+    #[test]
+    fn should_not_set_send_policies_from_an_account_which_is_not_served() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let bob = fixture.bob;
+        fixture.set_max_amount(U256::one(), Sender(bob));
+    }
+
+    #[should_panic(expected = "ApiError::User(1303) [66839]")]
+    #[test]
+    fn should_apply_the_send_policies_of_the_served_account_which_set_them() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        let holder = fixture.bob;
+
+        fixture.send(Key::from(holder), U256::from(10), Bytes::default(), Sender(owner));
+        fixture.register_for(Key::from(holder), Sender(holder));
+        let hook = Key::from(fixture.contract_hash_erc777_sender());
+        fixture.set_interface_implementer(
+            Key::from(holder),
+            casper_erc777_sender::constants::HASH_ERC1820_SENDER,
+            hook,
+            Sender(holder)
+        );
+        fixture.set_max_amount(U256::from(5), Sender(holder));
+
+        fixture.send(Key::from(fixture.joe), U256::from(10), Bytes::default(), Sender(owner));
+        assert_eq!(fixture.balance_of(Key::from(fixture.joe)), Some(U256::from(10)));
+
+        fixture.send(Key::from(fixture.joe), U256::from(6), Bytes::default(), Sender(holder));
+    }
+
+    #[should_panic(expected = "ApiError::User(1301) [66837]")]
+    #[test]
+    fn should_not_send_to_a_recipient_out_of_the_allowlist() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        fixture.set_recipient_allowlist(vec![Key::from(fixture.bob)], Sender(owner));
+        fixture.send(Key::from(fixture.joe), U256::one(), Bytes::default(), Sender(owner));
+    }
+
+    #[should_panic(expected = "ApiError::User(1302) [66838]")]
+    #[test]
+    fn should_not_send_to_a_blocked_recipient() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        fixture.set_recipient_blocklist(vec![Key::from(fixture.bob)], Sender(owner));
+        fixture.send(Key::from(fixture.bob), U256::one(), Bytes::default(), Sender(owner));
+    }
+
+    #[should_panic(expected = "ApiError::User(1303) [66839]")]
+    #[test]
+    fn should_not_send_more_than_the_maximum_amount() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        fixture.set_max_amount(U256::from(10), Sender(owner));
+        fixture.send(Key::from(fixture.bob), U256::from(11), Bytes::default(), Sender(owner));
+    }
+
+    #[should_panic(expected = "ApiError::User(1304) [66840]")]
+    #[test]
+    fn should_not_send_without_the_data_prefix() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        fixture.set_data_prefix(Bytes::from(vec![1u8, 2]), Sender(owner));
+        fixture.send(Key::from(fixture.bob), U256::one(), Bytes::from(vec![2u8, 1]), Sender(owner));
+    }
+
+    #[should_panic(expected = "ApiError::User(1305) [66841]")]
+    #[test]
+    fn should_not_send_from_an_operator_out_of_the_allowlist() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        let operator = fixture.joe;
+        fixture.authorize_operator(Key::from(operator), Sender(owner));
+        fixture.set_operator_allowlist(vec![Key::from(fixture.bob)], Sender(owner));
+
+        fixture.operator_send(
+            Key::from(owner),
+            Key::from(fixture.bob),
+            U256::one(),
+            Bytes::default(),
+            Bytes::default(),
+            Sender(operator)
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(1305) [66841]")]
+    #[test]
+    fn should_not_burn_from_an_operator_out_of_the_allowlist() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        let operator = fixture.joe;
+        fixture.authorize_operator(Key::from(operator), Sender(owner));
+        fixture.set_operator_allowlist(vec![Key::from(fixture.bob)], Sender(owner));
+
+        fixture.operator_burn(
+            Key::from(owner),
+            U256::one(),
+            Bytes::default(),
+            Bytes::default(),
+            Sender(operator)
+        );
+    }
+
+    #[test]
+    fn should_send_from_an_operator_in_the_allowlist() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        let operator = fixture.bob;
+        fixture.authorize_operator(Key::from(operator), Sender(owner));
+        fixture.set_operator_allowlist(vec![Key::from(operator)], Sender(owner));
+
+        fixture.operator_send(
+            Key::from(owner),
+            Key::from(fixture.joe),
+            U256::one(),
+            Bytes::default(),
+            Bytes::default(),
+            Sender(operator)
+        );
+        assert_eq!(fixture.balance_of(Key::from(fixture.joe)), Some(U256::one()));
+    }
+
+    #[test]
+    fn should_send_up_to_the_daily_limit() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        let token = fixture.token_package();
+
+        fixture.set_daily_limit(token, U256::from(10), Sender(owner));
+        fixture.send(Key::from(fixture.bob), U256::from(6), Bytes::default(), Sender(owner));
+        fixture.send(Key::from(fixture.joe), U256::from(4), Bytes::default(), Sender(owner));
+
+        let (_window_start, spent) = fixture.daily_spent(Key::from(owner), token).unwrap();
+        assert_eq!(spent, U256::from(10));
+    }
+
+    #[should_panic(expected = "ApiError::User(1306) [66842]")]
+    #[test]
+    fn should_not_send_over_the_daily_limit() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        let token = fixture.token_package();
+
+        fixture.set_daily_limit(token, U256::from(10), Sender(owner));
+        fixture.send(Key::from(fixture.bob), U256::from(6), Bytes::default(), Sender(owner));
+        fixture.send(Key::from(fixture.bob), U256::from(5), Bytes::default(), Sender(owner));
+    }
+    #[should_panic(expected = "ApiError::User(1306) [66842]")]
+    #[test]
+    fn should_not_send_over_the_daily_limit_across_midnight() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        let token = fixture.token_package();
+        let midnight = casper_erc777_sender::constants::DAY_IN_MILLISECONDS;
+
+        fixture.set_daily_limit(token, U256::from(10), Sender(owner));
+        fixture.send_at(Key::from(fixture.bob), U256::from(8), Bytes::default(), midnight - 1_000, Sender(owner));
+        fixture.send_at(Key::from(fixture.bob), U256::from(8), Bytes::default(), midnight + 1_000, Sender(owner));
+    }
+
+    #[test]
+    fn should_send_up_to_the_daily_limit_again_after_24_hours() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        let token = fixture.token_package();
+        let day = casper_erc777_sender::constants::DAY_IN_MILLISECONDS;
+        let first_send = day - 1_000;
+
+        fixture.set_daily_limit(token, U256::from(10), Sender(owner));
+        fixture.send_at(Key::from(fixture.bob), U256::from(8), Bytes::default(), first_send, Sender(owner));
+        fixture.send_at(Key::from(fixture.bob), U256::from(8), Bytes::default(), first_send + day, Sender(owner));
+
+        let (window_start, spent) = fixture.daily_spent(Key::from(owner), token).unwrap();
+        assert_eq!(window_start, first_send + day);
+        assert_eq!(spent, U256::from(8));
+        assert_eq!(fixture.balance_of(Key::from(fixture.bob)), Some(U256::from(16)));
+    }
+
+    #[should_panic(expected = "ApiError::User(1306) [66842]")]
+    #[test]
+    fn should_not_transfer_over_the_daily_limit() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        let token = fixture.token_package();
+
+        fixture.set_daily_limit(token, U256::from(10), Sender(owner));
+        fixture.transfer(Key::from(fixture.bob), U256::from(6), Sender(owner));
+        fixture.transfer(Key::from(fixture.bob), U256::from(5), Sender(owner));
+    }
+
+    #[should_panic(expected = "ApiError::User(1306) [66842]")]
+    #[test]
+    fn should_not_transfer_from_over_the_daily_limit() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        let spender = fixture.bob;
+        let token = fixture.token_package();
+
+        fixture.set_daily_limit(token, U256::from(10), Sender(owner));
+        fixture.approve(Key::from(spender), U256::from(20), Sender(owner));
+        fixture.transfer_from(Key::from(owner), Key::from(fixture.joe), U256::from(11), Sender(spender));
+    }
+
+    #[test]
+    fn should_record_the_movements_sent_by_the_owner() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        let token = fixture.token_package();
+
+        fixture.send(Key::from(fixture.bob), U256::from(6), Bytes::from(vec![1u8, 2]), Sender(owner));
+        fixture.send(Key::from(fixture.joe), U256::from(4), Bytes::default(), Sender(owner));
+
+        assert_eq!(fixture.movement_count(), 2);
+
+        let first = fixture.movement(0).unwrap();
+        assert_eq!(first.get("sequence").unwrap(), "0");
+        assert_eq!(first.get("token").unwrap(), &token.to_formatted_string());
+        assert_eq!(first.get("from").unwrap(), &Key::from(owner).to_formatted_string());
+        assert_eq!(first.get("to").unwrap(), &Key::from(fixture.bob).to_formatted_string());
+        assert_eq!(first.get("amount").unwrap(), "6");
+        assert_eq!(first.get("data").unwrap(), "0102");
+
+        let second = fixture.movement(1).unwrap();
+        assert_eq!(second.get("to").unwrap(), &Key::from(fixture.joe).to_formatted_string());
+        assert_eq!(second.get("amount").unwrap(), "4");
+    }
+
+    #[test]
+    fn should_record_the_operator_of_the_movements_sent_on_behalf_of_the_owner() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        let operator = fixture.bob;
+
+        fixture.authorize_operator(Key::from(operator), Sender(owner));
+        fixture.operator_send(
+            Key::from(owner),
+            Key::from(fixture.joe),
+            U256::from(3),
+            Bytes::default(),
+            Bytes::from(vec![9u8]),
+            Sender(operator)
+        );
+
+        let movement = fixture.movement(0).unwrap();
+        assert_eq!(movement.get("operator").unwrap(), &Key::from(operator).to_formatted_string());
+        assert_eq!(movement.get("from").unwrap(), &Key::from(owner).to_formatted_string());
+        assert_eq!(movement.get("to").unwrap(), &Key::from(fixture.joe).to_formatted_string());
+        assert_eq!(movement.get("operator_data").unwrap(), "09");
+    }
+
+    #[should_panic(expected = "ApiError::User(1307) [66843]")]
+    #[test]
+    fn should_not_send_a_token_which_is_not_registered() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        let token = fixture.token_package();
+
+        fixture.remove_token(token, Sender(owner));
+        fixture.send(Key::from(fixture.bob), U256::one(), Bytes::default(), Sender(owner));
+    }
+
+    #[should_panic(expected = "ApiError::User(1300) [66836]")]
+    #[test]
+    fn should_not_register_a_token_from_another_account() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let token = fixture.token_package();
+        fixture.add_token(token, Sender(fixture.bob));
+    }
+
+    #[should_panic(expected = "ApiError::User(1300) [66836]")]
+    #[test]
+    fn should_not_transfer_from_an_account_which_is_not_a_delegate() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        let operator = fixture.bob;
+
+        fixture.authorize_operator(Key::from(operator), Sender(owner));
+        fixture.transfer_from_erc777_sender(
+            Key::from(owner),
+            Key::from(operator),
+            U256::one(),
+            Bytes::default(),
+            Bytes::default(),
+            Sender(operator)
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(1300) [66836]")]
+    #[test]
+    fn should_not_burn_from_a_removed_delegate() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        let operator = fixture.bob;
+
+        fixture.authorize_operator(Key::from(operator), Sender(owner));
+        fixture.add_delegate(Key::from(operator), Sender(owner));
+        fixture.remove_delegate(Key::from(operator), Sender(owner));
+        fixture.burn_from_erc777_sender(
+            Key::from(owner),
+            U256::one(),
+            Bytes::default(),
+            Bytes::default(),
+            Sender(operator)
+        );
+    }
+
+    #[test]
+    fn should_serve_an_account_which_registers_the_hook() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        let holder = fixture.bob;
+
+        fixture.send(Key::from(holder), U256::from(10), Bytes::default(), Sender(owner));
+        fixture.register_for(Key::from(holder), Sender(holder));
+        let hook = Key::from(fixture.contract_hash_erc777_sender());
+        fixture.set_interface_implementer(
+            Key::from(holder),
+            casper_erc777_sender::constants::HASH_ERC1820_SENDER,
+            hook,
+            Sender(holder)
+        );
+        assert_eq!(fixture.served_accounts(), vec![Key::from(owner), Key::from(holder)]);
+
+        fixture.send(Key::from(fixture.joe), U256::from(4), Bytes::default(), Sender(holder));
+        assert_eq!(fixture.movement_count(), 2);
+
+        let movement = fixture.movement(1).unwrap();
+        assert_eq!(movement.get("from").unwrap(), &Key::from(holder).to_formatted_string());
+        assert_eq!(movement.get("amount").unwrap(), "4");
+    }
+
+    #[test]
+    fn should_stop_serving_an_account_which_unregisters_the_hook() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        let owner = fixture.ali;
+        let holder = fixture.bob;
+
+        fixture.send(Key::from(holder), U256::from(10), Bytes::default(), Sender(owner));
+        fixture.register_for(Key::from(holder), Sender(holder));
+        let hook = Key::from(fixture.contract_hash_erc777_sender());
+        fixture.set_interface_implementer(
+            Key::from(holder),
+            casper_erc777_sender::constants::HASH_ERC1820_SENDER,
+            hook,
+            Sender(holder)
+        );
+        fixture.unregister_for(Key::from(holder), Sender(holder));
+        fixture.clear_interface_implementer(
+            Key::from(holder),
+            casper_erc777_sender::constants::HASH_ERC1820_SENDER,
+            Sender(holder)
+        );
+        assert_eq!(fixture.served_accounts(), vec![Key::from(owner)]);
+
+        fixture.send(Key::from(fixture.joe), U256::from(4), Bytes::default(), Sender(holder));
+        assert_eq!(fixture.movement_count(), 1);
+    }
+
+    #[should_panic(expected = "ApiError::User(1300) [66836]")]
+    #[test]
+    fn should_not_register_the_hook_from_an_account_which_is_not_the_manager() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+        fixture.add_erc777_sender_context();
+
+        fixture.register_for(Key::from(fixture.bob), Sender(fixture.joe));
+    }
 }
 
 fn main() {
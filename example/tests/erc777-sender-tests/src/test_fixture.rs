@@ -2,6 +2,8 @@ use blake2::{
     digest::{Update, VariableOutput},
     VarBlake2b,
 };
+use std::collections::BTreeMap;
+
 use casper_engine_test_support::{Code, SessionBuilder, TestContext, TestContextBuilder};
 use casper_types::{account::AccountHash, bytesrepr::{FromBytes, ToBytes}, runtime_args, AsymmetricType, CLTyped, ContractHash, Key, PublicKey, RuntimeArgs, U512, U256, HashAddr};
 use casper_types::bytesrepr::Bytes;
@@ -93,11 +95,9 @@ impl TestFixture {
         println!("Install of erc777 sender");
         //----- ERC777 RECIPIENT
         let erc1820_contract_hash = self.contract_hash_erc1820();
-        let erc777_contract_hash = self.contract_hash_erc20();
         let session_code = Code::from(ERC777_SENDER_CONTRACT_WASM);
         let session_args = runtime_args! {
-            casper_erc777_sender::constants::ERC1820_CONTRACT_ARG_NAME => erc1820_contract_hash,
-            casper_erc777_sender::constants::ERC777_CONTRACT_ARG_NAME => erc777_contract_hash
+            casper_erc777_sender::constants::ERC1820_CONTRACT_ARG_NAME => erc1820_contract_hash
         };
 
         let session = SessionBuilder::new(session_code, session_args)
@@ -106,6 +106,9 @@ impl TestFixture {
             .build();
 
         self.context.run(session);
+
+        let token = self.token_package();
+        self.add_token(token, Sender(self.ali));
     }
 
     pub fn contract_hash_erc1820(&self) -> ContractHash {
@@ -157,6 +160,45 @@ impl TestFixture {
         self.context.run(session);
     }
 
+    fn call_at(&mut self, sender: Sender, hash_addr: HashAddr, method: &str, args: RuntimeArgs, block_time: u64) {
+        let Sender(address) = sender;
+        let code = Code::Hash(hash_addr, method.to_string());
+        let session = SessionBuilder::new(code, args)
+            .with_address(address)
+            .with_authorization_keys(&[address])
+            .with_block_time(block_time)
+            .build();
+        self.context.run(session);
+    }
+
+    //---- ERC1820 Calls
+    pub fn set_interface_implementer(&mut self, account: Key, interface_name: &str, implementer: Key, sender: Sender) {
+        let i_hash = Bytes::from(blake2b256(interface_name.as_bytes()).to_vec());
+        self.call(
+            sender,
+            self.contract_hash_erc1820().value(),
+            casper_erc1820::constants::SET_INTERFACE_ENTRY_POINT,
+            runtime_args! {
+                casper_erc1820::constants::ACCOUNT_RUNTIME_ARG_NAME => account,
+                casper_erc1820::constants::I_HASH_RUNTIME_ARG_NAME => i_hash,
+                casper_erc1820::constants::IMPLEMENTER_RUNTIME_ARG_NAME => implementer
+            },
+        );
+    }
+
+    pub fn clear_interface_implementer(&mut self, account: Key, interface_name: &str, sender: Sender) {
+        let i_hash = Bytes::from(blake2b256(interface_name.as_bytes()).to_vec());
+        self.call(
+            sender,
+            self.contract_hash_erc1820().value(),
+            casper_erc1820::constants::CLEAR_INTERFACE_ENTRY_POINT,
+            runtime_args! {
+                casper_erc1820::constants::ACCOUNT_RUNTIME_ARG_NAME => account,
+                casper_erc1820::constants::I_HASH_RUNTIME_ARG_NAME => i_hash
+            },
+        );
+    }
+
     //---- ERC777 Calls
     fn query_contract_erc20<T: CLTyped + FromBytes>(&self, name: &str) -> Option<T> {
         match self
@@ -272,6 +314,65 @@ impl TestFixture {
         );
     }
 
+    pub fn transfer(&mut self, recipient: Key, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.contract_hash_erc20().value(),
+            casper_erc777::constants::TRANSFER_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777::constants::RECIPIENT_RUNTIME_ARG_NAME => recipient,
+                casper_erc777::constants::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+        );
+    }
+
+    pub fn approve(&mut self, spender: Key, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.contract_hash_erc20().value(),
+            casper_erc777::constants::APPROVE_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777::constants::SPENDER_RUNTIME_ARG_NAME => spender,
+                casper_erc777::constants::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+        );
+    }
+
+    pub fn transfer_from(&mut self, owner: Key, recipient: Key, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.contract_hash_erc20().value(),
+            casper_erc777::constants::TRANSFER_FROM_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777::constants::OWNER_RUNTIME_ARG_NAME => owner,
+                casper_erc777::constants::RECIPIENT_RUNTIME_ARG_NAME => recipient,
+                casper_erc777::constants::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+        );
+    }
+
+    /// Calls `send` in a block made at `block_time`.
+    pub fn send_at(
+        &mut self,
+        recipient: Key,
+        amount: U256,
+        data: Bytes,
+        block_time: u64,
+        sender: Sender
+    ) {
+        self.call_at(
+            sender,
+            self.contract_hash_erc20().value(),
+            casper_erc777::constants::SEND_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777::constants::RECIPIENT_RUNTIME_ARG_NAME => recipient,
+                casper_erc777::constants::AMOUNT_RUNTIME_ARG_NAME => amount,
+                casper_erc777::constants::DATA_RUNTIME_ARG_NAME => data
+            },
+            block_time
+        );
+    }
+
     pub fn operator_send(
         &mut self,
         sender: Key,
@@ -330,6 +431,7 @@ impl TestFixture {
             self.contract_hash_erc777_sender().value(),
             casper_erc777_sender::constants::TRANSFER_ENTRY_POINT,
             runtime_args! {
+                casper_erc777_sender::constants::TOKEN_RUNTIME_ARG_NAME => self.token_package(),
                 casper_erc777_sender::constants::FROM_RUNTIME_ARG_NAME => from,
                 casper_erc777_sender::constants::TO_RUNTIME_ARG_NAME => to,
                 casper_erc777_sender::constants::AMOUNT_RUNTIME_ARG_NAME => amount,
@@ -352,6 +454,7 @@ impl TestFixture {
             self.contract_hash_erc777_sender().value(),
             casper_erc777_sender::constants::BURN_ENTRY_POINT,
             runtime_args! {
+                casper_erc777_sender::constants::TOKEN_RUNTIME_ARG_NAME => self.token_package(),
                 casper_erc777_sender::constants::ACCOUNT_RUNTIME_ARG_NAME => account,
                 casper_erc777_sender::constants::AMOUNT_RUNTIME_ARG_NAME => amount,
                 casper_erc777_sender::constants::USER_DATA_RUNTIME_ARG_NAME => user_data,
@@ -359,4 +462,216 @@ impl TestFixture {
             },
         );
     }
+
+    //------ ERC777 Sender Policies
+    fn call_erc777_sender(&mut self, method: &str, args: RuntimeArgs, sender: Sender) {
+        self.call(sender, self.contract_hash_erc777_sender().value(), method, args);
+    }
+
+    pub fn register_for(&mut self, account: Key, sender: Sender) {
+        self.call_erc777_sender(
+            casper_erc777_sender::constants::REGISTER_FOR_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_sender::constants::ACCOUNT_RUNTIME_ARG_NAME => account
+            },
+            sender
+        );
+    }
+
+    pub fn unregister_for(&mut self, account: Key, sender: Sender) {
+        self.call_erc777_sender(
+            casper_erc777_sender::constants::UNREGISTER_FOR_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_sender::constants::ACCOUNT_RUNTIME_ARG_NAME => account
+            },
+            sender
+        );
+    }
+
+    pub fn served_accounts(&self) -> Vec<Key> {
+        // The served accounts are an indexed list, a count then one item per index.
+        let count: u64 = self.served_accounts_item(0u8, &[]).unwrap_or_default();
+        (0..count)
+            .map(|index| self.served_accounts_item(1u8, &index.to_le_bytes()).unwrap())
+            .collect()
+    }
+
+    fn served_accounts_item<T: CLTyped + FromBytes>(&self, tag: u8, suffix: &[u8]) -> Option<T> {
+        let mut preimage = vec![tag];
+        preimage.append(&mut casper_erc777_sender::constants::SERVED_ACCOUNTS_LIST_OWNER.to_bytes().unwrap());
+        preimage.extend_from_slice(suffix);
+        let item_key = hex::encode(&blake2b256(&preimage));
+
+        let key = Key::Hash(self.contract_hash_erc777_sender().value());
+        let value = self
+            .context
+            .query_dictionary_item(
+                key,
+                Some(casper_erc777_sender::constants::SERVED_ACCOUNTS_KEY_NAME.to_string()),
+                item_key
+            ).ok()?;
+
+        Some(value.into_t::<T>().unwrap())
+    }
+
+    pub fn add_token(&mut self, token: Key, sender: Sender) {
+        self.call_erc777_sender(
+            casper_erc777_sender::constants::ADD_TOKEN_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_sender::constants::TOKEN_RUNTIME_ARG_NAME => token
+            },
+            sender
+        );
+    }
+
+    pub fn remove_token(&mut self, token: Key, sender: Sender) {
+        self.call_erc777_sender(
+            casper_erc777_sender::constants::REMOVE_TOKEN_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_sender::constants::TOKEN_RUNTIME_ARG_NAME => token
+            },
+            sender
+        );
+    }
+
+    pub fn add_delegate(&mut self, delegate: Key, sender: Sender) {
+        self.call_erc777_sender(
+            casper_erc777_sender::constants::ADD_DELEGATE_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_sender::constants::DELEGATE_RUNTIME_ARG_NAME => delegate
+            },
+            sender
+        );
+    }
+
+    pub fn remove_delegate(&mut self, delegate: Key, sender: Sender) {
+        self.call_erc777_sender(
+            casper_erc777_sender::constants::REMOVE_DELEGATE_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_sender::constants::DELEGATE_RUNTIME_ARG_NAME => delegate
+            },
+            sender
+        );
+    }
+
+    pub fn set_recipient_allowlist(&mut self, recipients: Vec<Key>, sender: Sender) {
+        self.call_erc777_sender(
+            casper_erc777_sender::constants::SET_RECIPIENT_ALLOWLIST_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_sender::constants::RECIPIENTS_RUNTIME_ARG_NAME => recipients
+            },
+            sender
+        );
+    }
+
+    pub fn set_recipient_blocklist(&mut self, recipients: Vec<Key>, sender: Sender) {
+        self.call_erc777_sender(
+            casper_erc777_sender::constants::SET_RECIPIENT_BLOCKLIST_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_sender::constants::RECIPIENTS_RUNTIME_ARG_NAME => recipients
+            },
+            sender
+        );
+    }
+
+    pub fn set_max_amount(&mut self, amount: U256, sender: Sender) {
+        self.call_erc777_sender(
+            casper_erc777_sender::constants::SET_MAX_AMOUNT_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_sender::constants::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+            sender
+        );
+    }
+
+    pub fn set_data_prefix(&mut self, prefix: Bytes, sender: Sender) {
+        self.call_erc777_sender(
+            casper_erc777_sender::constants::SET_DATA_PREFIX_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_sender::constants::PREFIX_RUNTIME_ARG_NAME => prefix
+            },
+            sender
+        );
+    }
+
+    pub fn set_operator_allowlist(&mut self, operators: Vec<Key>, sender: Sender) {
+        self.call_erc777_sender(
+            casper_erc777_sender::constants::SET_OPERATOR_ALLOWLIST_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_sender::constants::OPERATORS_RUNTIME_ARG_NAME => operators
+            },
+            sender
+        );
+    }
+
+    //------ ERC777 Sender Daily Limits
+    /// Key of the token contract package, which is how the sender contract identifies the token.
+    pub fn token_package(&self) -> Key {
+        let package_hash = self.context
+            .get_account(self.ali)
+            .unwrap()
+            .named_keys()
+            .get(&format!("{}{}", ERC20_CONTRACT_NAME, casper_erc777::constants::PACKAGE_HASH_KEY_NAME_SUFFIX))
+            .unwrap()
+            .into_hash()
+            .unwrap();
+        Key::Hash(package_hash)
+    }
+
+    pub fn set_daily_limit(&mut self, token: Key, amount: U256, sender: Sender) {
+        self.call_erc777_sender(
+            casper_erc777_sender::constants::SET_DAILY_LIMIT_ENTRY_POINT,
+            runtime_args! {
+                casper_erc777_sender::constants::TOKEN_RUNTIME_ARG_NAME => token,
+                casper_erc777_sender::constants::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+            sender
+        );
+    }
+
+    pub fn daily_spent(&self, holder: Key, token: Key) -> Option<(u64, U256)> {
+        let mut preimage = holder.to_bytes().unwrap();
+        preimage.append(&mut token.to_bytes().unwrap());
+        preimage.extend_from_slice(casper_erc777_sender::constants::DAILY_SPENT_TAG.as_bytes());
+        let item_key = hex::encode(&blake2b256(&preimage));
+
+        let key = Key::Hash(self.contract_hash_erc777_sender().value());
+        let value = self
+            .context
+            .query_dictionary_item(
+                key,
+                Some(casper_erc777_sender::constants::DAILY_LIMITS_REGISTRY_KEY_NAME.to_string()),
+                item_key
+            ).ok()?;
+
+        Some(value.into_t::<(u64, U256)>().unwrap())
+    }
+
+    //------ ERC777 Sender Movements
+    pub fn movement_count(&self) -> u64 {
+        self.context
+            .query(
+                self.ali,
+                &[
+                    ERC777_SENDER_CONTRACT_NAME.to_string(),
+                    casper_erc777_sender::constants::MOVEMENT_COUNT_KEY_NAME.to_string()
+                ]
+            )
+            .unwrap()
+            .into_t::<u64>()
+            .unwrap()
+    }
+
+    pub fn movement(&self, index: u64) -> Option<BTreeMap<String, String>> {
+        let key = Key::Hash(self.contract_hash_erc777_sender().value());
+        let value = self
+            .context
+            .query_dictionary_item(
+                key,
+                Some(casper_erc777_sender::constants::MOVEMENTS_REGISTRY_KEY_NAME.to_string()),
+                index.to_string()
+            ).ok()?;
+
+        Some(value.into_t::<BTreeMap<String, String>>().unwrap())
+    }
 }
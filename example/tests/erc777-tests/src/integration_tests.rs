@@ -202,6 +202,344 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_batch_send() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+
+        let owner = fixture.ali;
+        let bob = fixture.bob;
+        let joe = fixture.joe;
+
+        let owner_balance_before = fixture
+            .balance_of(Key::from(owner))
+            .expect("owner should have balance");
+
+        fixture.batch_send(
+            vec![Key::from(bob), Key::from(joe), Key::from(bob)],
+            vec![U256::from(10), U256::from(20), U256::from(5)],
+            Bytes::default(),
+            Sender(owner)
+        );
+
+        assert_eq!(
+            fixture.balance_of(Key::from(owner)).unwrap(),
+            owner_balance_before - U256::from(35)
+        );
+        assert_eq!(fixture.balance_of(Key::from(bob)), Some(U256::from(15)));
+        assert_eq!(fixture.balance_of(Key::from(joe)), Some(U256::from(20)));
+    }
+
+    #[test]
+    fn should_skip_self_and_zero_entries_of_a_batch() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+
+        let owner = fixture.ali;
+        let bob = fixture.bob;
+        let joe = fixture.joe;
+        fixture.add_test_recipient_context(Sender(joe));
+
+        let owner_balance_before = fixture.balance_of(Key::from(owner)).unwrap();
+        let events_before = fixture.events_count();
+
+        fixture.batch_send(
+            vec![Key::from(bob), Key::from(owner), Key::from(joe)],
+            vec![U256::from(10), U256::from(5), U256::zero()],
+            Bytes::default(),
+            Sender(owner)
+        );
+
+        assert_eq!(
+            fixture.balance_of(Key::from(owner)).unwrap(),
+            owner_balance_before - U256::from(10)
+        );
+        assert_eq!(fixture.received_count(joe), 0);
+        assert_eq!(fixture.events_count(), events_before + 1);
+
+        let event = fixture.event(events_before).unwrap();
+        assert_eq!(event.get("to").unwrap(), &Key::from(bob).to_formatted_string());
+        assert_eq!(event.get("amount").unwrap(), "10");
+    }
+
+    #[should_panic(expected = "ApiError::User(65527) [131063]")]
+    #[test]
+    fn should_not_batch_send_with_mismatched_lengths() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+
+        let owner = fixture.ali;
+        let bob = fixture.bob;
+
+        fixture.batch_send(
+            vec![Key::from(bob)],
+            vec![U256::from(10), U256::from(20)],
+            Bytes::default(),
+            Sender(owner)
+        );
+    }
+
+    #[test]
+    fn should_execute_and_release_holds() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+
+        let payer = fixture.ali;
+        let recipient = fixture.bob;
+        let notary = fixture.joe;
+
+        let payer_balance_before = fixture
+            .balance_of(Key::from(payer))
+            .expect("payer should have balance");
+
+        fixture.hold("trade-1", Key::from(recipient), Key::from(notary), U256::from(40), 0, Sender(payer));
+        fixture.hold("trade-2", Key::from(recipient), Key::from(notary), U256::from(2), 0, Sender(payer));
+        assert_eq!(fixture.balance_on_hold(Key::from(payer)), Some(U256::from(42)));
+
+        fixture.execute_hold("trade-1", Sender(notary));
+        fixture.release_hold("trade-2", Sender(notary));
+
+        assert_eq!(fixture.balance_on_hold(Key::from(payer)), Some(U256::zero()));
+        assert_eq!(
+            fixture.balance_of(Key::from(payer)).unwrap(),
+            payer_balance_before - U256::from(40)
+        );
+        assert_eq!(fixture.balance_of(Key::from(recipient)), Some(U256::from(40)));
+    }
+
+    #[should_panic(expected = "ApiError::User(65534) [131070]")]
+    #[test]
+    fn should_not_send_tokens_on_hold() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+
+        let payer = fixture.ali;
+        let recipient = fixture.bob;
+        let notary = fixture.joe;
+
+        fixture.hold(
+            "trade-1",
+            Key::from(recipient),
+            Key::from(notary),
+            TestFixture::token_total_supply(),
+            0,
+            Sender(payer)
+        );
+
+        fixture.send(Key::from(recipient), U256::one(), Bytes::default(), Sender(payer));
+    }
+
+    #[should_panic(expected = "ApiError::User(65523) [131059]")]
+    #[test]
+    fn should_not_execute_a_hold_by_other_than_the_notary() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+
+        let payer = fixture.ali;
+        let recipient = fixture.bob;
+        let notary = fixture.joe;
+
+        fixture.hold("trade-1", Key::from(recipient), Key::from(notary), U256::from(40), 0, Sender(payer));
+        fixture.execute_hold("trade-1", Sender(recipient));
+    }
+
+    #[test]
+    fn should_approve_with_a_signed_permit() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+
+        let owner = fixture.ali;
+        let spender = fixture.bob;
+        let amount = U256::from(42);
+
+        let digest = fixture.permit_digest(Key::from(owner), Key::from(spender), amount, 0, u64::MAX);
+        let signature = TestFixture::sign([3u8; 32], &digest);
+
+        fixture.permit(
+            fixture.ali_public_key.clone(),
+            Key::from(spender),
+            amount,
+            u64::MAX,
+            signature,
+            Sender(fixture.joe)
+        );
+
+        assert_eq!(
+            fixture.allowance(Key::from(owner), Key::from(spender)),
+            Some(amount)
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(65529) [131065]")]
+    #[test]
+    fn should_not_approve_with_a_permit_signed_by_another_key() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+
+        let owner = fixture.ali;
+        let spender = fixture.bob;
+        let amount = U256::from(42);
+
+        let digest = fixture.permit_digest(Key::from(owner), Key::from(spender), amount, 0, u64::MAX);
+        let signature = TestFixture::sign([6u8; 32], &digest);
+
+        fixture.permit(
+            fixture.ali_public_key.clone(),
+            Key::from(spender),
+            amount,
+            u64::MAX,
+            signature,
+            Sender(spender)
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(65529) [131065]")]
+    #[test]
+    fn should_not_replay_a_signed_permit() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+
+        let owner = fixture.ali;
+        let spender = fixture.bob;
+        let amount = U256::from(42);
+
+        let digest = fixture.permit_digest(Key::from(owner), Key::from(spender), amount, 0, u64::MAX);
+        let signature = TestFixture::sign([3u8; 32], &digest);
+
+        fixture.permit(
+            fixture.ali_public_key.clone(),
+            Key::from(spender),
+            amount,
+            u64::MAX,
+            signature.clone(),
+            Sender(spender)
+        );
+        assert_eq!(fixture.nonce_of(Key::from(owner)), 1);
+
+        // The nonce moved on, so the same signature no longer matches the digest.
+        fixture.permit(
+            fixture.ali_public_key.clone(),
+            Key::from(spender),
+            amount,
+            u64::MAX,
+            signature,
+            Sender(spender)
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(65528) [131064]")]
+    #[test]
+    fn should_not_approve_with_an_expired_permit() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+
+        let owner = fixture.ali;
+        let spender = fixture.bob;
+        let amount = U256::from(42);
+        let deadline = 1_000u64;
+
+        let digest = fixture.permit_digest(Key::from(owner), Key::from(spender), amount, 0, deadline);
+        let signature = TestFixture::sign([3u8; 32], &digest);
+
+        fixture.permit_at(
+            fixture.ali_public_key.clone(),
+            Key::from(spender),
+            amount,
+            deadline,
+            signature,
+            deadline + 1,
+            Sender(spender)
+        );
+    }
+
+    #[test]
+    fn should_approve_with_a_secp256k1_permit() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+
+        let seed = [7u8; 32];
+        let owner_public_key = TestFixture::secp256k1_public_key(seed);
+        let owner = owner_public_key.to_account_hash();
+        let spender = fixture.bob;
+        let amount = U256::from(42);
+
+        let digest = fixture.permit_digest(Key::from(owner), Key::from(spender), amount, 0, u64::MAX);
+        let signature = TestFixture::sign_secp256k1(seed, &digest);
+
+        fixture.permit(owner_public_key, Key::from(spender), amount, u64::MAX, signature, Sender(spender));
+
+        assert_eq!(
+            fixture.allowance(Key::from(owner), Key::from(spender)),
+            Some(amount)
+        );
+    }
+
+    #[test]
+    fn should_authorize_an_operator_with_a_signed_permit() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+
+        let owner = fixture.ali;
+        let operator = fixture.bob;
+
+        let digest = fixture.permit_operator_digest(Key::from(owner), Key::from(operator), 0, u64::MAX);
+        let signature = TestFixture::sign([3u8; 32], &digest);
+
+        fixture.permit_operator(
+            fixture.ali_public_key.clone(),
+            Key::from(operator),
+            u64::MAX,
+            signature,
+            Sender(operator)
+        );
+
+        let mut expected_operator = operator.to_string();
+        expected_operator.push('|');
+        assert_eq!(
+            fixture.operators(Key::from(owner)),
+            Some(base64::encode(expected_operator))
+        );
+        assert_eq!(fixture.nonce_of(Key::from(owner)), 1);
+    }
+
+    #[should_panic(expected = "ApiError::User(65530) [131066]")]
+    #[test]
+    fn should_not_authorize_a_contract_operator_with_a_signed_permit() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+
+        let owner = fixture.ali;
+        let operator = Key::Hash([1u8; 32]);
+
+        let digest = fixture.permit_operator_digest(Key::from(owner), operator, 0, u64::MAX);
+        let signature = TestFixture::sign([3u8; 32], &digest);
+
+        fixture.permit_operator(fixture.ali_public_key.clone(), operator, u64::MAX, signature, Sender(owner));
+    }
+
+    #[should_panic(expected = "ApiError::User(65530) [131066]")]
+    #[test]
+    fn should_not_authorize_a_contract_operator() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+
+        fixture.authorize_operator(Key::Hash([1u8; 32]), Sender(fixture.ali));
+    }
+
     #[should_panic(expected = "ApiError::User(65530) [131066]")]
     #[test]
     fn should_throw_an_exception_by_not_having_assigned_operator_to_send_tokens() {
@@ -241,6 +579,24 @@ mod tests {
             Sender(operator)
         );
     }
+    #[test]
+    fn should_notify_the_recipient_hook_of_the_recipient() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_erc20_context();
+
+        let owner = fixture.ali;
+        let holder = fixture.bob;
+        fixture.add_test_recipient_context(Sender(holder));
+
+        fixture.send(Key::from(holder), U256::from(10), Bytes::default(), Sender(owner));
+        assert_eq!(fixture.received_count(holder), 1);
+
+        // The hook of the holder is not called when the holder is the sender.
+        fixture.send(Key::from(fixture.joe), U256::from(4), Bytes::default(), Sender(holder));
+        assert_eq!(fixture.received_count(holder), 1);
+        assert_eq!(fixture.balance_of(Key::from(fixture.joe)), Some(U256::from(4)));
+    }
 }
 
 fn main() {
@@ -5,14 +5,20 @@ use blake2::{
 use casper_engine_test_support::{Code, SessionBuilder, TestContext, TestContextBuilder};
 use casper_types::{
     account::AccountHash, ContractHash, bytesrepr::{FromBytes, ToBytes}, runtime_args, AsymmetricType, CLTyped,
-    Key, PublicKey, RuntimeArgs, U512, U256, HashAddr
+    Key, PublicKey, RuntimeArgs, SecretKey as CasperSecretKey, U512, U256, HashAddr
 };
 use casper_types::bytesrepr::Bytes;
+use std::collections::BTreeMap;
+use ed25519_dalek::{Keypair, SecretKey, Signer};
+use k256::ecdsa::{signature::Signer as _, Signature as Secp256k1Signature, SigningKey};
 
 const ERC1820_CONTRACT_WASM: &str = "erc1820_registry.wasm";
 const ERC20_CONTRACT_WASM: &str = "erc777_token.wasm";
+const TEST_RECIPIENT_CONTRACT_WASM: &str = "erc777_test_recipient.wasm";
 const ERC1820_CONTRACT_NAME: &str = "erc1820_registry";
 const ERC20_CONTRACT_NAME: &str = "erc777_token_contract";
+const TEST_RECIPIENT_CONTRACT_NAME: &str = "erc777_test_recipient_contract";
+const RECEIVED_COUNT_KEY_NAME: &str = "received_count";
 
 
 fn blake2b256(item_key_string: &[u8]) -> Box<[u8]> {
@@ -29,6 +35,7 @@ pub struct TestFixture {
     pub ali: AccountHash,
     pub bob: AccountHash,
     pub joe: AccountHash,
+    pub ali_public_key: PublicKey,
 }
 
 impl TestFixture {
@@ -51,7 +58,8 @@ impl TestFixture {
             context,
             ali: ali.to_account_hash(),
             bob: bob.to_account_hash(),
-            joe: joe.to_account_hash()
+            joe: joe.to_account_hash(),
+            ali_public_key: ali
         }
     }
 
@@ -123,6 +131,59 @@ impl TestFixture {
         self.context.run(session);
     }
 
+    fn call_at(&mut self, sender: Sender, hash_addr: HashAddr, method: &str, args: RuntimeArgs, block_time: u64) {
+        let Sender(address) = sender;
+        let code = Code::Hash(hash_addr, method.to_string());
+        let session = SessionBuilder::new(code, args)
+            .with_address(address)
+            .with_authorization_keys(&[address])
+            .with_block_time(block_time)
+            .build();
+        self.context.run(session);
+    }
+
+    //---- Recipient hook
+    /// Installs a recipient hook counting its calls, and registers it for `sender` in the registry.
+    pub fn add_test_recipient_context(&mut self, sender: Sender) {
+        let Sender(address) = sender;
+        let session = SessionBuilder::new(Code::from(TEST_RECIPIENT_CONTRACT_WASM), runtime_args! {})
+            .with_address(address)
+            .with_authorization_keys(&[address])
+            .build();
+        self.context.run(session);
+
+        let recipient_hash = self.context
+            .get_account(address)
+            .unwrap()
+            .named_keys()
+            .get(TEST_RECIPIENT_CONTRACT_NAME)
+            .unwrap()
+            .normalize()
+            .into_hash()
+            .unwrap();
+
+        self.call(
+            sender,
+            self.contract_hash_erc1820().value(),
+            casper_erc1820::constants::SET_INTERFACE_ENTRY_POINT,
+            runtime_args! {
+                casper_erc1820::constants::ACCOUNT_RUNTIME_ARG_NAME => Key::from(address),
+                casper_erc1820::constants::I_HASH_RUNTIME_ARG_NAME =>
+                    Bytes::from(blake2b256(casper_erc777::constants::HASH_ERC1820_RECIPIENT.as_bytes()).to_vec()),
+                casper_erc1820::constants::IMPLEMENTER_RUNTIME_ARG_NAME => Key::Hash(recipient_hash)
+            },
+        );
+    }
+
+    /// Returns how many movements the recipient hook installed by `account` was notified of.
+    pub fn received_count(&self, account: AccountHash) -> u64 {
+        self.context
+            .query(account, &[TEST_RECIPIENT_CONTRACT_NAME.to_string(), RECEIVED_COUNT_KEY_NAME.to_string()])
+            .unwrap()
+            .into_t::<u64>()
+            .unwrap()
+    }
+
     //---- public function
     fn query_contract_erc20<T: CLTyped + FromBytes>(&self, name: &str) -> Option<T> {
         match self
@@ -139,6 +200,24 @@ impl TestFixture {
         }
     }
 
+    pub fn events_count(&self) -> u64 {
+        self.query_contract_erc20(casper_erc777::constants::EVENTS_COUNT_KEY_NAME)
+            .unwrap_or_default()
+    }
+
+    pub fn event(&self, index: u64) -> Option<BTreeMap<String, String>> {
+        let key = Key::Hash(self.contract_hash_erc20().value());
+        let value = self
+            .context
+            .query_dictionary_item(
+                key,
+                Some(casper_erc777::constants::EVENTS_KEY_NAME.to_string()),
+                index.to_string()
+            ).ok()?;
+
+        Some(value.into_t::<BTreeMap<String, String>>().unwrap())
+    }
+
     pub fn token_total_supply() -> U256 {
         Self::TOKEN_TOTAL_SUPPLY_AS_U64.into()
     }
@@ -185,6 +264,133 @@ impl TestFixture {
         Some(value.into_t::<String>().unwrap())
     }
 
+    pub fn allowance(&self, owner: Key, spender: Key) -> Option<U256> {
+        let mut preimage = Vec::new();
+        preimage.append(&mut owner.to_bytes().unwrap());
+        preimage.append(&mut spender.to_bytes().unwrap());
+        let item_key = hex::encode(&blake2b256(&preimage));
+
+        let key = Key::Hash(self.contract_hash_erc20().value());
+        let value = self
+            .context
+            .query_dictionary_item(key, Some(casper_erc777::constants::ALLOWANCES_KEY_NAME.to_string()), item_key)
+            .ok()?;
+
+        Some(value.into_t::<U256>().unwrap())
+    }
+
+    /// Signs `digest` with the ed25519 secret key built from `seed`.
+    pub fn sign(seed: [u8; 32], digest: &[u8]) -> Bytes {
+        let secret = SecretKey::from_bytes(&seed).unwrap();
+        let public = (&secret).into();
+        let keypair = Keypair { secret, public };
+        Bytes::from(keypair.sign(digest).to_bytes().to_vec())
+    }
+
+    /// Returns the secp256k1 public key built from `seed`.
+    pub fn secp256k1_public_key(seed: [u8; 32]) -> PublicKey {
+        PublicKey::from(&CasperSecretKey::secp256k1_from_bytes(seed).unwrap())
+    }
+
+    /// Signs `digest` with the secp256k1 secret key built from `seed`, `k256` hashes it with SHA-256.
+    pub fn sign_secp256k1(seed: [u8; 32], digest: &[u8]) -> Bytes {
+        let signing_key = SigningKey::from_bytes(&seed).unwrap();
+        let signature: Secp256k1Signature = signing_key.sign(digest);
+        Bytes::from(signature.as_ref().to_vec())
+    }
+
+    pub fn nonce_of(&self, owner: Key) -> u64 {
+        let item_key = base64::encode(&owner.to_bytes().unwrap());
+
+        let key = Key::Hash(self.contract_hash_erc20().value());
+        self.context
+            .query_dictionary_item(key, Some(casper_erc777::constants::NONCES_KEY_NAME.to_string()), item_key)
+            .map(|value| value.into_t::<u64>().unwrap())
+            .unwrap_or_default()
+    }
+
+    pub fn permit_digest(&self, owner: Key, spender: Key, amount: U256, nonce: u64, deadline: u64) -> Vec<u8> {
+        let mut preimage = Vec::new();
+        preimage.extend_from_slice(casper_erc777::constants::PERMIT_DOMAIN_TAG.as_bytes());
+        preimage.append(&mut self.contract_hash_erc20().to_bytes().unwrap());
+        preimage.append(&mut owner.to_bytes().unwrap());
+        preimage.append(&mut spender.to_bytes().unwrap());
+        preimage.append(&mut amount.to_bytes().unwrap());
+        preimage.append(&mut nonce.to_bytes().unwrap());
+        preimage.append(&mut deadline.to_bytes().unwrap());
+        blake2b256(&preimage).to_vec()
+    }
+
+    pub fn permit(
+        &mut self,
+        owner_public_key: PublicKey,
+        spender: Key,
+        amount: U256,
+        deadline: u64,
+        signature: Bytes,
+        sender: Sender
+    ) {
+        self.permit_at(owner_public_key, spender, amount, deadline, signature, 0, sender);
+    }
+
+    /// Calls `permit` in a block made at `block_time`.
+    pub fn permit_at(
+        &mut self,
+        owner_public_key: PublicKey,
+        spender: Key,
+        amount: U256,
+        deadline: u64,
+        signature: Bytes,
+        block_time: u64,
+        sender: Sender
+    ) {
+        self.call_at(
+            sender,
+            self.contract_hash_erc20().value(),
+            casper_erc777::constants::PERMIT_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777::constants::OWNER_PUBLIC_KEY_RUNTIME_ARG_NAME => owner_public_key,
+                casper_erc777::constants::SPENDER_RUNTIME_ARG_NAME => spender,
+                casper_erc777::constants::AMOUNT_RUNTIME_ARG_NAME => amount,
+                casper_erc777::constants::DEADLINE_RUNTIME_ARG_NAME => deadline,
+                casper_erc777::constants::SIGNATURE_RUNTIME_ARG_NAME => signature
+            },
+            block_time
+        );
+    }
+
+    pub fn permit_operator_digest(&self, owner: Key, operator: Key, nonce: u64, deadline: u64) -> Vec<u8> {
+        let mut preimage = Vec::new();
+        preimage.extend_from_slice(casper_erc777::constants::PERMIT_OPERATOR_DOMAIN_TAG.as_bytes());
+        preimage.append(&mut self.contract_hash_erc20().to_bytes().unwrap());
+        preimage.append(&mut owner.to_bytes().unwrap());
+        preimage.append(&mut operator.to_bytes().unwrap());
+        preimage.append(&mut nonce.to_bytes().unwrap());
+        preimage.append(&mut deadline.to_bytes().unwrap());
+        blake2b256(&preimage).to_vec()
+    }
+
+    pub fn permit_operator(
+        &mut self,
+        owner_public_key: PublicKey,
+        operator: Key,
+        deadline: u64,
+        signature: Bytes,
+        sender: Sender
+    ) {
+        self.call(
+            sender,
+            self.contract_hash_erc20().value(),
+            casper_erc777::constants::PERMIT_OPERATOR_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777::constants::OWNER_PUBLIC_KEY_RUNTIME_ARG_NAME => owner_public_key,
+                casper_erc777::constants::OPERATOR_RUNTIME_ARG_NAME => operator,
+                casper_erc777::constants::DEADLINE_RUNTIME_ARG_NAME => deadline,
+                casper_erc777::constants::SIGNATURE_RUNTIME_ARG_NAME => signature
+            },
+        );
+    }
+
     pub fn burn(&mut self, amount: U256, data: Bytes, sender: Sender) {
         self.call(
             sender,
@@ -238,6 +444,82 @@ impl TestFixture {
         );
     }
 
+    pub fn batch_send(
+        &mut self,
+        recipients: Vec<Key>,
+        amounts: Vec<U256>,
+        data: Bytes,
+        sender: Sender
+    ) {
+        self.call(
+            sender,
+            self.contract_hash_erc20().value(),
+            casper_erc777::constants::BATCH_SEND_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777::constants::RECIPIENTS_RUNTIME_ARG_NAME => recipients,
+                casper_erc777::constants::AMOUNTS_RUNTIME_ARG_NAME => amounts,
+                casper_erc777::constants::DATA_RUNTIME_ARG_NAME => data
+            },
+        );
+    }
+
+    pub fn hold(
+        &mut self,
+        hold_id: &str,
+        recipient: Key,
+        notary: Key,
+        amount: U256,
+        expiration: u64,
+        sender: Sender
+    ) {
+        self.call(
+            sender,
+            self.contract_hash_erc20().value(),
+            casper_erc777::constants::HOLD_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777::constants::HOLD_ID_RUNTIME_ARG_NAME => hold_id.to_string(),
+                casper_erc777::constants::RECIPIENT_RUNTIME_ARG_NAME => recipient,
+                casper_erc777::constants::NOTARY_RUNTIME_ARG_NAME => notary,
+                casper_erc777::constants::AMOUNT_RUNTIME_ARG_NAME => amount,
+                casper_erc777::constants::EXPIRATION_RUNTIME_ARG_NAME => expiration
+            },
+        );
+    }
+
+    pub fn execute_hold(&mut self, hold_id: &str, sender: Sender) {
+        self.call(
+            sender,
+            self.contract_hash_erc20().value(),
+            casper_erc777::constants::EXECUTE_HOLD_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777::constants::HOLD_ID_RUNTIME_ARG_NAME => hold_id.to_string()
+            },
+        );
+    }
+
+    pub fn release_hold(&mut self, hold_id: &str, sender: Sender) {
+        self.call(
+            sender,
+            self.contract_hash_erc20().value(),
+            casper_erc777::constants::RELEASE_HOLD_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777::constants::HOLD_ID_RUNTIME_ARG_NAME => hold_id.to_string()
+            },
+        );
+    }
+
+    pub fn balance_on_hold(&self, account: Key) -> Option<U256> {
+        let item_key = base64::encode(&account.to_bytes().unwrap());
+
+        let key = Key::Hash(self.contract_hash_erc20().value());
+        let value = self
+            .context
+            .query_dictionary_item(key, Some(casper_erc777::constants::HELD_BALANCES_KEY_NAME.to_string()), item_key)
+            .ok()?;
+
+        Some(value.into_t::<U256>().unwrap())
+    }
+
     pub fn operator_send(
         &mut self,
         sender: Key,
@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod test_fixture;
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{Key, U256, U512};
+
+    use crate::test_fixture::{Sender, TestFixture};
+
+    #[test]
+    fn should_install_with_cspr_decimals() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_wrapped_cspr_context(U256::one());
+
+        assert_eq!(fixture.token_decimals(), 9);
+        assert_eq!(fixture.total_supply(), U256::zero());
+    }
+
+    #[test]
+    fn should_deposit_and_withdraw() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_wrapped_cspr_context(U256::one());
+
+        fixture.deposit(U512::from(5_000_000_000u64), Sender(fixture.bob));
+        assert_eq!(
+            fixture.balance_of(Key::from(fixture.bob)),
+            Some(U256::from(5_000_000_000u64))
+        );
+        assert_eq!(fixture.total_supply(), U256::from(5_000_000_000u64));
+
+        fixture.withdraw(U256::from(2_000_000_000u64), Sender(fixture.bob));
+        assert_eq!(
+            fixture.balance_of(Key::from(fixture.bob)),
+            Some(U256::from(3_000_000_000u64))
+        );
+        assert_eq!(fixture.total_supply(), U256::from(3_000_000_000u64));
+    }
+
+    #[should_panic(expected = "ApiError::User(1100) [66636]")]
+    #[test]
+    fn should_not_deposit_an_amount_outside_the_granularity() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_wrapped_cspr_context(U256::from(1_000_000_000u64));
+
+        fixture.deposit(U512::from(1_500_000_000u64), Sender(fixture.bob));
+    }
+
+    #[should_panic(expected = "ApiError::User(65534) [131070]")]
+    #[test]
+    fn should_not_withdraw_more_than_the_balance() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_wrapped_cspr_context(U256::one());
+
+        fixture.deposit(U512::from(1_000_000_000u64), Sender(fixture.bob));
+        fixture.withdraw(U256::from(2_000_000_000u64), Sender(fixture.bob));
+    }
+
+    #[test]
+    fn should_transfer_wrapped_tokens() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_wrapped_cspr_context(U256::one());
+
+        fixture.deposit(U512::from(5_000_000_000u64), Sender(fixture.bob));
+        fixture.transfer(Key::from(fixture.ali), U256::from(2_000_000_000u64), Sender(fixture.bob));
+        assert_eq!(
+            fixture.balance_of(Key::from(fixture.ali)),
+            Some(U256::from(2_000_000_000u64))
+        );
+
+        fixture.withdraw(U256::from(2_000_000_000u64), Sender(fixture.ali));
+        assert_eq!(fixture.balance_of(Key::from(fixture.ali)), Some(U256::zero()));
+        assert_eq!(fixture.total_supply(), U256::from(3_000_000_000u64));
+    }
+
+    #[should_panic(expected = "ApiError::User(65534) [131070]")]
+    #[test]
+    fn should_not_withdraw_tokens_on_hold() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_erc1820_context();
+        fixture.add_wrapped_cspr_context(U256::one());
+
+        fixture.deposit(U512::from(2_000_000_000u64), Sender(fixture.bob));
+        fixture.hold(
+            "hold-1",
+            Key::from(fixture.ali),
+            Key::from(fixture.ali),
+            U256::from(2_000_000_000u64),
+            Sender(fixture.bob)
+        );
+        fixture.withdraw(U256::from(1_000_000_000u64), Sender(fixture.bob));
+    }
+}
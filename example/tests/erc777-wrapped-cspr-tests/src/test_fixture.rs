@@ -0,0 +1,190 @@
+use casper_engine_test_support::{Code, SessionBuilder, TestContext, TestContextBuilder};
+use casper_types::{
+    account::AccountHash, ContractHash, bytesrepr::{FromBytes, ToBytes}, runtime_args, AsymmetricType,
+    CLTyped, Key, PublicKey, RuntimeArgs, U512, U256, HashAddr
+};
+
+const ERC1820_CONTRACT_WASM: &str = "erc1820_registry.wasm";
+const WRAPPED_CSPR_CONTRACT_WASM: &str = "erc777_wrapped_cspr.wasm";
+const DEPOSIT_SESSION_WASM: &str = "wrapped_cspr_deposit.wasm";
+const ERC1820_CONTRACT_NAME: &str = "erc1820_registry";
+
+#[derive(Clone, Copy)]
+pub struct Sender(pub AccountHash);
+
+pub struct TestFixture {
+    pub context: TestContext,
+    pub ali: AccountHash,
+    pub bob: AccountHash,
+}
+
+impl TestFixture {
+    pub const TOKEN_NAME: &'static str = "Wrapped CSPR";
+    pub const TOKEN_SYMBOL: &'static str = "WCSPR";
+
+    pub fn install_contract() -> TestFixture {
+        let ali = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
+        let bob = PublicKey::ed25519_from_bytes([6u8; 32]).unwrap();
+
+        let context = TestContextBuilder::new()
+            .with_public_key(ali.clone(), U512::from(500_000_000_000_000_000u64))
+            .with_public_key(bob.clone(), U512::from(500_000_000_000_000_000u64))
+            .build();
+
+        TestFixture {
+            context,
+            ali: ali.to_account_hash(),
+            bob: bob.to_account_hash(),
+        }
+    }
+
+    pub fn add_erc1820_context(&mut self) {
+        let session_code = Code::from(ERC1820_CONTRACT_WASM);
+        let session_args = runtime_args! {};
+
+        let session = SessionBuilder::new(session_code, session_args)
+            .with_address(self.ali)
+            .with_authorization_keys(&[self.ali])
+            .build();
+
+        self.context.run(session);
+    }
+
+    pub fn add_wrapped_cspr_context(&mut self, granularity: U256) {
+        let contract_hash = self.contract_hash(ERC1820_CONTRACT_NAME);
+
+        let session_code = Code::from(WRAPPED_CSPR_CONTRACT_WASM);
+        let session_args = runtime_args! {
+            casper_erc777::constants::NAME_RUNTIME_ARG_NAME => TestFixture::TOKEN_NAME,
+            casper_erc777::constants::SYMBOL_RUNTIME_ARG_NAME => TestFixture::TOKEN_SYMBOL,
+            casper_erc777::constants::GRANULARITY_RUNTIME_ARG_NAME => granularity,
+            casper_erc777::constants::HASH_ERC1820_RUNTIME_ARG_NAME => contract_hash
+        };
+
+        let session = SessionBuilder::new(session_code, session_args)
+            .with_address(self.ali)
+            .with_authorization_keys(&[self.ali])
+            .build();
+
+        self.context.run(session);
+    }
+
+    fn contract_hash(&self, name: &str) -> ContractHash {
+        self.context
+            .get_account(self.ali)
+            .unwrap()
+            .named_keys()
+            .get(name)
+            .unwrap()
+            .normalize()
+            .into_hash()
+            .unwrap()
+            .into()
+    }
+
+    pub fn contract_hash_wrapped_cspr(&self) -> ContractHash {
+        self.contract_hash(casper_erc777_wrapped_cspr::constants::WRAPPED_CSPR_CONTRACT_NAME)
+    }
+
+    fn call(&mut self, sender: Sender, hash_addr: HashAddr, method: &str, args: RuntimeArgs) {
+        let Sender(address) = sender;
+        let code = Code::Hash(hash_addr, method.to_string());
+        let session = SessionBuilder::new(code, args)
+            .with_address(address)
+            .with_authorization_keys(&[address])
+            .build();
+        self.context.run(session);
+    }
+
+    fn query_contract<T: CLTyped + FromBytes>(&self, name: &str) -> Option<T> {
+        match self.context.query(
+            self.ali,
+            &[
+                casper_erc777_wrapped_cspr::constants::WRAPPED_CSPR_CONTRACT_NAME.to_string(),
+                name.to_string(),
+            ],
+        ) {
+            Err(_) => None,
+            Ok(maybe_value) => {
+                let value = maybe_value
+                    .into_t()
+                    .unwrap_or_else(|_| panic!("{} is not expected type.", name));
+                Some(value)
+            }
+        }
+    }
+
+    pub fn token_decimals(&self) -> u8 {
+        self.query_contract(casper_erc777::constants::DECIMALS_KEY_NAME).unwrap()
+    }
+
+    pub fn total_supply(&self) -> U256 {
+        self.query_contract(casper_erc777::constants::TOTAL_SUPPLY_KEY_NAME).unwrap()
+    }
+
+    pub fn balance_of(&self, account: Key) -> Option<U256> {
+        let item_key = base64::encode(&account.to_bytes().unwrap());
+
+        let key = Key::Hash(self.contract_hash_wrapped_cspr().value());
+        let value = self
+            .context
+            .query_dictionary_item(key, Some(casper_erc777::constants::BALANCES_KEY_NAME.to_string()), item_key)
+            .ok()?;
+
+        Some(value.into_t::<U256>().unwrap())
+    }
+
+    pub fn deposit(&mut self, amount: U512, sender: Sender) {
+        let Sender(address) = sender;
+        let session_code = Code::from(DEPOSIT_SESSION_WASM);
+        let session_args = runtime_args! {
+            casper_erc777_wrapped_cspr::constants::WRAPPED_CSPR_CONTRACT_ARG_NAME => self.contract_hash_wrapped_cspr(),
+            casper_erc777_wrapped_cspr::constants::AMOUNT_RUNTIME_ARG_NAME => amount
+        };
+
+        let session = SessionBuilder::new(session_code, session_args)
+            .with_address(address)
+            .with_authorization_keys(&[address])
+            .build();
+
+        self.context.run(session);
+    }
+
+    pub fn withdraw(&mut self, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.contract_hash_wrapped_cspr().value(),
+            casper_erc777_wrapped_cspr::constants::WITHDRAW_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777_wrapped_cspr::constants::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+        );
+    }
+
+    pub fn transfer(&mut self, recipient: Key, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.contract_hash_wrapped_cspr().value(),
+            casper_erc777::constants::TRANSFER_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777::constants::RECIPIENT_RUNTIME_ARG_NAME => recipient,
+                casper_erc777::constants::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+        );
+    }
+
+    pub fn hold(&mut self, hold_id: &str, recipient: Key, notary: Key, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.contract_hash_wrapped_cspr().value(),
+            casper_erc777::constants::HOLD_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777::constants::HOLD_ID_RUNTIME_ARG_NAME => hold_id.to_string(),
+                casper_erc777::constants::RECIPIENT_RUNTIME_ARG_NAME => recipient,
+                casper_erc777::constants::NOTARY_RUNTIME_ARG_NAME => notary,
+                casper_erc777::constants::AMOUNT_RUNTIME_ARG_NAME => amount,
+                casper_erc777::constants::EXPIRATION_RUNTIME_ARG_NAME => 0u64
+            },
+        );
+    }
+}
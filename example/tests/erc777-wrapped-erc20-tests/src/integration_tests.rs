@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod test_fixture;
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{Key, U256};
+
+    use crate::test_fixture::{Sender, TestFixture};
+
+    #[test]
+    fn should_mirror_the_underlying_decimals() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_contexts(U256::one());
+
+        assert_eq!(fixture.wrapped_decimals(), casper_erc777::constants::DECIMALS_KEY_VALUE);
+    }
+
+    #[test]
+    fn should_deposit_and_withdraw() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_contexts(U256::one());
+
+        let ali = Key::from(fixture.ali);
+        let wrapper = fixture.wrapped_package();
+
+        fixture.approve_underlying(wrapper, U256::from(100), Sender(fixture.ali));
+        fixture.deposit(U256::from(100), Sender(fixture.ali));
+
+        assert_eq!(fixture.wrapped_balance_of(ali), Some(U256::from(100)));
+        assert_eq!(fixture.underlying_balance_of(wrapper), Some(U256::from(100)));
+        assert_eq!(
+            fixture.underlying_balance_of(ali),
+            Some(TestFixture::underlying_total_supply() - U256::from(100))
+        );
+
+        fixture.withdraw(U256::from(40), Sender(fixture.ali));
+
+        assert_eq!(fixture.wrapped_balance_of(ali), Some(U256::from(60)));
+        assert_eq!(fixture.underlying_balance_of(wrapper), Some(U256::from(60)));
+        assert_eq!(
+            fixture.underlying_balance_of(ali),
+            Some(TestFixture::underlying_total_supply() - U256::from(60))
+        );
+    }
+
+    #[should_panic(expected = "ApiError::User(65533) [131069]")]
+    #[test]
+    fn should_not_deposit_without_an_allowance() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_contexts(U256::one());
+
+        fixture.deposit(U256::from(100), Sender(fixture.ali));
+    }
+
+    #[should_panic(expected = "ApiError::User(1201) [66737]")]
+    #[test]
+    fn should_not_withdraw_an_amount_outside_the_granularity() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.add_contexts(U256::from(10));
+
+        let wrapper = fixture.wrapped_package();
+        fixture.approve_underlying(wrapper, U256::from(100), Sender(fixture.ali));
+        fixture.deposit(U256::from(100), Sender(fixture.ali));
+        fixture.withdraw(U256::from(15), Sender(fixture.ali));
+    }
+}
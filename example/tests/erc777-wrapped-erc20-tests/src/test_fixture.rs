@@ -0,0 +1,192 @@
+use casper_engine_test_support::{Code, SessionBuilder, TestContext, TestContextBuilder};
+use casper_types::{
+    account::AccountHash, ContractHash, bytesrepr::{FromBytes, ToBytes}, runtime_args, AsymmetricType,
+    CLTyped, Key, PublicKey, RuntimeArgs, U512, U256, HashAddr
+};
+
+const ERC1820_CONTRACT_WASM: &str = "erc1820_registry.wasm";
+const UNDERLYING_CONTRACT_WASM: &str = "erc777_token.wasm";
+const WRAPPED_ERC20_CONTRACT_WASM: &str = "erc777_wrapped_erc20.wasm";
+const ERC1820_CONTRACT_NAME: &str = "erc1820_registry";
+
+#[derive(Clone, Copy)]
+pub struct Sender(pub AccountHash);
+
+pub struct TestFixture {
+    pub context: TestContext,
+    pub ali: AccountHash,
+    pub bob: AccountHash,
+}
+
+impl TestFixture {
+    pub const TOKEN_NAME: &'static str = "Wrapped Test ERC20";
+    pub const TOKEN_SYMBOL: &'static str = "WTERC";
+    const UNDERLYING_TOTAL_SUPPLY_AS_U64: u64 = 10000;
+
+    pub fn install_contract() -> TestFixture {
+        let ali = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
+        let bob = PublicKey::ed25519_from_bytes([6u8; 32]).unwrap();
+
+        let context = TestContextBuilder::new()
+            .with_public_key(ali.clone(), U512::from(500_000_000_000_000_000u64))
+            .with_public_key(bob.clone(), U512::from(500_000_000_000_000_000u64))
+            .build();
+
+        TestFixture {
+            context,
+            ali: ali.to_account_hash(),
+            bob: bob.to_account_hash(),
+        }
+    }
+
+    fn install(&mut self, session_code: Code, session_args: RuntimeArgs) {
+        let session = SessionBuilder::new(session_code, session_args)
+            .with_address(self.ali)
+            .with_authorization_keys(&[self.ali])
+            .build();
+
+        self.context.run(session);
+    }
+
+    /// Installs the registry, an underlying token owned by `ali` and the wrapper around it.
+    pub fn add_contexts(&mut self, granularity: U256) {
+        self.install(Code::from(ERC1820_CONTRACT_WASM), runtime_args! {});
+        let erc1820_hash = self.contract_hash(ERC1820_CONTRACT_NAME);
+
+        self.install(
+            Code::from(UNDERLYING_CONTRACT_WASM),
+            runtime_args! {
+                casper_erc777::constants::NAME_RUNTIME_ARG_NAME => "Test ERC20",
+                casper_erc777::constants::SYMBOL_RUNTIME_ARG_NAME => "TERC",
+                casper_erc777::constants::GRANULARITY_RUNTIME_ARG_NAME => U256::one(),
+                casper_erc777::constants::TOTAL_SUPPLY_RUNTIME_ARG_NAME => TestFixture::underlying_total_supply(),
+                casper_erc777::constants::HASH_ERC1820_RUNTIME_ARG_NAME => erc1820_hash
+            },
+        );
+
+        self.install(
+            Code::from(WRAPPED_ERC20_CONTRACT_WASM),
+            runtime_args! {
+                casper_erc777::constants::NAME_RUNTIME_ARG_NAME => TestFixture::TOKEN_NAME,
+                casper_erc777::constants::SYMBOL_RUNTIME_ARG_NAME => TestFixture::TOKEN_SYMBOL,
+                casper_erc777::constants::GRANULARITY_RUNTIME_ARG_NAME => granularity,
+                casper_erc777_wrapped_erc20::constants::UNDERLYING_TOKEN_RUNTIME_ARG_NAME => self.contract_hash_underlying(),
+                casper_erc777::constants::HASH_ERC1820_RUNTIME_ARG_NAME => erc1820_hash
+            },
+        );
+    }
+
+    pub fn underlying_total_supply() -> U256 {
+        Self::UNDERLYING_TOTAL_SUPPLY_AS_U64.into()
+    }
+
+    fn named_key(&self, name: &str) -> Key {
+        *self
+            .context
+            .get_account(self.ali)
+            .unwrap()
+            .named_keys()
+            .get(name)
+            .unwrap()
+    }
+
+    fn contract_hash(&self, name: &str) -> ContractHash {
+        self.named_key(name)
+            .normalize()
+            .into_hash()
+            .unwrap()
+            .into()
+    }
+
+    pub fn contract_hash_underlying(&self) -> ContractHash {
+        self.contract_hash(casper_erc777::constants::ERC20_TOKEN_CONTRACT_NAME)
+    }
+
+    pub fn contract_hash_wrapped(&self) -> ContractHash {
+        self.contract_hash(casper_erc777_wrapped_erc20::constants::WRAPPED_ERC20_CONTRACT_NAME)
+    }
+
+    /// Key of the wrapper contract package, which holds the underlying tokens.
+    pub fn wrapped_package(&self) -> Key {
+        let name = casper_erc777_wrapped_erc20::constants::WRAPPED_ERC20_PACKAGE_HASH_KEY_NAME;
+        Key::Hash(self.named_key(name).into_hash().unwrap())
+    }
+
+    fn call(&mut self, sender: Sender, hash_addr: HashAddr, method: &str, args: RuntimeArgs) {
+        let Sender(address) = sender;
+        let code = Code::Hash(hash_addr, method.to_string());
+        let session = SessionBuilder::new(code, args)
+            .with_address(address)
+            .with_authorization_keys(&[address])
+            .build();
+        self.context.run(session);
+    }
+
+    pub fn wrapped_decimals(&self) -> u8 {
+        self.context
+            .query(
+                self.ali,
+                &[
+                    casper_erc777_wrapped_erc20::constants::WRAPPED_ERC20_CONTRACT_NAME.to_string(),
+                    casper_erc777::constants::DECIMALS_KEY_NAME.to_string(),
+                ],
+            )
+            .unwrap()
+            .into_t()
+            .unwrap()
+    }
+
+    fn query_balance(&self, contract_hash: ContractHash, account: Key) -> Option<U256> {
+        let item_key = base64::encode(&account.to_bytes().unwrap());
+
+        let key = Key::Hash(contract_hash.value());
+        let value = self
+            .context
+            .query_dictionary_item(key, Some(casper_erc777::constants::BALANCES_KEY_NAME.to_string()), item_key)
+            .ok()?;
+
+        Some(value.into_t::<U256>().unwrap())
+    }
+
+    pub fn underlying_balance_of(&self, account: Key) -> Option<U256> {
+        self.query_balance(self.contract_hash_underlying(), account)
+    }
+
+    pub fn wrapped_balance_of(&self, account: Key) -> Option<U256> {
+        self.query_balance(self.contract_hash_wrapped(), account)
+    }
+
+    pub fn approve_underlying(&mut self, spender: Key, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.contract_hash_underlying().value(),
+            casper_erc777::constants::APPROVE_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777::constants::SPENDER_RUNTIME_ARG_NAME => spender,
+                casper_erc777::constants::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+        );
+    }
+
+    pub fn deposit(&mut self, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.contract_hash_wrapped().value(),
+            casper_erc777_wrapped_erc20::constants::DEPOSIT_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777_wrapped_erc20::constants::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+        );
+    }
+
+    pub fn withdraw(&mut self, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            self.contract_hash_wrapped().value(),
+            casper_erc777_wrapped_erc20::constants::WITHDRAW_ENTRY_POINT_NAME,
+            runtime_args! {
+                casper_erc777_wrapped_erc20::constants::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+        );
+    }
+}